@@ -1,5 +1,5 @@
 use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
-use embedded_time::{duration::*, rate::*};
+use embedded_time::{duration::*, fixed_point::FixedPoint, rate::*};
 use std::convert::TryFrom;
 use std::mem::size_of;
 
@@ -118,5 +118,55 @@ fn conversions(c: &mut Criterion) {
     group.finish();
 }
 
-criterion_group!(benches, duration_vs_core_duration, conversions);
+fn division_free_conversions(c: &mut Criterion) {
+    let mut group = c.benchmark_group("division-free conversions");
+
+    // each conversion is compared with the division-based conversion of the same values
+    let duration = Nanoseconds(123_456_789_123_u64);
+    group.bench_function("Milliseconds<u64>::from::<Nanoseconds<u64>>()", |b| {
+        b.iter(|| Milliseconds::<u64>::from(black_box(duration)))
+    });
+    group.bench_function("Milliseconds<u64>::from_ticks() (division)", |b| {
+        b.iter(|| {
+            Milliseconds::<u64>::from_ticks(
+                black_box(duration).integer(),
+                Nanoseconds::<u64>::SCALING_FACTOR,
+            )
+        })
+    });
+
+    type Ticks32k = embedded_time::duration::Scaled<u32, 1, 32_768>;
+    let duration = Microseconds(123_456_u32);
+    group.bench_function(
+        "Scaled<u32, 1, 32_768>::try_from::<Microseconds<u32>>()",
+        |b| b.iter(|| Ticks32k::try_from(black_box(duration))),
+    );
+    group.bench_function("Scaled<u32, 1, 32_768>::from_ticks() (division)", |b| {
+        b.iter(|| {
+            Ticks32k::from_ticks(
+                black_box(duration).integer(),
+                Microseconds::<u32>::SCALING_FACTOR,
+            )
+        })
+    });
+
+    let rate = Hertz(14_745_600_u32);
+    group.bench_function("Kilohertz<u32>::from::<Hertz<u32>>()", |b| {
+        b.iter(|| Kilohertz::<u32>::from(black_box(rate)))
+    });
+    group.bench_function("Kilohertz<u32>::from_ticks() (division)", |b| {
+        b.iter(|| {
+            Kilohertz::<u32>::from_ticks(black_box(rate).integer(), Hertz::<u32>::SCALING_FACTOR)
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    duration_vs_core_duration,
+    conversions,
+    division_free_conversions
+);
 criterion_main!(benches);
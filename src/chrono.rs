@@ -0,0 +1,138 @@
+//! Interoperability with the [`chrono`](https://docs.rs/chrono) crate
+//!
+//! With the `chrono` feature, the duration units convert to [`chrono::Duration`] and an
+//! [`Instant`] converts to a [`chrono::DateTime<Utc>`] given the date and time of its clock's
+//! epoch. The conversions use the same fixed-point math as the rest of the crate and are
+//! truncated to nanoseconds.
+
+use crate::{
+    duration::{self, *},
+    fixed_point::FixedPoint,
+    time_int::TimeInt,
+    ConversionError, Instant,
+};
+use chrono::{DateTime, Utc};
+use core::convert::TryFrom;
+
+/// Converts a number of ticks of the _scaling factor_ into a `chrono` duration
+fn to_chrono_duration(
+    ticks: u64,
+    scaling_factor: &Fraction,
+) -> Result<chrono::Duration, ConversionError> {
+    chrono::Duration::from_std(duration::ticks_to_core_duration(ticks, scaling_factor)?)
+        .map_err(|_| ConversionError::Overflow)
+}
+
+impl<Clock: crate::Clock> Instant<Clock>
+where
+    u64: From<Clock::T>,
+{
+    /// Returns the date and time of the instant given that of the clock's epoch (`0` ticks)
+    ///
+    /// ```rust
+    /// use chrono::{TimeZone, Utc};
+    /// use embedded_time::{fraction::Fraction, Instant};
+    /// # #[derive(Debug)]
+    /// struct Clock;
+    /// impl embedded_time::Clock for Clock {
+    ///     type T = u32;
+    ///     const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+    ///     // ...
+    /// # fn try_now(&self) -> Result<Instant<Self>, embedded_time::clock::Error> {unimplemented!()}
+    /// }
+    ///
+    /// let boot = Utc.with_ymd_and_hms(2021, 10, 2, 12, 0, 0).unwrap();
+    ///
+    /// assert_eq!(
+    ///     Instant::<Clock>::new(90_500).to_chrono_date_time(boot),
+    ///     Ok(Utc.with_ymd_and_hms(2021, 10, 2, 12, 1, 30).unwrap()
+    ///         + chrono::Duration::milliseconds(500))
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// [`ConversionError::Overflow`] : The date and time is out of the range of
+    /// [`chrono::DateTime`].
+    pub fn to_chrono_date_time(
+        &self,
+        epoch: DateTime<Utc>,
+    ) -> Result<DateTime<Utc>, ConversionError> {
+        let since_epoch = self.duration_since_epoch();
+        epoch
+            .checked_add_signed(to_chrono_duration(
+                since_epoch.integer().into(),
+                since_epoch.scaling_factor(),
+            )?)
+            .ok_or(ConversionError::Overflow)
+    }
+}
+
+macro_rules! impl_chrono {
+    ($($name:ident),+) => {
+        $(
+            impl<T: TimeInt> TryFrom<$name<T>> for chrono::Duration
+            where
+                u64: From<T>,
+            {
+                type Error = ConversionError;
+
+                /// The duration is truncated to nanoseconds
+                fn try_from(duration: $name<T>) -> Result<Self, Self::Error> {
+                    to_chrono_duration(duration.integer().into(), &$name::<T>::SCALING_FACTOR)
+                }
+            }
+        )+
+    };
+}
+impl_chrono![
+    Weeks,
+    Days,
+    Hours,
+    Minutes,
+    Seconds,
+    Milliseconds,
+    Microseconds,
+    Nanoseconds,
+    Picoseconds
+];
+
+impl<T: TimeInt, const NUM: u32, const DENOM: u32> TryFrom<Scaled<T, NUM, DENOM>>
+    for chrono::Duration
+where
+    u64: From<T>,
+{
+    type Error = ConversionError;
+
+    /// The duration is truncated to nanoseconds
+    fn try_from(duration: Scaled<T, NUM, DENOM>) -> Result<Self, Self::Error> {
+        to_chrono_duration(
+            duration.integer().into(),
+            &Scaled::<T, NUM, DENOM>::SCALING_FACTOR,
+        )
+    }
+}
+
+impl<Clock: crate::Clock> TryFrom<Ticks<Clock>> for chrono::Duration
+where
+    u64: From<Clock::T>,
+{
+    type Error = ConversionError;
+
+    /// The duration is truncated to nanoseconds
+    fn try_from(duration: Ticks<Clock>) -> Result<Self, Self::Error> {
+        to_chrono_duration(duration.integer().into(), &Clock::SCALING_FACTOR)
+    }
+}
+
+impl<T: TimeInt> TryFrom<Generic<T>> for chrono::Duration
+where
+    u64: From<T>,
+{
+    type Error = ConversionError;
+
+    /// The duration is truncated to nanoseconds
+    fn try_from(duration: Generic<T>) -> Result<Self, Self::Error> {
+        to_chrono_duration(duration.integer().into(), duration.scaling_factor())
+    }
+}
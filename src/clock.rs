@@ -9,6 +9,8 @@ use core::hash::Hash;
 /// Potential `Clock` errors
 #[non_exhaustive]
 #[derive(Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "ufmt", derive(ufmt::derive::uDebug))]
 pub enum Error {
     /// Exact cause of failure is unknown
     Unspecified,
@@ -26,7 +28,7 @@ impl Default for Error {
 /// timer devices, RTCs, etc.
 ///
 /// The `Clock` is characterized by an inner unsigned integer storage type (either [`u32`] or
-/// [`u64`]), a [`u32`]/[`u32`] [`Fraction`] defining the duration (in seconds) of one
+/// [`u64`]), a [`u64`]/[`u64`] [`Fraction`] defining the duration (in seconds) of one
 /// count of the `Clock`, and a custom error type representing errors that may be generated by the
 /// implementation.
 ///
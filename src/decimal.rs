@@ -0,0 +1,309 @@
+//! Decimal number formatting and parsing shared by the human-readable types
+
+use crate::ConversionError;
+use core::fmt::{self, Write as _};
+
+/// Write `numerator / denominator` as a decimal number
+///
+/// The precision of the formatter sets a fixed number of fractional digits. Otherwise, up to
+/// `max_digits` fractional digits are written, omitting trailing zeros.
+///
+/// The value is truncated, not rounded.
+pub(crate) fn write(
+    f: &mut fmt::Formatter<'_>,
+    numerator: u128,
+    denominator: u128,
+    max_digits: usize,
+) -> fmt::Result {
+    if denominator == 0 {
+        return Err(fmt::Error);
+    }
+    write!(f, "{}", numerator / denominator)?;
+
+    let mut remainder = numerator % denominator;
+    match f.precision() {
+        Some(precision) => write_fraction_digits(f, remainder, denominator, precision),
+        None if remainder == 0 => Ok(()),
+        None => {
+            f.write_char('.')?;
+            for _ in 0..max_digits {
+                remainder = write_digit(f, remainder, denominator)?;
+                if remainder == 0 {
+                    break;
+                }
+            }
+            Ok(())
+        }
+    }
+}
+
+/// Write the output of `write` padded to the width of the formatter
+///
+/// `write` is given a formatter with the same precision but without a width. Like strings, the
+/// output is left-aligned by default.
+pub(crate) fn pad<F>(f: &mut fmt::Formatter<'_>, write: F) -> fmt::Result
+where
+    F: Fn(&mut fmt::Formatter<'_>) -> fmt::Result,
+{
+    let width = match f.width() {
+        Some(width) => width,
+        None => return write(f),
+    };
+
+    let unpadded = Unpadded(write);
+    let mut chars = CharCount(0);
+    match f.precision() {
+        Some(precision) => write!(chars, "{:.*}", precision, unpadded)?,
+        None => write!(chars, "{}", unpadded)?,
+    }
+
+    let padding = width.saturating_sub(chars.0);
+    let (before, after) = match f.align() {
+        Some(fmt::Alignment::Right) => (padding, 0),
+        Some(fmt::Alignment::Center) => (padding / 2, padding - padding / 2),
+        _ => (0, padding),
+    };
+    let fill = f.fill();
+    for _ in 0..before {
+        f.write_char(fill)?;
+    }
+    match f.precision() {
+        Some(precision) => write!(f, "{:.*}", precision, unpadded)?,
+        None => write!(f, "{}", unpadded)?,
+    }
+    for _ in 0..after {
+        f.write_char(fill)?;
+    }
+    Ok(())
+}
+
+/// Formats with a closure
+struct Unpadded<F>(F);
+
+impl<F> fmt::Display for Unpadded<F>
+where
+    F: Fn(&mut fmt::Formatter<'_>) -> fmt::Result,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        (self.0)(f)
+    }
+}
+
+/// Counts the characters written to it
+struct CharCount(usize);
+
+impl fmt::Write for CharCount {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0 += s.chars().count();
+        Ok(())
+    }
+}
+
+/// Write exactly `precision` fractional digits of `remainder / denominator`, truncated
+pub(crate) fn write_fraction_digits(
+    f: &mut fmt::Formatter<'_>,
+    mut remainder: u128,
+    denominator: u128,
+    precision: usize,
+) -> fmt::Result {
+    if precision > 0 {
+        f.write_char('.')?;
+        for _ in 0..precision {
+            remainder = write_digit(f, remainder, denominator)?;
+        }
+    }
+    Ok(())
+}
+
+/// Write the next digit of a long division, returning the new remainder
+fn write_digit(
+    f: &mut fmt::Formatter<'_>,
+    remainder: u128,
+    denominator: u128,
+) -> Result<u128, fmt::Error> {
+    // the remainder is always less than the denominator, so the digit is always less than 10
+    let scaled = remainder.checked_mul(10).ok_or(fmt::Error)?;
+    f.write_char(char::from(b'0' + (scaled / denominator) as u8))?;
+    Ok(scaled % denominator)
+}
+
+/// A non-negative decimal number such as `1.5`
+///
+/// The value is `whole + fraction / divisor`.
+#[derive(Copy, Clone, Debug)]
+pub(crate) struct Decimal {
+    whole: u128,
+    fraction: u128,
+    divisor: u128,
+}
+
+impl Decimal {
+    pub(crate) const ZERO: Self = Self {
+        whole: 0,
+        fraction: 0,
+        divisor: 1,
+    };
+
+    /// Splits a leading decimal number off of `s`, returning it and the rest of the string
+    ///
+    /// # Errors
+    ///
+    /// - [`ConversionError::ParseFailure`] : `s` doesn't start with a decimal number
+    /// - [`ConversionError::Overflow`] : the whole part doesn't fit in a `u128`
+    /// - [`ConversionError::PrecisionLoss`] : the fractional part has too many digits
+    pub(crate) fn split(s: &str) -> Result<(Self, &str), ConversionError> {
+        let (whole, fraction, rest) = split_number(s, &['.'])?;
+        Ok((Self::from_digits(whole, fraction.unwrap_or(""))?, rest))
+    }
+
+    /// Splits a leading ISO 8601 decimal number off of `s`, returning it and the rest of the string
+    ///
+    /// The decimal sign is either a comma or a full stop and must be followed by at least one
+    /// digit.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`split()`](Self::split).
+    pub(crate) fn split_iso8601(s: &str) -> Result<(Self, &str), ConversionError> {
+        match split_number(s, &['.', ','])? {
+            (_, Some(""), _) => Err(ConversionError::ParseFailure),
+            (whole, fraction, rest) => {
+                Ok((Self::from_digits(whole, fraction.unwrap_or(""))?, rest))
+            }
+        }
+    }
+
+    /// Returns the decimal number with the given whole and fractional digits
+    fn from_digits(whole: &str, fraction: &str) -> Result<Self, ConversionError> {
+        // trailing zeros of the fractional part don't add precision
+        let fraction = fraction.trim_end_matches('0');
+
+        Ok(Self {
+            whole: parse_digits(whole).ok_or(ConversionError::Overflow)?,
+            fraction: parse_digits(fraction).ok_or(ConversionError::PrecisionLoss)?,
+            divisor: 10_u128
+                .checked_pow(fraction.len() as u32)
+                .ok_or(ConversionError::PrecisionLoss)?,
+        })
+    }
+
+    /// Returns `self × multiplier`
+    ///
+    /// # Errors
+    ///
+    /// - [`ConversionError::Overflow`] : the whole part of the result doesn't fit in a `u128`
+    /// - [`ConversionError::PrecisionLoss`] : the fractional part has too many digits
+    pub(crate) fn checked_mul(&self, multiplier: u128) -> Result<Self, ConversionError> {
+        let fraction = self
+            .fraction
+            .checked_mul(multiplier)
+            .ok_or(ConversionError::PrecisionLoss)?;
+        let whole = self
+            .whole
+            .checked_mul(multiplier)
+            .and_then(|whole| whole.checked_add(fraction / self.divisor))
+            .ok_or(ConversionError::Overflow)?;
+
+        Ok(Self {
+            whole,
+            fraction: fraction % self.divisor,
+            divisor: self.divisor,
+        })
+    }
+
+    /// Returns `self + rhs`
+    ///
+    /// # Errors
+    ///
+    /// - [`ConversionError::Overflow`] : the whole part of the result doesn't fit in a `u128`
+    pub(crate) fn checked_add(&self, rhs: &Self) -> Result<Self, ConversionError> {
+        let (fine, coarse) = if self.divisor >= rhs.divisor {
+            (self, rhs)
+        } else {
+            (rhs, self)
+        };
+
+        // both divisors are powers of 10 and each fraction is less than its divisor, so this can't
+        // overflow
+        let fraction = fine.fraction + coarse.fraction * (fine.divisor / coarse.divisor);
+        let whole = fine
+            .whole
+            .checked_add(coarse.whole)
+            .and_then(|whole| whole.checked_add(fraction / fine.divisor))
+            .ok_or(ConversionError::Overflow)?;
+
+        Ok(Self {
+            whole,
+            fraction: fraction % fine.divisor,
+            divisor: fine.divisor,
+        })
+    }
+
+    /// Returns `self × numerator / denominator` if the result is an integer
+    ///
+    /// # Errors
+    ///
+    /// - [`ConversionError::Overflow`] : the result doesn't fit in a `u128`
+    /// - [`ConversionError::PrecisionLoss`] : the result is not an integer
+    /// - [`ConversionError::DivByZero`] : `denominator` is `0`
+    pub(crate) fn checked_mul_ratio(
+        &self,
+        numerator: u128,
+        denominator: u128,
+    ) -> Result<u128, ConversionError> {
+        if denominator == 0 {
+            return Err(ConversionError::DivByZero);
+        }
+
+        let whole = self
+            .whole
+            .checked_mul(numerator)
+            .ok_or(ConversionError::Overflow)?;
+        let fraction = self
+            .fraction
+            .checked_mul(numerator)
+            .ok_or(ConversionError::PrecisionLoss)?;
+        if fraction % self.divisor != 0 {
+            return Err(ConversionError::PrecisionLoss);
+        }
+
+        let scaled = whole
+            .checked_add(fraction / self.divisor)
+            .ok_or(ConversionError::Overflow)?;
+        if scaled % denominator != 0 {
+            return Err(ConversionError::PrecisionLoss);
+        }
+
+        Ok(scaled / denominator)
+    }
+}
+
+/// Splits a leading number off of `s`, returning its whole digits, its fractional digits (if it
+/// has a decimal sign) and the rest of the string
+///
+/// Returns [`ConversionError::ParseFailure`] if there are no digits or more than one decimal sign.
+fn split_number<'a>(
+    s: &'a str,
+    separators: &[char],
+) -> Result<(&'a str, Option<&'a str>, &'a str), ConversionError> {
+    let number_len = s
+        .find(|c: char| !c.is_ascii_digit() && !separators.contains(&c))
+        .unwrap_or(s.len());
+    let (number, rest) = s.split_at(number_len);
+    let (whole, fraction) = match number.find(separators) {
+        Some(separator) => (&number[..separator], Some(&number[separator + 1..])),
+        None => (number, None),
+    };
+    match fraction {
+        Some(fraction) if fraction.contains(separators) => Err(ConversionError::ParseFailure),
+        Some("") | None if whole.is_empty() => Err(ConversionError::ParseFailure),
+        _ => Ok((whole, fraction, rest)),
+    }
+}
+
+/// Parse a string of ASCII digits, returning `None` upon overflow
+fn parse_digits(digits: &str) -> Option<u128> {
+    digits.bytes().try_fold(0_u128, |value, digit| {
+        value.checked_mul(10)?.checked_add(u128::from(digit - b'0'))
+    })
+}
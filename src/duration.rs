@@ -1,21 +1,27 @@
 //! Duration types/units
 
+#[cfg(any(feature = "f32", feature = "f64"))]
+use crate::float;
 pub use crate::fraction::Fraction;
 use crate::{
+    decimal::{self, Decimal},
     fixed_point::{self, FixedPoint},
-    rate,
-    time_int::TimeInt,
+    iso8601, rate,
+    time_int::{SignedTimeInt, TimeInt},
     ConversionError,
 };
 use core::{
     convert::TryFrom,
+    fmt::{self, Write as _},
     hash::{Hash, Hasher},
-    mem::size_of,
+    marker::PhantomData,
+    ops,
     prelude::v1::*,
+    str::FromStr,
 };
 #[doc(hidden)]
 pub use fixed_point::FixedPoint as _;
-use num::{CheckedDiv, CheckedMul};
+use num::{traits::CheckedNeg, CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, Signed as _};
 #[doc(inline)]
 pub use units::*;
 
@@ -53,6 +59,85 @@ pub use units::*;
 /// assert_eq!(format!("{}", Seconds(123_u32)), "123");
 /// ```
 ///
+/// For human-readable output with units, see [`Duration::display_human()`].
+///
+/// # Parsing
+///
+/// `Duration`s (including [`Generic`]) implement [`FromStr`]. The string
+/// consists of one or more integer or decimal values, each followed by a unit suffix: `ps`, `ns`,
+/// `us` (or `µs`), `ms`, `s`, `min` (or `m`), `h`, `d` and `w`. Whitespace is allowed between values
+/// and units. The units must go from coarse to fine, each at most once.
+///
+/// ```rust
+/// use embedded_time::duration::*;
+///
+/// assert_eq!("250ms".parse(), Ok(Milliseconds(250_u32)));
+/// assert_eq!("1.5s".parse(), Ok(Milliseconds(1_500_u32)));
+/// assert_eq!("1h30m".parse(), Ok(Minutes(90_u32)));
+/// assert_eq!("1h 30min 15.5s".parse(), Ok(Milliseconds(5_415_500_u64)));
+/// ```
+///
+/// A [`Generic`] `Duration` uses the coarsest unit (weeks to picoseconds) that represents the
+/// value exactly.
+///
+/// ```rust
+/// use embedded_time::duration::*;
+///
+/// assert_eq!(
+///     "1.5s".parse::<Generic<u32>>().map(|generic| *generic.scaling_factor()),
+///     Ok(Fraction::new(1, 1_000))
+/// );
+/// ```
+///
+/// ## Errors
+///
+/// [`ConversionError::ParseFailure`] : The string is not a valid duration
+///
+/// ```rust
+/// use embedded_time::{duration::*, ConversionError};
+///
+/// assert_eq!("250".parse::<Milliseconds>(), Err(ConversionError::ParseFailure));
+/// assert_eq!("1.5 fortnights".parse::<Seconds>(), Err(ConversionError::ParseFailure));
+/// assert_eq!("10ms5s".parse::<Milliseconds>(), Err(ConversionError::ParseFailure));
+/// ```
+///
+/// ---
+///
+/// [`ConversionError::PrecisionLoss`] : The value cannot be represented exactly in the
+/// destination type
+///
+/// ```rust
+/// use embedded_time::{duration::*, ConversionError};
+///
+/// assert_eq!("1.5s".parse::<Seconds>(), Err(ConversionError::PrecisionLoss));
+/// assert_eq!("0.1ns".parse::<Nanoseconds>(), Err(ConversionError::PrecisionLoss));
+/// ```
+///
+/// ---
+///
+/// [`ConversionError::Overflow`] : The value doesn't fit in the destination type
+///
+/// ```rust
+/// use embedded_time::{duration::*, ConversionError};
+///
+/// assert_eq!("5s".parse::<Nanoseconds<u32>>(), Err(ConversionError::Overflow));
+/// ```
+///
+/// # ISO 8601
+///
+/// `Duration`s can be written as and read from ISO 8601 durations (eg. `PT1H30M0.250S`) without
+/// allocating. See [`Duration::write_iso8601()`] and [`Duration::from_iso8601()`].
+///
+/// ```rust
+/// use embedded_time::duration::*;
+///
+/// let mut iso = String::new();
+/// Milliseconds(5_400_250_u32).write_iso8601(&mut iso).unwrap();
+///
+/// assert_eq!(iso, "PT1H30M0.250S");
+/// assert_eq!(Milliseconds::<u32>::from_iso8601(&iso), Ok(Milliseconds(5_400_250_u32)));
+/// ```
+///
 /// # Getting H:M:S.MS... Components
 ///
 /// ```rust
@@ -94,6 +179,29 @@ pub use units::*;
 /// assert_eq!(millis.integer(), 23_000_u32);
 /// ```
 ///
+/// # Constant evaluation
+///
+/// Construction, [`integer()`](Seconds::integer), widening and conversions to any _scaling
+/// factor_ of units with a concrete integer type are `const fn`, so reload values and scheduling
+/// tables can be computed at compile time. A conversion that fails in a `const` context is a
+/// compile-time error.
+///
+/// ```rust
+/// use embedded_time::duration::*;
+///
+/// const TIMEOUT: Milliseconds<u32> = Milliseconds::new(250);
+/// const TIMEOUT_TICKS: u32 = TIMEOUT.const_into_ticks(Fraction::new(1, 32_768));
+/// const TIMEOUT_MICROS: Microseconds<u64> = Microseconds(
+///     TIMEOUT
+///         .const_widen()
+///         .const_into_ticks(Microseconds::<u64>::SCALING_FACTOR),
+/// );
+///
+/// assert_eq!(TIMEOUT_TICKS, 8_192);
+/// assert_eq!(TIMEOUT_MICROS, Microseconds(250_000_u64));
+/// assert_eq!(Seconds(u32::MAX).const_checked_into_ticks(Fraction::new(1, 1_000)), None);
+/// ```
+///
 /// # Converting to `core` types
 ///
 /// ([`core::time::Duration`])
@@ -194,7 +302,7 @@ pub use units::*;
 ///
 /// ---
 ///
-/// [`ConversionError::Unspecified`]
+/// [`ConversionError::Overflow`] : The result doesn't fit in either integer type.
 ///
 /// ```rust
 /// use embedded_time::{duration::*, ConversionError};
@@ -202,7 +310,7 @@ pub use units::*;
 ///
 /// assert_eq!(
 ///     Seconds::<u32>::try_from(Generic::new(u32::MAX, Fraction::new(10,1))),
-///     Err(ConversionError::Unspecified)
+///     Err(ConversionError::Overflow)
 /// );
 /// ```
 ///
@@ -240,6 +348,20 @@ pub use units::*;
 ///
 /// See [`Duration::to_rate()`]
 ///
+/// # Creating a custom `Duration`
+///
+/// [`Scaled`] is a duration unit with any _scaling factor_ known at compile time:
+///
+/// ```rust
+/// use core::convert::TryFrom;
+/// use embedded_time::duration::*;
+///
+/// // 10 ms system ticks
+/// type SysTicks = Scaled<u32, 1, 100>;
+///
+/// assert_eq!(SysTicks::try_from(Seconds(2_u32)), Ok(Scaled(200_u32)));
+/// ```
+///
 /// # Add/Sub
 ///
 /// The result of the operation is the LHS type
@@ -266,10 +388,17 @@ pub use units::*;
 /// let _ = Seconds(u32::MAX) + Seconds(1_u32);
 /// ```
 ///
+/// [`saturating_add()`](fixed_point::FixedPoint::saturating_add) and
+/// [`saturating_sub()`](fixed_point::FixedPoint::saturating_sub) clamp the result instead.
+/// [`checked_add()`](Duration::checked_add) and [`checked_sub()`](Duration::checked_sub) return an
+/// error instead.
+///
+/// [`add_finer()`](AddFiner::add_finer) returns the finer of the two units instead of the LHS type.
+///
 /// # Mul/Div
 ///
-/// Durations may also be multiplied and divided by integers. The result is of the LHS type. Both
-/// _panicky_ and _checked_ operations are available.
+/// Durations may also be multiplied and divided by integers. The result is of the LHS type.
+/// _Panicky_, _checked_ and _saturating_ (multiplication only) operations are available.
 ///
 /// # Comparisons
 ///
@@ -313,14 +442,14 @@ pub trait Duration: Sized + Copy {
     ///
     /// ---
     ///
-    /// [`ConversionError::Unspecified`]
+    /// [`ConversionError::Overflow`] : The result doesn't fit in either integer type.
     ///
     /// ```rust
     /// use embedded_time::{duration::*, ConversionError};
     ///
     /// assert_eq!(
     ///     Seconds(u32::MAX).to_generic::<u32>(Fraction::new(1, 2)),
-    ///     Err(ConversionError::Unspecified)
+    ///     Err(ConversionError::Overflow)
     /// );
     /// ```
     ///
@@ -370,13 +499,13 @@ pub trait Duration: Sized + Copy {
     ///
     /// ---
     ///
-    /// [`ConversionError::Overflow`] : The conversion of the _scaling factor_ causes an overflow.
+    /// [`ConversionError::Overflow`] : The result doesn't fit in the destination type.
     ///
     /// ```rust
     /// use embedded_time::{duration::*, rate::*, ConversionError};
     ///
     /// assert_eq!(
-    ///     Hours(u32::MAX).to_rate::<Megahertz<u32>>(),
+    ///     Nanoseconds(1_u32).to_rate::<Millihertz<u32>>(),
     ///     Err(ConversionError::Overflow)
     /// );
     /// ```
@@ -399,113 +528,1665 @@ pub trait Duration: Sized + Copy {
         Self: FixedPoint,
         Rate::T: TryFrom<Self::T>,
     {
-        let conversion_factor = Self::SCALING_FACTOR
-            .checked_mul(&Rate::SCALING_FACTOR)
-            .ok_or(ConversionError::Unspecified)?
-            .recip();
-
-        if size_of::<Self::T>() >= size_of::<Rate::T>() {
-            fixed_point::FixedPoint::from_ticks(
-                Self::T::from(*conversion_factor.numerator())
-                    .checked_div(
-                        &self
-                            .integer()
-                            .checked_mul(&Self::T::from(*conversion_factor.denominator()))
-                            .ok_or(ConversionError::Overflow)?,
-                    )
-                    .ok_or(ConversionError::DivByZero)?,
-                Rate::SCALING_FACTOR,
-            )
-        } else {
-            fixed_point::FixedPoint::from_ticks(
-                Rate::T::from(*conversion_factor.numerator())
-                    .checked_div(
-                        &Rate::T::try_from(self.integer())
-                            .map_err(|_| ConversionError::Overflow)?
-                            .checked_mul(&Rate::T::from(*conversion_factor.denominator()))
-                            .ok_or(ConversionError::Overflow)?,
-                    )
-                    .ok_or(ConversionError::DivByZero)?,
-                Rate::SCALING_FACTOR,
-            )
-        }
+        let ticks = fixed_point::reciprocal_ticks(
+            self.integer().into_u128(),
+            Self::SCALING_FACTOR,
+            Rate::SCALING_FACTOR,
+        )?;
+        Ok(FixedPoint::new(
+            Rate::T::try_from_u128(ticks).ok_or(ConversionError::Overflow)?,
+        ))
     }
-}
-
-/// The `Generic` `Duration` type allows an arbitrary _scaling factor_ to be used without having to
-/// impl `FixedPoint`.
-///
-/// The purpose of this type is to allow a simple `Duration` object that can be defined at run-time.
-/// It does this by replacing the `const` _scaling factor_ with a struct field.
-#[derive(Copy, Clone, Debug, Default)]
-pub struct Generic<T> {
-    integer: T,
-    scaling_factor: Fraction,
-}
 
-impl<T: TimeInt> PartialOrd<Generic<T>> for Generic<T> {
-    /// See [Comparisons](trait.Duration.html#comparisons)
-    fn partial_cmp(&self, rhs: &Generic<T>) -> Option<core::cmp::Ordering> {
-        Some(
-            self.integer
-                .checked_mul_fraction(&self.scaling_factor)?
-                .cmp(&rhs.integer.checked_mul_fraction(&rhs.scaling_factor)?),
-        )
+    /// Returns a [`DisplayHuman`] that formats the duration using the largest sensible units
+    ///
+    /// See [`DisplayHuman`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use embedded_time::duration::*;
+    ///
+    /// assert_eq!(format!("{}", Milliseconds(3_723_004_u32).display_human()), "1h 02m 03.004s");
+    /// assert_eq!(format!("{}", Nanoseconds(250_000_u32).display_human()), "250 µs");
+    /// ```
+    fn display_human(&self) -> DisplayHuman
+    where
+        Self: FixedPoint,
+        u64: From<Self::T>,
+    {
+        DisplayHuman::new(self.integer().into(), Self::SCALING_FACTOR)
     }
-}
 
-impl<T: TimeInt> Ord for Generic<T> {
-    fn cmp(&self, rhs: &Generic<T>) -> core::cmp::Ordering {
-        if let Some(v) = self.partial_cmp(rhs) {
-            v
-        } else {
-            panic!("Cmp failed")
-        }
+    /// Writes the duration as an ISO 8601 duration (eg. `PT1H30M0.250S`)
+    ///
+    /// Only the hours (`H`), minutes (`M`) and seconds (`S`) designators are written. Zero
+    /// components are omitted, except for a zero duration which is written as `PT0S`. A
+    /// fractional part of the seconds is written with the number of digits of the type's
+    /// resolution (eg. 3 for [`Milliseconds`]).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use embedded_time::duration::*;
+    ///
+    /// let mut iso = String::new();
+    /// Seconds(3_600_u32).write_iso8601(&mut iso).unwrap();
+    /// assert_eq!(iso, "PT1H");
+    ///
+    /// let mut iso = String::new();
+    /// Microseconds(90_000_500_u32).write_iso8601(&mut iso).unwrap();
+    /// assert_eq!(iso, "PT1M30.000500S");
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// [`core::fmt::Error`] : The writer fails or the value cannot be written exactly as a
+    /// decimal number.
+    fn write_iso8601<W: fmt::Write>(&self, w: &mut W) -> fmt::Result
+    where
+        Self: FixedPoint,
+        u64: From<Self::T>,
+    {
+        iso8601::write(w, self.integer().into(), &Self::SCALING_FACTOR)
     }
-}
 
-impl<T: TimeInt> PartialEq<Generic<T>> for Generic<T> {
-    /// See [Comparisons](trait.Duration.html#comparisons)
-    fn eq(&self, rhs: &Generic<T>) -> bool {
-        self.partial_cmp(rhs) == Some(core::cmp::Ordering::Equal)
+    /// Parses an ISO 8601 duration (eg. `PT1H30M0.250S`)
+    ///
+    /// The weeks (`W`) and days (`D`) designators are accepted as exactly 7 and 1 days. Years and
+    /// months are rejected as their length varies. The decimal sign is either a comma or a full
+    /// stop.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use embedded_time::duration::*;
+    ///
+    /// assert_eq!(Milliseconds::<u32>::from_iso8601("PT1H30M0.250S"), Ok(Milliseconds(5_400_250_u32)));
+    /// assert_eq!(Milliseconds::<u32>::from_iso8601("PT0,5S"), Ok(Milliseconds(500_u32)));
+    /// assert_eq!(Hours::<u32>::from_iso8601("P1W2D"), Ok(Hours(216_u32)));
+    /// assert_eq!(Seconds::<u32>::from_iso8601("PT0.5M"), Ok(Seconds(30_u32)));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// [`ConversionError::ParseFailure`] : The string is not a valid ISO 8601 duration (or uses
+    /// years or months)
+    ///
+    /// ```rust
+    /// use embedded_time::{duration::*, ConversionError};
+    ///
+    /// assert_eq!(Seconds::<u32>::from_iso8601("PT"), Err(ConversionError::ParseFailure));
+    /// assert_eq!(Seconds::<u32>::from_iso8601("P1M"), Err(ConversionError::ParseFailure));
+    /// ```
+    ///
+    /// ---
+    ///
+    /// [`ConversionError::PrecisionLoss`] : The value cannot be represented exactly in the
+    /// destination type
+    ///
+    /// ```rust
+    /// use embedded_time::{duration::*, ConversionError};
+    ///
+    /// assert_eq!(Seconds::<u32>::from_iso8601("PT0.5S"), Err(ConversionError::PrecisionLoss));
+    /// ```
+    ///
+    /// ---
+    ///
+    /// [`ConversionError::Overflow`] : The value doesn't fit in the destination type
+    ///
+    /// ```rust
+    /// use embedded_time::{duration::*, ConversionError};
+    ///
+    /// assert_eq!(Nanoseconds::<u32>::from_iso8601("PT5S"), Err(ConversionError::Overflow));
+    /// ```
+    fn from_iso8601(s: &str) -> Result<Self, ConversionError>
+    where
+        Self: FixedPoint,
+        Self::T: TryFrom<u128>,
+    {
+        let ticks = iso8601::seconds_to_ticks(&iso8601::parse(s)?, &Self::SCALING_FACTOR)?;
+        Ok(Self::new(
+            Self::T::try_from(ticks).map_err(|_| ConversionError::Overflow)?,
+        ))
     }
-}
-
-impl<T: TimeInt> Eq for Generic<T> {}
 
-impl<T: TimeInt + Hash> Hash for Generic<T> {
-    fn hash<H: Hasher>(&self, state: &mut H) {
-        if let Some(v) = self.integer.checked_mul_fraction(&self.scaling_factor) {
-            v.hash(state);
-        }
+    /// Returns the duration in seconds as an `f32`, rounded to the nearest representable value
+    ///
+    /// ```rust
+    /// use embedded_time::duration::*;
+    ///
+    /// assert_eq!(Milliseconds(1_500_u32).as_secs_f32(), 1.5);
+    /// ```
+    #[cfg(feature = "f32")]
+    fn as_secs_f32(&self) -> f32
+    where
+        Self: FixedPoint,
+        u64: From<Self::T>,
+    {
+        float::to_f32(self.integer().into(), &Self::SCALING_FACTOR)
     }
-}
 
-impl<T: TimeInt> Generic<T> {
-    /// Constructs a new fixed-point `Generic` `Duration` value
-    pub fn new(integer: T, scaling_factor: Fraction) -> Self {
-        Self {
-            integer,
-            scaling_factor,
-        }
+    /// Returns the duration in seconds as an `f64`, rounded to the nearest representable value
+    ///
+    /// ```rust
+    /// use embedded_time::duration::*;
+    ///
+    /// assert_eq!(Microseconds(250_u32).as_secs_f64(), 0.000_25);
+    /// ```
+    #[cfg(feature = "f64")]
+    fn as_secs_f64(&self) -> f64
+    where
+        Self: FixedPoint,
+        u64: From<Self::T>,
+    {
+        float::to_f64(self.integer().into(), &Self::SCALING_FACTOR)
     }
 
-    /// Returns the _integer_ part
-    pub fn integer(&self) -> T {
-        self.integer
+    /// Constructs a duration from seconds as an `f32`, rounded to the nearest _integer_ (ties to
+    /// even)
+    ///
+    /// ```rust
+    /// use embedded_time::duration::*;
+    ///
+    /// assert_eq!(Milliseconds::<u32>::from_secs_f32(1.5), Ok(Milliseconds(1_500_u32)));
+    /// assert_eq!(Seconds::<u32>::from_secs_f32(2.5), Ok(Seconds(2_u32)));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// - [`ConversionError::ConversionFailure`] : The value is negative or NaN
+    /// - [`ConversionError::Overflow`] : The value is infinite or doesn't fit in the type
+    #[cfg(feature = "f32")]
+    fn from_secs_f32(secs: f32) -> Result<Self, ConversionError>
+    where
+        Self: FixedPoint,
+        Self::T: TryFrom<u128>,
+    {
+        let ticks = float::to_ticks(f64::from(secs), &Self::SCALING_FACTOR)?;
+        Ok(Self::new(
+            Self::T::try_from(ticks).map_err(|_| ConversionError::Overflow)?,
+        ))
     }
 
-    /// Returns the _scaling factor_ [`Fraction`] part
-    pub fn scaling_factor(&self) -> &Fraction {
-        &self.scaling_factor
+    /// Constructs a duration from seconds as an `f64`, rounded to the nearest _integer_ (ties to
+    /// even)
+    ///
+    /// ```rust
+    /// use embedded_time::duration::*;
+    ///
+    /// assert_eq!(Microseconds::<u32>::from_secs_f64(0.000_000_6), Ok(Microseconds(1_u32)));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// - [`ConversionError::ConversionFailure`] : The value is negative or NaN
+    /// - [`ConversionError::Overflow`] : The value is infinite or doesn't fit in the type
+    ///
+    /// ```rust
+    /// use embedded_time::{duration::*, ConversionError};
+    ///
+    /// assert_eq!(Seconds::<u32>::from_secs_f64(-1.0), Err(ConversionError::ConversionFailure));
+    /// assert_eq!(Seconds::<u32>::from_secs_f64(f64::NAN), Err(ConversionError::ConversionFailure));
+    /// assert_eq!(Seconds::<u32>::from_secs_f64(5e9), Err(ConversionError::Overflow));
+    /// ```
+    #[cfg(feature = "f64")]
+    fn from_secs_f64(secs: f64) -> Result<Self, ConversionError>
+    where
+        Self: FixedPoint,
+        Self::T: TryFrom<u128>,
+    {
+        let ticks = float::to_ticks(secs, &Self::SCALING_FACTOR)?;
+        Ok(Self::new(
+            Self::T::try_from(ticks).map_err(|_| ConversionError::Overflow)?,
+        ))
     }
-}
 
-impl<T: TimeInt> Duration for Generic<T> {}
+    /// Checked addition of a duration of any compatible unit
+    ///
+    /// The RHS is converted into the LHS type first, which fails rather than truncating a RHS of a
+    /// finer unit. Unlike the `+` operator, this never panics.
+    ///
+    /// ```rust
+    /// use embedded_time::{duration::*, ConversionError};
+    ///
+    /// assert_eq!(Milliseconds(500_u32).checked_add(Seconds(1_u32)), Ok(Milliseconds(1_500_u32)));
+    /// assert_eq!(Milliseconds(u32::MAX).checked_add(Seconds(1_u32)), Err(ConversionError::Overflow));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// - [`ConversionError::Overflow`] : The result doesn't fit in the LHS type
+    /// - [`ConversionError::PrecisionLoss`] : The RHS can't be represented exactly in the LHS unit
+    /// - Any error from converting the RHS into the LHS type
+    fn checked_add<Rhs>(self, rhs: Rhs) -> Result<Self, ConversionError>
+    where
+        Self: FixedPoint + TryFrom<Rhs>,
+        Rhs: Duration + FixedPoint,
+        ConversionError: From<<Self as TryFrom<Rhs>>::Error>,
+    {
+        let rhs: Self = fixed_point::try_from_exact(rhs)?;
+        self.integer()
+            .checked_add(&rhs.integer())
+            .map(Self::new)
+            .ok_or(ConversionError::Overflow)
+    }
 
-/// Duration units
-#[doc(hidden)]
-pub mod units {
+    /// Checked subtraction of a duration of any compatible unit
+    ///
+    /// The RHS is converted into the LHS type first, which fails rather than truncating a RHS of a
+    /// finer unit. Unlike the `-` operator, this never panics.
+    ///
+    /// ```rust
+    /// use embedded_time::{duration::*, ConversionError};
+    ///
+    /// assert_eq!(Milliseconds(1_500_u32).checked_sub(Seconds(1_u32)), Ok(Milliseconds(500_u32)));
+    /// assert_eq!(Milliseconds(500_u32).checked_sub(Seconds(1_u32)), Err(ConversionError::NegDuration));
+    /// assert_eq!(
+    ///     Seconds(2_u32).checked_sub(Milliseconds(1_500_u32)),
+    ///     Err(ConversionError::PrecisionLoss)
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// - [`ConversionError::NegDuration`] : The result is negative
+    /// - [`ConversionError::PrecisionLoss`] : The RHS can't be represented exactly in the LHS unit
+    /// - Any error from converting the RHS into the LHS type
+    fn checked_sub<Rhs>(self, rhs: Rhs) -> Result<Self, ConversionError>
+    where
+        Self: FixedPoint + TryFrom<Rhs>,
+        Rhs: Duration + FixedPoint,
+        ConversionError: From<<Self as TryFrom<Rhs>>::Error>,
+    {
+        let rhs: Self = fixed_point::try_from_exact(rhs)?;
+        self.integer()
+            .checked_sub(&rhs.integer())
+            .map(Self::new)
+            .ok_or(ConversionError::NegDuration)
+    }
+
+    /// Checked remainder of a division by a duration of any compatible unit
+    ///
+    /// The RHS is converted into the LHS type first, which fails rather than truncating a RHS of a
+    /// finer unit. Unlike the `%` operator, a zero RHS is an error.
+    ///
+    /// ```rust
+    /// use embedded_time::{duration::*, ConversionError};
+    ///
+    /// assert_eq!(Minutes(62_u32).checked_rem(Hours(1_u32)), Ok(Minutes(2_u32)));
+    /// assert_eq!(Minutes(62_u32).checked_rem(Hours(0_u32)), Err(ConversionError::DivByZero));
+    /// assert_eq!(
+    ///     Seconds(5_u32).checked_rem(Milliseconds(500_u32)),
+    ///     Err(ConversionError::PrecisionLoss)
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// - [`ConversionError::DivByZero`] : The RHS is `0`
+    /// - [`ConversionError::PrecisionLoss`] : The RHS can't be represented exactly in the LHS unit
+    /// - Any error from converting the RHS into the LHS type
+    fn checked_rem<Rhs>(self, rhs: Rhs) -> Result<Self, ConversionError>
+    where
+        Self: FixedPoint + TryFrom<Rhs>,
+        Rhs: Duration + FixedPoint,
+        ConversionError: From<<Self as TryFrom<Rhs>>::Error>,
+    {
+        let rhs: Self = fixed_point::try_from_exact(rhs)?;
+        if rhs.integer() == Self::T::from(0) {
+            Err(ConversionError::DivByZero)
+        } else {
+            Ok(Self::new(self.integer() % rhs.integer()))
+        }
+    }
+}
+
+/// Addition of durations with the finer of the two units as the result
+///
+/// The `+` operator converts the RHS into the LHS type, which truncates when the LHS is the
+/// coarser unit. `add_finer()` converts into whichever unit has the smaller _scaling factor_
+/// instead, so no precision is lost.
+///
+/// Implemented for every pair of _named_ durations with the same integer type.
+///
+/// ```rust
+/// use embedded_time::duration::*;
+///
+/// assert_eq!(Seconds(1_u32) + Milliseconds(500_u32), Seconds(1_u32));
+/// assert_eq!(Seconds(1_u32).add_finer(Milliseconds(500_u32)), Milliseconds(1_500_u32));
+/// assert_eq!(Milliseconds(500_u32).add_finer(Seconds(1_u32)), Milliseconds(1_500_u32));
+/// ```
+pub trait AddFiner<Rhs>: Sized {
+    /// The finer of `Self` and `Rhs`
+    type Output: Duration;
+
+    /// Checked addition in the finer unit
+    ///
+    /// ```rust
+    /// use embedded_time::{duration::*, ConversionError};
+    ///
+    /// assert_eq!(
+    ///     Minutes(1_u32).checked_add_finer(Seconds(30_u32)),
+    ///     Ok(Seconds(90_u32))
+    /// );
+    /// assert_eq!(
+    ///     Seconds(1_u32).checked_add_finer(Milliseconds(u32::MAX)),
+    ///     Err(ConversionError::Overflow)
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// - [`ConversionError::Overflow`] : The result doesn't fit in the finer unit
+    /// - Any error from converting the coarser value into the finer unit
+    fn checked_add_finer(self, rhs: Rhs) -> Result<Self::Output, ConversionError>;
+
+    /// Panicky addition in the finer unit
+    ///
+    /// # Panics
+    ///
+    /// See [`AddFiner::checked_add_finer()`]
+    fn add_finer(self, rhs: Rhs) -> Self::Output {
+        self.checked_add_finer(rhs).expect("Add failed")
+    }
+}
+
+/// Human-readable formatting of a duration
+///
+/// Returned by [`Duration::display_human()`] and [`Generic::display_human()`]. The duration is
+/// broken into the largest sensible components without allocating:
+///
+/// | Duration       | Output               |
+/// | :------------- | :------------------- |
+/// | 0              | `0 s`                |
+/// | < 1 ns         | `250 ps`             |
+/// | < 1 µs         | `1.5 ns`             |
+/// | < 1 ms         | `250 µs`             |
+/// | < 1 s          | `1.5 ms`             |
+/// | < 1 min        | `3.004 s`            |
+/// | < 1 h          | `2m 03.004s`         |
+/// | < 1 d          | `1h 02m 03.004s`     |
+/// | ≥ 1 d          | `3d 01h 02m 03.004s` |
+///
+/// By default, the fractional part is written with as many digits as needed (down to picosecond
+/// resolution). The precision of the formatter sets a fixed number of fractional digits instead.
+/// The width, fill and alignment are honoured as for strings (left-aligned by default).
+///
+/// **The value is truncated, not rounded**.
+///
+/// # Examples
+///
+/// ```rust
+/// use embedded_time::duration::*;
+///
+/// let duration = Microseconds(1_500_u32);
+///
+/// assert_eq!(format!("{}", duration.display_human()), "1.5 ms");
+/// assert_eq!(format!("{:.3}", duration.display_human()), "1.500 ms");
+/// assert_eq!(format!("{:.0}", duration.display_human()), "1 ms");
+///
+/// assert_eq!(format!("{}", Seconds(0_u32).display_human()), "0 s");
+/// assert_eq!(format!("{:.1}", Seconds(125_u32).display_human()), "2m 05.0s");
+///
+/// assert_eq!(format!("[{:>8}]", duration.display_human()), "[  1.5 ms]");
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct DisplayHuman {
+    integer: u64,
+    scaling_factor: Fraction,
+}
+
+impl DisplayHuman {
+    const PICOS_PER_NANO: u128 = 1_000;
+    const PICOS_PER_MICRO: u128 = 1_000_000;
+    const PICOS_PER_MILLI: u128 = 1_000_000_000;
+    const PICOS_PER_SEC: u128 = 1_000_000_000_000;
+    const SECS_PER_MIN: u128 = 60;
+    const SECS_PER_HOUR: u128 = 60 * Self::SECS_PER_MIN;
+    const SECS_PER_DAY: u128 = 24 * Self::SECS_PER_HOUR;
+
+    fn new(integer: u64, scaling_factor: Fraction) -> Self {
+        Self {
+            integer,
+            scaling_factor,
+        }
+    }
+
+    /// Writes the value and unit, ignoring the width, fill and alignment of the formatter
+    fn write_unpadded(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // split into whole seconds and picoseconds so that no intermediate can overflow
+        let ticks = u128::from(self.integer) * u128::from(*self.scaling_factor.numerator());
+        let denominator = u128::from(*self.scaling_factor.denominator());
+        let (seconds, picos) = match (
+            ticks.checked_div(denominator),
+            ticks.checked_rem(denominator),
+        ) {
+            (Some(seconds), Some(remainder)) => {
+                (seconds, remainder * Self::PICOS_PER_SEC / denominator)
+            }
+            _ => return write!(f, "{} x {} s", self.integer, self.scaling_factor),
+        };
+
+        if seconds == 0 {
+            if picos == 0 {
+                decimal::write(f, 0, 1, 0)?;
+                f.write_str(" s")
+            } else if picos < Self::PICOS_PER_NANO {
+                decimal::write(f, picos, 1, 0)?;
+                f.write_str(" ps")
+            } else if picos < Self::PICOS_PER_MICRO {
+                decimal::write(f, picos, Self::PICOS_PER_NANO, 3)?;
+                f.write_str(" ns")
+            } else if picos < Self::PICOS_PER_MILLI {
+                decimal::write(f, picos, Self::PICOS_PER_MICRO, 6)?;
+                f.write_str(" µs")
+            } else {
+                decimal::write(f, picos, Self::PICOS_PER_MILLI, 9)?;
+                f.write_str(" ms")
+            }
+        } else if seconds < Self::SECS_PER_MIN {
+            decimal::write(
+                f,
+                seconds * Self::PICOS_PER_SEC + picos,
+                Self::PICOS_PER_SEC,
+                12,
+            )?;
+            f.write_str(" s")
+        } else {
+            let days = seconds / Self::SECS_PER_DAY;
+            let hours = seconds % Self::SECS_PER_DAY / Self::SECS_PER_HOUR;
+            let minutes = seconds % Self::SECS_PER_HOUR / Self::SECS_PER_MIN;
+            let seconds = seconds % Self::SECS_PER_MIN;
+
+            if days > 0 {
+                write!(f, "{}d {:02}h {:02}m ", days, hours, minutes)?;
+            } else if hours > 0 {
+                write!(f, "{}h {:02}m ", hours, minutes)?;
+            } else {
+                write!(f, "{}m ", minutes)?;
+            }
+            if seconds < 10 {
+                f.write_char('0')?;
+            }
+            decimal::write(
+                f,
+                seconds * Self::PICOS_PER_SEC + picos,
+                Self::PICOS_PER_SEC,
+                12,
+            )?;
+            f.write_char('s')
+        }
+    }
+}
+
+impl fmt::Display for DisplayHuman {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        decimal::pad(f, |f| self.write_unpadded(f))
+    }
+}
+
+/// The `Generic` `Duration` type allows an arbitrary _scaling factor_ to be used without having to
+/// impl `FixedPoint`.
+///
+/// The purpose of this type is to allow a simple `Duration` object that can be defined at run-time.
+/// It does this by replacing the `const` _scaling factor_ with a struct field.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct Generic<T> {
+    integer: T,
+    scaling_factor: Fraction,
+}
+
+#[cfg(feature = "defmt")]
+impl<T: TimeInt + defmt::Format> defmt::Format for Generic<T> {
+    fn format(&self, f: defmt::Formatter<'_>) {
+        defmt::write!(f, "{} x {} s", self.integer, self.scaling_factor)
+    }
+}
+
+#[cfg(feature = "ufmt")]
+impl<T: TimeInt + ufmt::uDebug> ufmt::uDebug for Generic<T> {
+    fn fmt<W>(&self, f: &mut ufmt::Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: ufmt::uWrite + ?Sized,
+    {
+        f.debug_struct("Generic")?
+            .field("integer", &self.integer)?
+            .field("scaling_factor", &self.scaling_factor)?
+            .finish()
+    }
+}
+
+impl<T: TimeInt> PartialOrd<Generic<T>> for Generic<T> {
+    /// See [Comparisons](trait.Duration.html#comparisons)
+    fn partial_cmp(&self, rhs: &Generic<T>) -> Option<core::cmp::Ordering> {
+        Some(
+            self.integer
+                .checked_mul_fraction(&self.scaling_factor)?
+                .cmp(&rhs.integer.checked_mul_fraction(&rhs.scaling_factor)?),
+        )
+    }
+}
+
+impl<T: TimeInt> Ord for Generic<T> {
+    fn cmp(&self, rhs: &Generic<T>) -> core::cmp::Ordering {
+        if let Some(v) = self.partial_cmp(rhs) {
+            v
+        } else {
+            panic!("Cmp failed")
+        }
+    }
+}
+
+impl<T: TimeInt> PartialEq<Generic<T>> for Generic<T> {
+    /// See [Comparisons](trait.Duration.html#comparisons)
+    fn eq(&self, rhs: &Generic<T>) -> bool {
+        self.partial_cmp(rhs) == Some(core::cmp::Ordering::Equal)
+    }
+}
+
+impl<T: TimeInt> Eq for Generic<T> {}
+
+impl<T: TimeInt + Hash> Hash for Generic<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        if let Some(v) = self.integer.checked_mul_fraction(&self.scaling_factor) {
+            v.hash(state);
+        }
+    }
+}
+
+impl<T: TimeInt> Generic<T> {
+    /// Constructs a new fixed-point `Generic` `Duration` value
+    pub fn new(integer: T, scaling_factor: Fraction) -> Self {
+        Self {
+            integer,
+            scaling_factor,
+        }
+    }
+
+    /// Returns the _integer_ part
+    pub fn integer(&self) -> T {
+        self.integer
+    }
+
+    /// Returns the _scaling factor_ [`Fraction`] part
+    pub fn scaling_factor(&self) -> &Fraction {
+        &self.scaling_factor
+    }
+
+    /// Returns a [`DisplayHuman`] that formats the duration using the largest sensible units
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use embedded_time::duration::*;
+    ///
+    /// let generic = Generic::new(49_152_u32, Fraction::new(1, 32_768));
+    ///
+    /// assert_eq!(format!("{}", generic.display_human()), "1.5 s");
+    /// ```
+    pub fn display_human(&self) -> DisplayHuman
+    where
+        u64: From<T>,
+    {
+        DisplayHuman::new(self.integer.into(), self.scaling_factor)
+    }
+
+    /// Writes the duration as an ISO 8601 duration (eg. `PT1H30M0.250S`)
+    ///
+    /// See [`Duration::write_iso8601()`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use embedded_time::duration::*;
+    ///
+    /// let mut iso = String::new();
+    /// Generic::new(49_153_u32, Fraction::new(1, 32_768))
+    ///     .write_iso8601(&mut iso)
+    ///     .unwrap();
+    ///
+    /// assert_eq!(iso, "PT1.500030517578125S");
+    /// ```
+    pub fn write_iso8601<W: fmt::Write>(&self, w: &mut W) -> fmt::Result
+    where
+        u64: From<T>,
+    {
+        iso8601::write(w, self.integer.into(), &self.scaling_factor)
+    }
+
+    /// Parses an ISO 8601 duration (eg. `PT1H30M0.250S`) into the given _scaling factor_
+    ///
+    /// See [`Duration::from_iso8601()`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use embedded_time::duration::*;
+    ///
+    /// assert_eq!(
+    ///     Generic::<u32>::from_iso8601("PT1.500030517578125S", Fraction::new(1, 32_768)),
+    ///     Ok(Generic::new(49_153_u32, Fraction::new(1, 32_768)))
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// See [`Duration::from_iso8601()`]
+    pub fn from_iso8601(s: &str, scaling_factor: Fraction) -> Result<Self, ConversionError>
+    where
+        T: TryFrom<u128>,
+    {
+        let ticks = iso8601::seconds_to_ticks(&iso8601::parse(s)?, &scaling_factor)?;
+        Ok(Self::new(
+            T::try_from(ticks).map_err(|_| ConversionError::Overflow)?,
+            scaling_factor,
+        ))
+    }
+
+    /// Returns the duration in seconds as an `f32`, rounded to the nearest representable value
+    ///
+    /// ```rust
+    /// use embedded_time::duration::*;
+    ///
+    /// assert_eq!(Generic::new(16_384_u32, Fraction::new(1, 32_768)).as_secs_f32(), 0.5);
+    /// ```
+    #[cfg(feature = "f32")]
+    pub fn as_secs_f32(&self) -> f32
+    where
+        u64: From<T>,
+    {
+        float::to_f32(self.integer.into(), &self.scaling_factor)
+    }
+
+    /// Returns the duration in seconds as an `f64`, rounded to the nearest representable value
+    ///
+    /// ```rust
+    /// use embedded_time::duration::*;
+    ///
+    /// assert_eq!(Generic::new(1_u32, Fraction::new(1, 32_768)).as_secs_f64(), 1.0 / 32_768.0);
+    /// ```
+    #[cfg(feature = "f64")]
+    pub fn as_secs_f64(&self) -> f64
+    where
+        u64: From<T>,
+    {
+        float::to_f64(self.integer.into(), &self.scaling_factor)
+    }
+
+    /// Constructs a duration with the given _scaling factor_ from seconds as an `f32`, rounded to
+    /// the nearest _integer_ (ties to even)
+    ///
+    /// # Errors
+    ///
+    /// - [`ConversionError::ConversionFailure`] : The value is negative or NaN
+    /// - [`ConversionError::Overflow`] : The value is infinite or doesn't fit in the type
+    /// - [`ConversionError::DivByZero`] : The _scaling factor_ is `0`
+    #[cfg(feature = "f32")]
+    pub fn from_secs_f32(secs: f32, scaling_factor: Fraction) -> Result<Self, ConversionError>
+    where
+        T: TryFrom<u128>,
+    {
+        let ticks = float::to_ticks(f64::from(secs), &scaling_factor)?;
+        Ok(Self::new(
+            T::try_from(ticks).map_err(|_| ConversionError::Overflow)?,
+            scaling_factor,
+        ))
+    }
+
+    /// Constructs a duration with the given _scaling factor_ from seconds as an `f64`, rounded to
+    /// the nearest _integer_ (ties to even)
+    ///
+    /// ```rust
+    /// use embedded_time::duration::*;
+    ///
+    /// assert_eq!(
+    ///     Generic::<u32>::from_secs_f64(0.5, Fraction::new(1, 32_768)),
+    ///     Ok(Generic::new(16_384_u32, Fraction::new(1, 32_768)))
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// - [`ConversionError::ConversionFailure`] : The value is negative or NaN
+    /// - [`ConversionError::Overflow`] : The value is infinite or doesn't fit in the type
+    /// - [`ConversionError::DivByZero`] : The _scaling factor_ is `0`
+    #[cfg(feature = "f64")]
+    pub fn from_secs_f64(secs: f64, scaling_factor: Fraction) -> Result<Self, ConversionError>
+    where
+        T: TryFrom<u128>,
+    {
+        let ticks = float::to_ticks(secs, &scaling_factor)?;
+        Ok(Self::new(
+            T::try_from(ticks).map_err(|_| ConversionError::Overflow)?,
+            scaling_factor,
+        ))
+    }
+}
+
+impl<T: TimeInt> Duration for Generic<T> {}
+
+impl<T: TimeInt> FromStr for Generic<T>
+where
+    T: TryFrom<u128>,
+{
+    type Err = ConversionError;
+
+    /// See [Parsing](trait.Duration.html#parsing)
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        const SCALING_FACTORS: [Fraction; 9] = [
+            Weeks::<u32>::SCALING_FACTOR,
+            Days::<u32>::SCALING_FACTOR,
+            Hours::<u32>::SCALING_FACTOR,
+            Minutes::<u32>::SCALING_FACTOR,
+            Seconds::<u32>::SCALING_FACTOR,
+            Milliseconds::<u32>::SCALING_FACTOR,
+            Microseconds::<u32>::SCALING_FACTOR,
+            Nanoseconds::<u32>::SCALING_FACTOR,
+            Picoseconds::<u32>::SCALING_FACTOR,
+        ];
+
+        let picoseconds = parse_picoseconds(s)?;
+        let (integer, scaling_factor) = SCALING_FACTORS
+            .iter()
+            .find_map(|scaling_factor| {
+                picoseconds_to_ticks(picoseconds, scaling_factor)
+                    .ok()
+                    .map(|ticks| (ticks, *scaling_factor))
+            })
+            .ok_or(ConversionError::PrecisionLoss)?;
+
+        Ok(Self::new(
+            T::try_from(integer).map_err(|_| ConversionError::Overflow)?,
+            scaling_factor,
+        ))
+    }
+}
+
+/// Parses a duration string (see [Parsing](trait.Duration.html#parsing)) into picoseconds
+fn parse_picoseconds(s: &str) -> Result<u128, ConversionError> {
+    // coarse to fine
+    const UNITS: [(&str, u128); 11] = [
+        ("w", 604_800_000_000_000_000),
+        ("d", 86_400_000_000_000_000),
+        ("h", 3_600_000_000_000_000),
+        ("min", 60_000_000_000_000),
+        ("m", 60_000_000_000_000),
+        ("s", 1_000_000_000_000),
+        ("ms", 1_000_000_000),
+        ("us", 1_000_000),
+        ("µs", 1_000_000),
+        ("ns", 1_000),
+        ("ps", 1),
+    ];
+
+    let mut remaining = s.trim();
+    if remaining.is_empty() {
+        return Err(ConversionError::ParseFailure);
+    }
+
+    // the units must be strictly descending, so each one is only searched for among the finer ones
+    let mut next_unit = 0;
+    let mut picoseconds = 0_u128;
+    while !remaining.is_empty() {
+        let (value, rest) = Decimal::split(remaining)?;
+        let rest = rest.trim_start();
+        // the longest symbol wins (eg. `ms` over `m`)
+        let (index, &(symbol, unit_picoseconds)) = UNITS
+            .iter()
+            .enumerate()
+            .skip(next_unit)
+            .filter(|(_, (symbol, _))| rest.starts_with(symbol))
+            .max_by_key(|(_, (symbol, _))| symbol.len())
+            .ok_or(ConversionError::ParseFailure)?;
+        // skip the other symbols of the same unit (eg. `m` after `min`)
+        next_unit = index
+            + UNITS[index..]
+                .iter()
+                .take_while(|&&(_, unit)| unit == unit_picoseconds)
+                .count();
+        remaining = rest[symbol.len()..].trim_start();
+
+        picoseconds = picoseconds
+            .checked_add(value.checked_mul_ratio(unit_picoseconds, 1)?)
+            .ok_or(ConversionError::Overflow)?;
+    }
+
+    Ok(picoseconds)
+}
+
+/// Parses a duration string (see [Parsing](trait.Duration.html#parsing)) into an exact number of
+/// ticks of the _scaling factor_
+#[doc(hidden)]
+pub fn parse_ticks(s: &str, scaling_factor: &Fraction) -> Result<u128, ConversionError> {
+    picoseconds_to_ticks(parse_picoseconds(s)?, scaling_factor)
+}
+
+/// Converts a number of ticks of the _scaling factor_ into a `core` duration, truncated to
+/// nanoseconds
+#[doc(hidden)]
+pub fn ticks_to_core_duration(
+    ticks: u64,
+    scaling_factor: &Fraction,
+) -> Result<core::time::Duration, ConversionError> {
+    let numerator = u128::from(ticks) * u128::from(*scaling_factor.numerator());
+    let denominator = u128::from(*scaling_factor.denominator());
+    if denominator == 0 {
+        return Err(ConversionError::DivByZero);
+    }
+
+    let seconds = u64::try_from(numerator / denominator).map_err(|_| ConversionError::Overflow)?;
+    // the remainder is less than the denominator so this can't overflow
+    let nanoseconds = (numerator % denominator * 1_000_000_000 / denominator) as u32;
+    Ok(core::time::Duration::new(seconds, nanoseconds))
+}
+
+/// Converts a `core` duration into a (truncated) number of ticks of the _scaling factor_
+#[doc(hidden)]
+pub fn core_duration_to_ticks(
+    core_duration: core::time::Duration,
+    scaling_factor: &Fraction,
+) -> Result<u128, ConversionError> {
+    let numerator = core_duration
+        .as_nanos()
+        .checked_mul(u128::from(*scaling_factor.denominator()))
+        .ok_or(ConversionError::ConversionFailure)?;
+    let denominator = u128::from(*scaling_factor.numerator()) * 1_000_000_000;
+
+    numerator
+        .checked_div(denominator)
+        .ok_or(ConversionError::DivByZero)
+}
+
+/// Converts picoseconds into an exact number of ticks of the _scaling factor_
+fn picoseconds_to_ticks(
+    picoseconds: u128,
+    scaling_factor: &Fraction,
+) -> Result<u128, ConversionError> {
+    let numerator = picoseconds
+        .checked_mul(u128::from(*scaling_factor.denominator()))
+        .ok_or(ConversionError::Overflow)?;
+    let denominator = u128::from(*scaling_factor.numerator()) * 1_000_000_000_000;
+
+    if denominator == 0 {
+        Err(ConversionError::DivByZero)
+    } else if numerator % denominator != 0 {
+        Err(ConversionError::PrecisionLoss)
+    } else {
+        Ok(numerator / denominator)
+    }
+}
+
+/// A signed duration of `D` units
+///
+/// The _integer_ is the signed counterpart of the _integer_ of `D` (`i32` for `u32`, `i64` for
+/// `u64`). It is useful for values that may be negative such as clock offsets and phase errors.
+///
+/// # Examples
+///
+/// ```rust
+/// use embedded_time::{duration::*, ConversionError};
+/// use core::convert::TryFrom;
+///
+/// // 3 ms early
+/// let offset = -SignedDuration::<Milliseconds>::new(3);
+///
+/// assert_eq!(offset.integer(), -3_i32);
+/// assert_eq!(offset.unsigned_abs(), Milliseconds(3_u32));
+/// assert_eq!(offset + SignedDuration::new(5), SignedDuration::new(2));
+/// assert_eq!(format!("{}", offset), "-3");
+/// ```
+///
+/// # Converting to and from unsigned units
+///
+/// ```rust
+/// use embedded_time::{duration::*, ConversionError};
+/// use core::convert::TryFrom;
+///
+/// let offset = SignedDuration::try_from(Milliseconds(3_u32)).unwrap();
+/// assert_eq!(Milliseconds::try_from(offset), Ok(Milliseconds(3_u32)));
+///
+/// assert_eq!(Milliseconds::try_from(-offset), Err(ConversionError::NegDuration));
+/// assert_eq!(
+///     SignedDuration::try_from(Milliseconds(u32::MAX)),
+///     Err(ConversionError::Overflow)
+/// );
+/// ```
+///
+/// # Converting between units
+///
+/// See [`SignedDuration::try_convert()`]
+///
+/// # Panics
+///
+/// The arithmetic operators panic for the same reasons as the integer operations would. Namely, if
+/// the result overflows the type. Checked versions are available.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub struct SignedDuration<D: FixedPoint>
+where
+    D::T: SignedTimeInt,
+{
+    integer: <D::T as SignedTimeInt>::Signed,
+    unit: PhantomData<D>,
+}
+
+impl<D: FixedPoint> SignedDuration<D>
+where
+    D::T: SignedTimeInt,
+{
+    /// Constructs a new `SignedDuration` from a signed _integer_
+    pub fn new(integer: <D::T as SignedTimeInt>::Signed) -> Self {
+        Self {
+            integer,
+            unit: PhantomData,
+        }
+    }
+
+    /// Returns the signed _integer_ part
+    pub fn integer(&self) -> <D::T as SignedTimeInt>::Signed {
+        self.integer
+    }
+
+    /// Returns `true` if the duration is less than zero
+    pub fn is_negative(&self) -> bool {
+        self.integer.is_negative()
+    }
+
+    /// Returns `true` if the duration is greater than zero
+    pub fn is_positive(&self) -> bool {
+        self.integer.is_positive()
+    }
+
+    /// Returns the absolute value or [`None`] upon overflow (only for the minimum value)
+    ///
+    /// ```rust
+    /// use embedded_time::duration::*;
+    ///
+    /// assert_eq!(
+    ///     SignedDuration::<Seconds>::new(-5).checked_abs(),
+    ///     Some(SignedDuration::new(5))
+    /// );
+    /// assert_eq!(SignedDuration::<Seconds>::new(i32::MIN).checked_abs(), None);
+    /// ```
+    pub fn checked_abs(self) -> Option<Self> {
+        if self.is_negative() {
+            self.checked_neg()
+        } else {
+            Some(self)
+        }
+    }
+
+    /// Returns the magnitude as an unsigned duration
+    ///
+    /// ```rust
+    /// use embedded_time::duration::*;
+    ///
+    /// assert_eq!(SignedDuration::<Seconds>::new(-5).unsigned_abs(), Seconds(5_u32));
+    /// assert_eq!(
+    ///     SignedDuration::<Seconds>::new(i32::MIN).unsigned_abs(),
+    ///     Seconds(1_u32 << 31)
+    /// );
+    /// ```
+    pub fn unsigned_abs(self) -> D {
+        D::new(D::T::unsigned_abs(self.integer))
+    }
+
+    /// Negation with overflow checking
+    pub fn checked_neg(self) -> Option<Self> {
+        Some(Self::new(self.integer.checked_neg()?))
+    }
+
+    /// Addition with overflow checking
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        Some(Self::new(self.integer.checked_add(&rhs.integer)?))
+    }
+
+    /// Subtraction with overflow checking
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        Some(Self::new(self.integer.checked_sub(&rhs.integer)?))
+    }
+
+    /// Multiplication with overflow checking
+    pub fn checked_mul(self, rhs: <D::T as SignedTimeInt>::Signed) -> Option<Self> {
+        Some(Self::new(self.integer.checked_mul(&rhs)?))
+    }
+
+    /// Division with overflow and division-by-zero checking
+    pub fn checked_div(self, rhs: <D::T as SignedTimeInt>::Signed) -> Option<Self> {
+        Some(Self::new(self.integer.checked_div(&rhs)?))
+    }
+
+    /// Converts to a `SignedDuration` of another unit
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use embedded_time::duration::*;
+    ///
+    /// assert_eq!(
+    ///     SignedDuration::<Seconds>::new(-2).try_convert::<Milliseconds>(),
+    ///     Ok(SignedDuration::new(-2_000))
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Failure will only occur if the value does not fit in the destination type.
+    ///
+    /// [`ConversionError::Overflow`] : The signed _integer_ of the destination overflows
+    ///
+    /// ```rust
+    /// use embedded_time::{duration::*, ConversionError};
+    ///
+    /// assert_eq!(
+    ///     SignedDuration::<Seconds>::new(-3_000_000).try_convert::<Milliseconds>(),
+    ///     Err(ConversionError::Overflow)
+    /// );
+    /// ```
+    ///
+    /// Other errors are the same as for converting the unsigned units.
+    pub fn try_convert<Dest: FixedPoint>(self) -> Result<SignedDuration<Dest>, ConversionError>
+    where
+        Dest::T: SignedTimeInt + TryFrom<D::T>,
+    {
+        let magnitude: Dest =
+            FixedPoint::from_ticks(D::T::unsigned_abs(self.integer), D::SCALING_FACTOR)?;
+
+        Ok(SignedDuration::new(
+            Dest::T::checked_from_magnitude(magnitude.integer(), self.is_negative())
+                .ok_or(ConversionError::Overflow)?,
+        ))
+    }
+}
+
+impl<D: FixedPoint> fmt::Display for SignedDuration<D>
+where
+    D::T: SignedTimeInt,
+{
+    /// Just forwards the underlying signed integer to [`core::fmt::Display::fmt()`]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.integer, f)
+    }
+}
+
+#[cfg(feature = "ufmt")]
+impl<D: FixedPoint> ufmt::uDisplay for SignedDuration<D>
+where
+    D::T: SignedTimeInt,
+    <D::T as SignedTimeInt>::Signed: ufmt::uDisplay,
+{
+    /// Just forwards the underlying signed integer to [`ufmt::uDisplay::fmt()`]
+    fn fmt<W>(&self, f: &mut ufmt::Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: ufmt::uWrite + ?Sized,
+    {
+        ufmt::uDisplay::fmt(&self.integer, f)
+    }
+}
+
+impl<D: FixedPoint> ops::Neg for SignedDuration<D>
+where
+    D::T: SignedTimeInt,
+{
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.integer)
+    }
+}
+
+impl<D: FixedPoint> ops::Add for SignedDuration<D>
+where
+    D::T: SignedTimeInt,
+{
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.integer + rhs.integer)
+    }
+}
+
+impl<D: FixedPoint> ops::Sub for SignedDuration<D>
+where
+    D::T: SignedTimeInt,
+{
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.integer - rhs.integer)
+    }
+}
+
+impl<D: FixedPoint> ops::Mul<<D::T as SignedTimeInt>::Signed> for SignedDuration<D>
+where
+    D::T: SignedTimeInt,
+{
+    type Output = Self;
+
+    fn mul(self, rhs: <D::T as SignedTimeInt>::Signed) -> Self::Output {
+        Self::new(self.integer * rhs)
+    }
+}
+
+impl<D: FixedPoint> ops::Div<<D::T as SignedTimeInt>::Signed> for SignedDuration<D>
+where
+    D::T: SignedTimeInt,
+{
+    type Output = Self;
+
+    fn div(self, rhs: <D::T as SignedTimeInt>::Signed) -> Self::Output {
+        Self::new(self.integer / rhs)
+    }
+}
+
+/// Defines a duration unit type with the given _scaling factor_ (in seconds)
+///
+/// The built-in units such as [`Milliseconds`] are generated by this macro too, so the unit has the
+/// same implementations: [`Duration`] and [`FixedPoint`], formatting, parsing, the arithmetic
+/// operators (including [`AddFiner`] with itself), `const` conversions, conversions to and from
+/// [`Generic`], [`Scaled`], [`SignedDuration`] and [`Ticks`] durations and
+/// [`core::time::Duration`] (truncated) and conversions to and from each of the built-in duration
+/// units. Two user-defined units convert through [`Generic`].
+///
+/// Attributes (including doc comments and additional derives such as `serde`'s) are applied to the
+/// generated struct.
+///
+/// ```rust
+/// use core::convert::TryFrom;
+/// use embedded_time::{define_duration_unit, duration::*};
+///
+/// define_duration_unit!(
+///     /// Video frames at 60 fps
+///     pub Frames, (1, 60)
+/// );
+///
+/// assert_eq!(Frames::<u32>::try_from(Seconds(2_u32)), Ok(Frames(120_u32)));
+/// assert_eq!(Milliseconds::<u32>::try_from(Frames(3_u32)), Ok(Milliseconds(50_u32)));
+/// assert_eq!(Frames(60_u32) + Seconds(1_u32), Frames(120_u32));
+/// assert_eq!(
+///     Frames::<u32>::try_from(Generic::new(1_u32, Fraction::new(1, 20))),
+///     Ok(Frames(3_u32))
+/// );
+/// assert_eq!(format!("{}", Frames(90_u32).display_human()), "1.5 s");
+/// assert_eq!("0.5s".parse(), Ok(Frames(30_u32)));
+/// assert_eq!(
+///     core::time::Duration::try_from(Frames(90_u32)),
+///     Ok(core::time::Duration::from_millis(1_500))
+/// );
+/// ```
+#[macro_export]
+macro_rules! define_duration_unit {
+    ( @unit $(#[$attr:meta])* $vis:vis $name:ident, ($numer:expr, $denom:expr) ) => {
+        $(#[$attr])*
+        #[derive(Copy, Clone, Eq, Ord, Hash, Debug, Default)]
+        $vis struct $name<T: $crate::TimeInt = u32>(pub T);
+
+        impl<T: $crate::TimeInt> $name<T> {
+            /// See [Constructing a
+            /// duration](::embedded_time::duration::Duration#constructing-a-duration)
+            pub const fn new(value: T) -> Self {
+                Self(value)
+            }
+
+            /// See [Get the integer
+            /// part](::embedded_time::duration::Duration#get-the-integer-part)
+            pub const fn integer(&self) -> T {
+                self.0
+            }
+        }
+
+        impl $name<u32> {
+            $crate::impl_const_conversions![u32];
+
+            /// See [Constant
+            /// evaluation](::embedded_time::duration::Duration#constant-evaluation)
+            pub const fn const_widen(self) -> $name<u64> {
+                $name(self.0 as u64)
+            }
+        }
+
+        impl $name<u64> {
+            $crate::impl_const_conversions![u64];
+        }
+
+        impl<T: $crate::TimeInt> $crate::duration::Duration for $name<T> {}
+
+        impl<T: $crate::TimeInt> $crate::fixed_point::FixedPoint for $name<T> {
+            type T = T;
+            const SCALING_FACTOR: $crate::fraction::Fraction =
+                $crate::fraction::Fraction::new($numer, $denom);
+
+            /// See [Constructing a
+            /// duration](::embedded_time::duration::Duration#constructing-a-duration)
+            fn new(value: Self::T) -> Self {
+                Self(value)
+            }
+
+            /// See [Get the integer
+            /// part](::embedded_time::duration::Duration#get-the-integer-part)
+            fn integer(&self) -> Self::T {
+                self.0
+            }
+        }
+
+        impl<T: $crate::TimeInt> ::core::fmt::Display for $name<T> {
+            /// See [Formatting](::embedded_time::duration::Duration#formatting)
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                ::core::fmt::Display::fmt(&self.0, f)
+            }
+        }
+
+        impl<T: $crate::TimeInt> ::core::str::FromStr for $name<T>
+        where
+            T: ::core::convert::TryFrom<u128>,
+        {
+            type Err = $crate::ConversionError;
+
+            /// See [Parsing](::embedded_time::duration::Duration#parsing)
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                let ticks = $crate::duration::parse_ticks(
+                    s,
+                    &<Self as $crate::fixed_point::FixedPoint>::SCALING_FACTOR,
+                )?;
+                Ok(Self(
+                    <T as ::core::convert::TryFrom<u128>>::try_from(ticks)
+                        .map_err(|_| $crate::ConversionError::Overflow)?,
+                ))
+            }
+        }
+
+        impl<T: $crate::TimeInt, RhsInt: $crate::TimeInt> ::core::cmp::PartialEq<$name<RhsInt>>
+            for $name<T>
+        where
+            T: ::core::convert::TryFrom<RhsInt>,
+        {
+            /// See [Comparisons](::embedded_time::duration::Duration#comparisons)
+            fn eq(&self, rhs: &$name<RhsInt>) -> bool {
+                match <T as ::core::convert::TryFrom<RhsInt>>::try_from(rhs.0) {
+                    Ok(rhs_integer) => self.0 == rhs_integer,
+                    Err(_) => false,
+                }
+            }
+        }
+
+        impl<T: $crate::TimeInt, RhsInt: $crate::TimeInt> ::core::cmp::PartialOrd<$name<RhsInt>>
+            for $name<T>
+        where
+            T: ::core::convert::TryFrom<RhsInt>,
+        {
+            /// See [Comparisons](::embedded_time::duration::Duration#comparisons)
+            fn partial_cmp(&self, rhs: &$name<RhsInt>) -> Option<::core::cmp::Ordering> {
+                match <T as ::core::convert::TryFrom<RhsInt>>::try_from(rhs.0) {
+                    Ok(rhs_integer) => Some(self.0.cmp(&rhs_integer)),
+                    Err(_) => Some(::core::cmp::Ordering::Less),
+                }
+            }
+        }
+
+        impl<T: $crate::TimeInt, RhsInt: $crate::TimeInt, const NUM: u32, const DENOM: u32>
+            ::core::cmp::PartialEq<$crate::duration::Scaled<RhsInt, NUM, DENOM>> for $name<T>
+        {
+            /// See [Comparisons](::embedded_time::duration::Duration#comparisons)
+            fn eq(&self, rhs: &$crate::duration::Scaled<RhsInt, NUM, DENOM>) -> bool {
+                $crate::fixed_point::cmp_values(self, rhs) == Some(::core::cmp::Ordering::Equal)
+            }
+        }
+
+        impl<T: $crate::TimeInt, RhsInt: $crate::TimeInt, const NUM: u32, const DENOM: u32>
+            ::core::cmp::PartialEq<$name<RhsInt>> for $crate::duration::Scaled<T, NUM, DENOM>
+        {
+            /// See [Comparisons](::embedded_time::duration::Duration#comparisons)
+            fn eq(&self, rhs: &$name<RhsInt>) -> bool {
+                $crate::fixed_point::cmp_values(self, rhs) == Some(::core::cmp::Ordering::Equal)
+            }
+        }
+
+        impl<T: $crate::TimeInt, RhsInt: $crate::TimeInt, const NUM: u32, const DENOM: u32>
+            ::core::cmp::PartialOrd<$crate::duration::Scaled<RhsInt, NUM, DENOM>> for $name<T>
+        {
+            /// See [Comparisons](::embedded_time::duration::Duration#comparisons)
+            fn partial_cmp(
+                &self,
+                rhs: &$crate::duration::Scaled<RhsInt, NUM, DENOM>,
+            ) -> Option<::core::cmp::Ordering> {
+                $crate::fixed_point::cmp_values(self, rhs)
+            }
+        }
+
+        impl<T: $crate::TimeInt, RhsInt: $crate::TimeInt, const NUM: u32, const DENOM: u32>
+            ::core::cmp::PartialOrd<$name<RhsInt>> for $crate::duration::Scaled<T, NUM, DENOM>
+        {
+            /// See [Comparisons](::embedded_time::duration::Duration#comparisons)
+            fn partial_cmp(&self, rhs: &$name<RhsInt>) -> Option<::core::cmp::Ordering> {
+                $crate::fixed_point::cmp_values(self, rhs)
+            }
+        }
+
+        impl ::core::convert::From<$name<u32>> for $name<u64> {
+            /// See [Converting between
+            /// `Duration`s](::embedded_time::duration::Duration#converting-between-durations)
+            fn from(source: $name<u32>) -> Self {
+                Self(u64::from(source.0))
+            }
+        }
+
+        impl ::core::convert::TryFrom<$name<u64>> for $name<u32> {
+            type Error = $crate::ConversionError;
+
+            /// See [Converting between
+            /// `Duration`s](::embedded_time::duration::Duration#converting-between-durations)
+            fn try_from(source: $name<u64>) -> Result<Self, Self::Error> {
+                $crate::fixed_point::FixedPoint::from_fixed_point(source)
+            }
+        }
+
+        impl<T: $crate::TimeInt, Rhs: $crate::duration::Duration> ::core::ops::Add<Rhs>
+            for $name<T>
+        where
+            Rhs: $crate::fixed_point::FixedPoint,
+            Self: ::core::convert::TryFrom<Rhs>,
+        {
+            type Output = Self;
+
+            /// See [Add/Sub](::embedded_time::duration::Duration#addsub)
+            fn add(self, rhs: Rhs) -> Self::Output {
+                <Self as $crate::fixed_point::FixedPoint>::add(self, rhs)
+            }
+        }
+
+        impl<T: $crate::TimeInt, Rhs: $crate::duration::Duration> ::core::ops::Sub<Rhs>
+            for $name<T>
+        where
+            Rhs: $crate::fixed_point::FixedPoint,
+            Self: ::core::convert::TryFrom<Rhs>,
+        {
+            type Output = Self;
+
+            /// See [Add/Sub](::embedded_time::duration::Duration#addsub)
+            fn sub(self, rhs: Rhs) -> Self::Output {
+                <Self as $crate::fixed_point::FixedPoint>::sub(self, rhs)
+            }
+        }
+
+        impl<T: $crate::TimeInt> $crate::duration::AddFiner<$name<T>> for $name<T> {
+            type Output = Self;
+
+            /// See [`AddFiner`](::embedded_time::duration::AddFiner)
+            fn checked_add_finer(self, rhs: Self) -> Result<Self, $crate::ConversionError> {
+                $crate::duration::Duration::checked_add(self, rhs)
+            }
+        }
+
+        impl<T: $crate::TimeInt, Clock: $crate::Clock> ::core::ops::Add<$crate::Instant<Clock>>
+            for $name<T>
+        where
+            Clock::T: ::core::convert::TryFrom<T>,
+        {
+            type Output = $crate::Instant<Clock>;
+
+            // Symmetric version of Instant + Duration
+            fn add(self, rhs: $crate::Instant<Clock>) -> Self::Output {
+                if let Some(v) = rhs.checked_add(self) {
+                    v
+                } else {
+                    panic!("Add failed")
+                }
+            }
+        }
+
+        impl<T: $crate::TimeInt> ::core::ops::Mul<T> for $name<T> {
+            type Output = Self;
+
+            /// See [Mul/Div](::embedded_time::duration::Duration#muldiv)
+            fn mul(self, rhs: T) -> Self::Output {
+                <Self as $crate::fixed_point::FixedPoint>::mul(self, rhs)
+            }
+        }
+
+        impl<T: $crate::TimeInt> ::core::ops::Div<T> for $name<T> {
+            type Output = Self;
+
+            /// See [Mul/Div](::embedded_time::duration::Duration#muldiv)
+            fn div(self, rhs: T) -> Self::Output {
+                <Self as $crate::fixed_point::FixedPoint>::div(self, rhs)
+            }
+        }
+
+        impl<T: $crate::TimeInt, Rhs: $crate::duration::Duration> ::core::ops::Rem<Rhs>
+            for $name<T>
+        where
+            Rhs: $crate::fixed_point::FixedPoint,
+            Self: ::core::convert::TryFrom<Rhs>,
+        {
+            type Output = Self;
+
+            /// See [Remainder](::embedded_time::duration::Duration#remainder)
+            fn rem(self, rhs: Rhs) -> Self::Output {
+                <Self as $crate::fixed_point::FixedPoint>::rem(self, rhs)
+            }
+        }
+
+        impl<SourceInt: $crate::TimeInt, DestInt: $crate::TimeInt>
+            ::core::convert::TryFrom<$crate::duration::Generic<SourceInt>> for $name<DestInt>
+        where
+            DestInt: ::core::convert::TryFrom<SourceInt>,
+        {
+            type Error = $crate::ConversionError;
+
+            /// See [Converting from a `Generic`
+            /// `Duration`](::embedded_time::duration::Duration#converting-from-a-generic-duration)
+            fn try_from(
+                generic_duration: $crate::duration::Generic<SourceInt>,
+            ) -> Result<Self, Self::Error> {
+                $crate::fixed_point::FixedPoint::from_ticks(
+                    generic_duration.integer(),
+                    *generic_duration.scaling_factor(),
+                )
+            }
+        }
+
+        impl<T: $crate::TimeInt> ::core::convert::From<$name<T>> for $crate::duration::Generic<T> {
+            /// See [Converting to a `Generic`
+            /// `Duration`](::embedded_time::duration::Duration#converting-to-a-generic-duration)
+            fn from(duration: $name<T>) -> Self {
+                Self::new(
+                    duration.0,
+                    <$name<T> as $crate::fixed_point::FixedPoint>::SCALING_FACTOR,
+                )
+            }
+        }
+
+        impl<T: $crate::SignedTimeInt> ::core::convert::TryFrom<$name<T>>
+            for $crate::duration::SignedDuration<$name<T>>
+        {
+            type Error = $crate::ConversionError;
+
+            /// See [Converting to and from unsigned
+            /// units](::embedded_time::duration::SignedDuration#converting-to-and-from-unsigned-units)
+            fn try_from(duration: $name<T>) -> Result<Self, Self::Error> {
+                Ok(Self::new(
+                    <T as $crate::SignedTimeInt>::checked_from_magnitude(duration.0, false)
+                        .ok_or($crate::ConversionError::Overflow)?,
+                ))
+            }
+        }
+
+        impl<T: $crate::SignedTimeInt>
+            ::core::convert::TryFrom<$crate::duration::SignedDuration<$name<T>>> for $name<T>
+        {
+            type Error = $crate::ConversionError;
+
+            /// See [Converting to and from unsigned
+            /// units](::embedded_time::duration::SignedDuration#converting-to-and-from-unsigned-units)
+            fn try_from(
+                duration: $crate::duration::SignedDuration<$name<T>>,
+            ) -> Result<Self, Self::Error> {
+                if duration.is_negative() {
+                    Err($crate::ConversionError::NegDuration)
+                } else {
+                    Ok(duration.unsigned_abs())
+                }
+            }
+        }
+
+        impl<SourceInt: $crate::TimeInt, DestInt: $crate::TimeInt, const NUM: u32, const DENOM: u32>
+            ::core::convert::TryFrom<$name<SourceInt>>
+            for $crate::duration::Scaled<DestInt, NUM, DENOM>
+        where
+            DestInt: ::core::convert::TryFrom<SourceInt>,
+        {
+            type Error = $crate::ConversionError;
+
+            /// See [Converting between
+            /// `Duration`s](::embedded_time::duration::Duration#converting-between-durations)
+            fn try_from(duration: $name<SourceInt>) -> Result<Self, Self::Error> {
+                $crate::fixed_point::FixedPoint::from_fixed_point(duration)
+            }
+        }
+
+        impl<SourceInt: $crate::TimeInt, DestInt: $crate::TimeInt, const NUM: u32, const DENOM: u32>
+            ::core::convert::TryFrom<$crate::duration::Scaled<SourceInt, NUM, DENOM>>
+            for $name<DestInt>
+        where
+            DestInt: ::core::convert::TryFrom<SourceInt>,
+        {
+            type Error = $crate::ConversionError;
+
+            /// See [Converting between
+            /// `Duration`s](::embedded_time::duration::Duration#converting-between-durations)
+            fn try_from(
+                duration: $crate::duration::Scaled<SourceInt, NUM, DENOM>,
+            ) -> Result<Self, Self::Error> {
+                duration.try_convert()
+            }
+        }
+
+        impl<Clock: $crate::Clock, SourceInt: $crate::TimeInt>
+            ::core::convert::TryFrom<$name<SourceInt>> for $crate::duration::Ticks<Clock>
+        where
+            Clock::T: ::core::convert::TryFrom<SourceInt>,
+        {
+            type Error = $crate::ConversionError;
+
+            /// See [Converting between
+            /// `Duration`s](::embedded_time::duration::Duration#converting-between-durations)
+            fn try_from(duration: $name<SourceInt>) -> Result<Self, Self::Error> {
+                $crate::fixed_point::FixedPoint::from_fixed_point(duration)
+            }
+        }
+
+        impl<Clock: $crate::Clock, DestInt: $crate::TimeInt>
+            ::core::convert::TryFrom<$crate::duration::Ticks<Clock>> for $name<DestInt>
+        where
+            DestInt: ::core::convert::TryFrom<Clock::T>,
+        {
+            type Error = $crate::ConversionError;
+
+            /// See [Converting between
+            /// `Duration`s](::embedded_time::duration::Duration#converting-between-durations)
+            fn try_from(duration: $crate::duration::Ticks<Clock>) -> Result<Self, Self::Error> {
+                $crate::fixed_point::FixedPoint::from_fixed_point(duration)
+            }
+        }
+    };
+
+    ( @core $name:ident ) => {
+        impl<T: $crate::TimeInt> ::core::convert::TryFrom<$name<T>> for ::core::time::Duration
+        where
+            u64: ::core::convert::From<T>,
+        {
+            type Error = $crate::ConversionError;
+
+            /// See [Converting to `core`
+            /// types](::embedded_time::duration::Duration#converting-to-core-types)
+            ///
+            /// The duration is truncated to nanoseconds.
+            fn try_from(duration: $name<T>) -> Result<Self, Self::Error> {
+                $crate::duration::ticks_to_core_duration(
+                    duration.0.into(),
+                    &<$name<T> as $crate::fixed_point::FixedPoint>::SCALING_FACTOR,
+                )
+            }
+        }
+
+        impl<T: $crate::TimeInt> ::core::convert::TryFrom<::core::time::Duration> for $name<T>
+        where
+            T: ::core::convert::TryFrom<u128>,
+        {
+            type Error = $crate::ConversionError;
+
+            /// See [Converting from `core`
+            /// types](::embedded_time::duration::Duration#converting-from-core-types)
+            ///
+            /// The duration is truncated to the unit.
+            fn try_from(core_duration: ::core::time::Duration) -> Result<Self, Self::Error> {
+                let ticks = $crate::duration::core_duration_to_ticks(
+                    core_duration,
+                    &<Self as $crate::fixed_point::FixedPoint>::SCALING_FACTOR,
+                )?;
+                Ok(Self(
+                    <T as ::core::convert::TryFrom<u128>>::try_from(ticks)
+                        .map_err(|_| $crate::ConversionError::ConversionFailure)?,
+                ))
+            }
+        }
+    };
+
+    ( @conversions $name:ident, $($unit:ident),+ ) => {
+        $(
+            impl<SourceInt: $crate::TimeInt, DestInt: $crate::TimeInt>
+                ::core::convert::TryFrom<$crate::duration::$unit<SourceInt>> for $name<DestInt>
+            where
+                DestInt: ::core::convert::TryFrom<SourceInt>,
+            {
+                type Error = $crate::ConversionError;
+
+                /// See [Converting between
+                /// `Duration`s](::embedded_time::duration::Duration#converting-between-durations)
+                fn try_from(
+                    duration: $crate::duration::$unit<SourceInt>,
+                ) -> Result<Self, Self::Error> {
+                    $crate::fixed_point::FixedPoint::from_fixed_point(duration)
+                }
+            }
+
+            impl<SourceInt: $crate::TimeInt, DestInt: $crate::TimeInt>
+                ::core::convert::TryFrom<$name<SourceInt>> for $crate::duration::$unit<DestInt>
+            where
+                DestInt: ::core::convert::TryFrom<SourceInt>,
+            {
+                type Error = $crate::ConversionError;
+
+                /// See [Converting between
+                /// `Duration`s](::embedded_time::duration::Duration#converting-between-durations)
+                fn try_from(duration: $name<SourceInt>) -> Result<Self, Self::Error> {
+                    $crate::fixed_point::FixedPoint::from_fixed_point(duration)
+                }
+            }
+        )+
+    };
+
+    ( $(#[$attr:meta])* $vis:vis $name:ident, ($numer:expr, $denom:expr) ) => {
+        $crate::define_duration_unit![@unit $(#[$attr])* $vis $name, ($numer, $denom)];
+        $crate::define_duration_unit![@core $name];
+        $crate::define_duration_unit![
+            @conversions $name,
+            Weeks,
+            Days,
+            Hours,
+            Minutes,
+            Seconds,
+            Milliseconds,
+            Microseconds,
+            Nanoseconds,
+            Picoseconds
+        ];
+    };
+}
+
+/// Duration units
+#[doc(hidden)]
+pub mod units {
     use super::*;
     use crate::{
         fixed_point::{self, FixedPoint},
@@ -522,242 +2203,752 @@ pub mod units {
     #[doc(hidden)]
     pub use Extensions as _;
 
-    macro_rules! impl_duration {
-        ( $name:ident, ($numer:expr, $denom:expr) ) => {
-            /// A duration unit type
-            #[derive(Copy, Clone, Eq, Ord, Hash, Debug, Default)]
-            #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-            pub struct $name<T: TimeInt = u32>(pub T);
-
-            impl<T: TimeInt> $name<T> {
-                /// See [Constructing a duration](trait.Duration.html#constructing-a-duration)
-                pub fn new(value: T) -> Self {
-                    Self(value)
-                }
-            }
+    macro_rules! impl_duration {
+        ( $name:ident, ($numer:expr, $denom:expr) ) => {
+            crate::define_duration_unit![
+                @unit
+                /// A duration unit type
+                pub $name, ($numer, $denom)
+            ];
+        };
+
+        ( $name:ident, ($numer:expr, $denom:expr), ge_secs ) => {
+            impl_duration![$name, ($numer, $denom)];
+
+            // TODO: Make this more custom (seconds and higher<u32> can be `From`) and comprehensive
+            // (allow u64 durations)
+            impl TryFrom<$name<u32>> for core::time::Duration {
+                type Error = ConversionError;
+
+                /// See [Converting to `core`
+                /// types](trait.Duration.html#converting-to-core-types)
+                fn try_from(duration: $name<u32>) -> Result<Self, Self::Error> {
+                    let seconds: Seconds<u64> = duration.into();
+                    Ok(Self::from_secs(seconds.integer()))
+                }
+            }
+
+            impl TryFrom<core::time::Duration> for $name<u32> {
+                type Error = ConversionError;
+
+                /// See [Converting from `core`
+                /// types](trait.Duration.html#converting-from-core-types)
+                fn try_from(core_duration: core::time::Duration) -> Result<Self, Self::Error> {
+                    let seconds = Seconds(core_duration.as_secs());
+                    seconds.try_into()
+                }
+            }
+
+            impl From<core::time::Duration> for $name<u64> {
+                /// See [Converting from `core`
+                /// types](trait.Duration.html#converting-from-core-types)
+                fn from(core_duration: core::time::Duration) -> Self {
+                    let seconds = Seconds(core_duration.as_secs());
+                    seconds.into()
+                }
+            }
+        };
+        ( $name:ident, ($numer:expr, $denom:expr), $from_core_dur:ident, $as_core_dur:ident ) => {
+            impl_duration![$name, ($numer, $denom)];
+
+            impl<T: TimeInt> TryFrom<$name<T>> for core::time::Duration
+            where
+                u64: From<T>,
+            {
+                type Error = ConversionError;
+
+                /// See [Converting to `core` types](trait.Duration.html#converting-to-core-types)
+                fn try_from(duration: $name<T>) -> Result<Self, Self::Error> {
+                    Ok(Self::$from_core_dur(duration.integer().into()))
+                }
+            }
+
+            impl<T: TimeInt> TryFrom<core::time::Duration> for $name<T>
+            where
+                T: TryFrom<u128>,
+            {
+                type Error = ConversionError;
+
+                /// See [Converting from `core`
+                /// types](trait.Duration.html#converting-from-core-types)
+                fn try_from(core_duration: core::time::Duration) -> Result<Self, Self::Error> {
+                    Ok(Self(
+                        core_duration
+                            .$as_core_dur()
+                            .try_into()
+                            .map_err(|_| ConversionError::ConversionFailure)?,
+                    ))
+                }
+            }
+        };
+        ( $name:ident, ($numer:expr, $denom:expr), lt_nanos ) => {
+            impl_duration![$name, ($numer, $denom)];
+
+            impl<T: TimeInt> TryFrom<$name<T>> for core::time::Duration
+            where
+                u64: From<T>,
+            {
+                type Error = ConversionError;
+
+                /// See [Converting to `core` types](trait.Duration.html#converting-to-core-types)
+                ///
+                /// The duration is truncated to nanoseconds.
+                fn try_from(duration: $name<T>) -> Result<Self, Self::Error> {
+                    // the number of nanoseconds is less than the integer, so it fits in a u64
+                    let nanoseconds =
+                        u128::from(u64::from(duration.integer())) * 1_000_000_000 / $denom;
+                    Ok(Self::from_nanos(nanoseconds as u64))
+                }
+            }
+
+            impl<T: TimeInt> TryFrom<core::time::Duration> for $name<T>
+            where
+                T: TryFrom<u128>,
+            {
+                type Error = ConversionError;
+
+                /// See [Converting from `core`
+                /// types](trait.Duration.html#converting-from-core-types)
+                fn try_from(core_duration: core::time::Duration) -> Result<Self, Self::Error> {
+                    Ok(Self(
+                        core_duration
+                            .as_nanos()
+                            .checked_mul($denom / 1_000_000_000)
+                            .and_then(|ticks| ticks.try_into().ok())
+                            .ok_or(ConversionError::ConversionFailure)?,
+                    ))
+                }
+            }
+        };
+    }
+    impl_duration![Weeks, (604_800, 1), ge_secs];
+    impl_duration![Days, (86_400, 1), ge_secs];
+    impl_duration![Hours, (3600, 1), ge_secs];
+    impl_duration![Minutes, (60, 1), ge_secs];
+    impl_duration![Seconds, (1, 1), ge_secs];
+    impl_duration![Milliseconds, (1, 1_000), from_millis, as_millis];
+    impl_duration![Microseconds, (1, 1_000_000), from_micros, as_micros];
+    impl_duration![Nanoseconds, (1, 1_000_000_000), from_nanos, as_nanos];
+    impl_duration![Picoseconds, (1, 1_000_000_000_000), lt_nanos];
+
+    #[cfg(feature = "defmt")]
+    macro_rules! impl_format {
+        ( $($name:ident, $format:literal);+ ) => {
+            $(
+                impl<T: TimeInt + defmt::Format> defmt::Format for $name<T> {
+                    fn format(&self, f: defmt::Formatter<'_>) {
+                        defmt::write!(f, $format, self.0)
+                    }
+                }
+            )+
+        };
+    }
+    #[cfg(feature = "defmt")]
+    impl_format![
+        Weeks, "{} w";
+        Days, "{} d";
+        Hours, "{} h";
+        Minutes, "{} min";
+        Seconds, "{} s";
+        Milliseconds, "{} ms";
+        Microseconds, "{} us";
+        Nanoseconds, "{} ns";
+        Picoseconds, "{} ps"
+    ];
+
+    #[cfg(feature = "ufmt")]
+    macro_rules! impl_ufmt {
+        ( $($name:ident),+ ) => {
+            $(
+                impl<T: TimeInt + ufmt::uDisplay> ufmt::uDisplay for $name<T> {
+                    /// See [Formatting](trait.Duration.html#formatting)
+                    fn fmt<W>(&self, f: &mut ufmt::Formatter<'_, W>) -> Result<(), W::Error>
+                    where
+                        W: ufmt::uWrite + ?Sized,
+                    {
+                        ufmt::uDisplay::fmt(&self.0, f)
+                    }
+                }
+
+                impl<T: TimeInt + ufmt::uDebug> ufmt::uDebug for $name<T> {
+                    fn fmt<W>(&self, f: &mut ufmt::Formatter<'_, W>) -> Result<(), W::Error>
+                    where
+                        W: ufmt::uWrite + ?Sized,
+                    {
+                        f.debug_tuple(stringify!($name))?.field(&self.0)?.finish()
+                    }
+                }
+            )+
+        };
+    }
+    #[cfg(feature = "ufmt")]
+    impl_ufmt![
+        Weeks,
+        Days,
+        Hours,
+        Minutes,
+        Seconds,
+        Milliseconds,
+        Microseconds,
+        Nanoseconds,
+        Picoseconds
+    ];
+
+    /// A duration unit with a compile-time _scaling factor_ of `NUM / DENOM` seconds
+    ///
+    /// Complements the _named_ units with units that aren't predefined, such as the ticks of a
+    /// clock. `Scaled<T, 1, 1_000>` has the same _scaling factor_ as [`Milliseconds<T>`].
+    ///
+    /// The _named_ units aren't type aliases of `Scaled`: an alias can't be used as a tuple-struct
+    /// constructor or pattern, so `Milliseconds(250)` would no longer compile. Instead, a `Scaled`
+    /// value compares exactly with the _named_ units and with `Scaled` units of any other _scaling
+    /// factor_.
+    ///
+    /// ```rust
+    /// use core::convert::TryFrom;
+    /// use embedded_time::duration::*;
+    ///
+    /// // ticks of a 32.768 kHz crystal
+    /// type Ticks32k = Scaled<u32, 1, 32_768>;
+    ///
+    /// assert_eq!(Ticks32k::try_from(Milliseconds(250_u32)), Ok(Scaled(8_192_u32)));
+    /// assert_eq!(Milliseconds::<u32>::try_from(Ticks32k::new(16_384)), Ok(Milliseconds(500_u32)));
+    /// assert_eq!(Ticks32k::new(8_192) + Milliseconds(250_u32), Ticks32k::new(16_384));
+    ///
+    /// // comparisons
+    /// assert_eq!(Ticks32k::new(8_192), Milliseconds(250_u32));
+    /// assert_eq!(Ticks32k::new(8_192), Scaled::<u64, 1, 1_024>(256));
+    /// assert!(Ticks32k::new(33) > Scaled::<u32, 1, 1_024>(1));
+    ///
+    /// // converting between scaled units
+    /// assert_eq!(
+    ///     Ticks32k::new(98_304).try_convert::<Scaled<u32, 1, 1_024>>(),
+    ///     Ok(Scaled(3_072_u32))
+    /// );
+    /// ```
+    #[derive(Copy, Clone, Hash, Debug, Default)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub struct Scaled<T: TimeInt, const NUM: u32, const DENOM: u32>(pub T);
+
+    impl<T: TimeInt, const NUM: u32, const DENOM: u32> Eq for Scaled<T, NUM, DENOM> {}
+
+    impl<T: TimeInt, const NUM: u32, const DENOM: u32> Ord for Scaled<T, NUM, DENOM> {
+        fn cmp(&self, rhs: &Self) -> core::cmp::Ordering {
+            self.0.cmp(&rhs.0)
+        }
+    }
+
+    impl<
+            T: TimeInt,
+            RhsInt: TimeInt,
+            const NUM: u32,
+            const DENOM: u32,
+            const RHS_NUM: u32,
+            const RHS_DENOM: u32,
+        > PartialEq<Scaled<RhsInt, RHS_NUM, RHS_DENOM>> for Scaled<T, NUM, DENOM>
+    {
+        /// See [Comparisons](trait.Duration.html#comparisons)
+        fn eq(&self, rhs: &Scaled<RhsInt, RHS_NUM, RHS_DENOM>) -> bool {
+            self.partial_cmp(rhs) == Some(core::cmp::Ordering::Equal)
+        }
+    }
+
+    impl<
+            T: TimeInt,
+            RhsInt: TimeInt,
+            const NUM: u32,
+            const DENOM: u32,
+            const RHS_NUM: u32,
+            const RHS_DENOM: u32,
+        > PartialOrd<Scaled<RhsInt, RHS_NUM, RHS_DENOM>> for Scaled<T, NUM, DENOM>
+    {
+        /// See [Comparisons](trait.Duration.html#comparisons)
+        fn partial_cmp(
+            &self,
+            rhs: &Scaled<RhsInt, RHS_NUM, RHS_DENOM>,
+        ) -> Option<core::cmp::Ordering> {
+            if NUM == RHS_NUM && DENOM == RHS_DENOM {
+                Some(self.0.into_u128().cmp(&rhs.0.into_u128()))
+            } else {
+                fixed_point::cmp_values(self, rhs)
+            }
+        }
+    }
+
+    impl<T: TimeInt, const NUM: u32, const DENOM: u32> Scaled<T, NUM, DENOM> {
+        /// See [Constructing a duration](trait.Duration.html#constructing-a-duration)
+        pub const fn new(value: T) -> Self {
+            Self(value)
+        }
+
+        /// See [Get the integer part](trait.Duration.html#get-the-integer-part)
+        pub const fn integer(&self) -> T {
+            self.0
+        }
+
+        /// Converts into another duration unit (_named_ or _scaled_)
+        ///
+        /// # Errors
+        ///
+        /// See [Converting between `Duration`s](trait.Duration.html#converting-between-durations)
+        pub fn try_convert<Dest>(self) -> Result<Dest, ConversionError>
+        where
+            Dest: Duration + FixedPoint,
+            Dest::T: TryFrom<T>,
+        {
+            FixedPoint::from_fixed_point(self)
+        }
+    }
+
+    impl<const NUM: u32, const DENOM: u32> Scaled<u32, NUM, DENOM> {
+        crate::impl_const_conversions![u32];
+
+        /// See [Constant evaluation](trait.Duration.html#constant-evaluation)
+        pub const fn const_widen(self) -> Scaled<u64, NUM, DENOM> {
+            Scaled(self.0 as u64)
+        }
+    }
+
+    impl<const NUM: u32, const DENOM: u32> Scaled<u64, NUM, DENOM> {
+        crate::impl_const_conversions![u64];
+    }
+
+    impl<T: TimeInt, const NUM: u32, const DENOM: u32> Duration for Scaled<T, NUM, DENOM> {}
+
+    impl<T: TimeInt, const NUM: u32, const DENOM: u32> FixedPoint for Scaled<T, NUM, DENOM> {
+        type T = T;
+        const SCALING_FACTOR: Fraction = Fraction::new(NUM as u64, DENOM as u64);
+
+        /// See [Constructing a duration](trait.Duration.html#constructing-a-duration)
+        fn new(value: Self::T) -> Self {
+            Self(value)
+        }
+
+        /// See [Get the integer part](trait.Duration.html#get-the-integer-part)
+        fn integer(&self) -> Self::T {
+            self.0
+        }
+    }
+
+    impl<T: TimeInt, const NUM: u32, const DENOM: u32> fmt::Display for Scaled<T, NUM, DENOM> {
+        /// See [Formatting](trait.Duration.html#formatting)
+        fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+            fmt::Display::fmt(&self.0, f)
+        }
+    }
+
+    #[cfg(feature = "defmt")]
+    impl<T: TimeInt + defmt::Format, const NUM: u32, const DENOM: u32> defmt::Format
+        for Scaled<T, NUM, DENOM>
+    {
+        fn format(&self, f: defmt::Formatter<'_>) {
+            defmt::write!(f, "{} x {}/{} s", self.0, NUM, DENOM)
+        }
+    }
+
+    #[cfg(feature = "ufmt")]
+    impl<T: TimeInt + ufmt::uDisplay, const NUM: u32, const DENOM: u32> ufmt::uDisplay
+        for Scaled<T, NUM, DENOM>
+    {
+        /// See [Formatting](trait.Duration.html#formatting)
+        fn fmt<W>(&self, f: &mut ufmt::Formatter<'_, W>) -> Result<(), W::Error>
+        where
+            W: ufmt::uWrite + ?Sized,
+        {
+            ufmt::uDisplay::fmt(&self.0, f)
+        }
+    }
+
+    #[cfg(feature = "ufmt")]
+    impl<T: TimeInt + ufmt::uDebug, const NUM: u32, const DENOM: u32> ufmt::uDebug
+        for Scaled<T, NUM, DENOM>
+    {
+        fn fmt<W>(&self, f: &mut ufmt::Formatter<'_, W>) -> Result<(), W::Error>
+        where
+            W: ufmt::uWrite + ?Sized,
+        {
+            f.debug_tuple("Scaled")?.field(&self.0)?.finish()
+        }
+    }
+
+    impl<T: TimeInt, const NUM: u32, const DENOM: u32> FromStr for Scaled<T, NUM, DENOM>
+    where
+        T: TryFrom<u128>,
+    {
+        type Err = ConversionError;
+
+        /// See [Parsing](trait.Duration.html#parsing)
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            let ticks = parse_ticks(s, &Self::SCALING_FACTOR)?;
+            Ok(Self(
+                T::try_from(ticks).map_err(|_| ConversionError::Overflow)?,
+            ))
+        }
+    }
+
+    impl<T: TimeInt, Rhs: Duration, const NUM: u32, const DENOM: u32> ops::Add<Rhs>
+        for Scaled<T, NUM, DENOM>
+    where
+        Rhs: FixedPoint,
+        Self: TryFrom<Rhs>,
+    {
+        type Output = Self;
+
+        /// See [Add/Sub](trait.Duration.html#addsub)
+        fn add(self, rhs: Rhs) -> Self::Output {
+            <Self as FixedPoint>::add(self, rhs)
+        }
+    }
+
+    impl<T: TimeInt, Rhs: Duration, const NUM: u32, const DENOM: u32> ops::Sub<Rhs>
+        for Scaled<T, NUM, DENOM>
+    where
+        Rhs: FixedPoint,
+        Self: TryFrom<Rhs>,
+    {
+        type Output = Self;
+
+        /// See [Add/Sub](trait.Duration.html#addsub)
+        fn sub(self, rhs: Rhs) -> Self::Output {
+            <Self as FixedPoint>::sub(self, rhs)
+        }
+    }
+
+    impl<T: TimeInt, const NUM: u32, const DENOM: u32> ops::Mul<T> for Scaled<T, NUM, DENOM> {
+        type Output = Self;
+
+        /// See [Mul/Div](trait.Duration.html#muldiv)
+        fn mul(self, rhs: T) -> Self::Output {
+            <Self as FixedPoint>::mul(self, rhs)
+        }
+    }
+
+    impl<T: TimeInt, const NUM: u32, const DENOM: u32> ops::Div<T> for Scaled<T, NUM, DENOM> {
+        type Output = Self;
+
+        /// See [Mul/Div](trait.Duration.html#muldiv)
+        fn div(self, rhs: T) -> Self::Output {
+            <Self as FixedPoint>::div(self, rhs)
+        }
+    }
+
+    impl<T: TimeInt, Rhs: Duration, const NUM: u32, const DENOM: u32> ops::Rem<Rhs>
+        for Scaled<T, NUM, DENOM>
+    where
+        Rhs: FixedPoint,
+        Self: TryFrom<Rhs>,
+    {
+        type Output = Self;
+
+        /// See [Remainder](trait.Duration.html#remainder)
+        fn rem(self, rhs: Rhs) -> Self::Output {
+            <Self as FixedPoint>::rem(self, rhs)
+        }
+    }
+
+    impl<SourceInt: TimeInt, DestInt: TimeInt, const NUM: u32, const DENOM: u32>
+        TryFrom<Generic<SourceInt>> for Scaled<DestInt, NUM, DENOM>
+    where
+        DestInt: TryFrom<SourceInt>,
+    {
+        type Error = ConversionError;
+
+        /// See [Converting from a `Generic`
+        /// `Duration`](trait.Duration.html#converting-from-a-generic-duration)
+        fn try_from(generic_duration: Generic<SourceInt>) -> Result<Self, Self::Error> {
+            fixed_point::FixedPoint::from_ticks(
+                generic_duration.integer,
+                generic_duration.scaling_factor,
+            )
+        }
+    }
+
+    impl<T: TimeInt, const NUM: u32, const DENOM: u32> From<Scaled<T, NUM, DENOM>> for Generic<T> {
+        /// See [Converting to a `Generic`
+        /// `Duration`](trait.Duration.html#converting-to-a-generic-duration)
+        fn from(duration: Scaled<T, NUM, DENOM>) -> Self {
+            Self::new(duration.integer(), Scaled::<T, NUM, DENOM>::SCALING_FACTOR)
+        }
+    }
+
+    /// A duration of ticks of a specific [`Clock`](crate::Clock)
+    ///
+    /// The _integer_ type and the _scaling factor_ are those of the clock so, unlike a
+    /// [`Generic`] duration, a `Ticks` can only be used with the clock it was measured with. It is
+    /// returned by the subtraction of two [`Instant`](crate::Instant)s and is added to an
+    /// `Instant` of the same clock without any conversion.
+    ///
+    /// ```rust
+    /// use core::convert::TryFrom;
+    /// use embedded_time::{duration::*, Instant};
+    /// # #[derive(Debug)]
+    /// struct Clock;
+    /// impl embedded_time::Clock for Clock {
+    ///     type T = u32;
+    ///     const SCALING_FACTOR: Fraction = Fraction::new(1, 32_768);
+    ///     // ...
+    /// # fn try_now(&self) -> Result<Instant<Self>, embedded_time::clock::Error> {unimplemented!()}
+    /// }
+    ///
+    /// let elapsed = Instant::<Clock>::new(49_152) - Instant::<Clock>::new(16_384);
+    ///
+    /// assert_eq!(elapsed, Ticks::<Clock>::new(32_768));
+    /// assert_eq!(Milliseconds::<u32>::try_from(elapsed), Ok(Milliseconds(1_000_u32)));
+    /// assert_eq!(Ticks::<Clock>::try_from(Milliseconds(250_u32)), Ok(Ticks::new(8_192)));
+    /// assert_eq!(
+    ///     Instant::<Clock>::new(0).checked_add(elapsed),
+    ///     Some(Instant::<Clock>::new(32_768))
+    /// );
+    /// ```
+    pub struct Ticks<Clock: crate::Clock> {
+        integer: Clock::T,
+        _clock: PhantomData<Clock>,
+    }
+
+    impl<Clock: crate::Clock> Ticks<Clock> {
+        /// See [Constructing a duration](trait.Duration.html#constructing-a-duration)
+        pub fn new(integer: Clock::T) -> Self {
+            Self {
+                integer,
+                _clock: PhantomData,
+            }
+        }
+
+        /// See [Get the integer part](trait.Duration.html#get-the-integer-part)
+        pub fn integer(&self) -> Clock::T {
+            self.integer
+        }
+    }
+
+    impl<Clock: crate::Clock> Copy for Ticks<Clock> {}
+
+    impl<Clock: crate::Clock> Clone for Ticks<Clock> {
+        fn clone(&self) -> Self {
+            *self
+        }
+    }
+
+    impl<Clock: crate::Clock> Default for Ticks<Clock> {
+        fn default() -> Self {
+            Self::new(0.into())
+        }
+    }
+
+    impl<Clock: crate::Clock> fmt::Debug for Ticks<Clock> {
+        fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+            f.debug_tuple("Ticks").field(&self.integer).finish()
+        }
+    }
 
-            impl<T: TimeInt> Duration for $name<T> {}
+    impl<Clock: crate::Clock> PartialEq for Ticks<Clock> {
+        /// See [Comparisons](trait.Duration.html#comparisons)
+        fn eq(&self, rhs: &Self) -> bool {
+            self.integer == rhs.integer
+        }
+    }
 
-            impl<T: TimeInt> FixedPoint for $name<T> {
-                type T = T;
-                const SCALING_FACTOR: Fraction = Fraction::new($numer, $denom);
+    impl<Clock: crate::Clock> Eq for Ticks<Clock> {}
 
-                /// See [Constructing a duration](trait.Duration.html#constructing-a-duration)
-                fn new(value: Self::T) -> Self {
-                    Self(value)
-                }
+    impl<Clock: crate::Clock> PartialOrd for Ticks<Clock> {
+        /// See [Comparisons](trait.Duration.html#comparisons)
+        fn partial_cmp(&self, rhs: &Self) -> Option<cmp::Ordering> {
+            Some(self.cmp(rhs))
+        }
+    }
 
-                /// See [Get the integer part](trait.Duration.html#get-the-integer-part)
-                fn integer(&self) -> Self::T {
-                    self.0
-                }
-            }
+    impl<Clock: crate::Clock> Ord for Ticks<Clock> {
+        fn cmp(&self, rhs: &Self) -> cmp::Ordering {
+            self.integer.cmp(&rhs.integer)
+        }
+    }
 
-            impl<T: TimeInt> fmt::Display for $name<T> {
-                /// See [Formatting](trait.Duration.html#formatting)
-                fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-                    fmt::Display::fmt(&self.0, f)
-                }
-            }
+    impl<Clock: crate::Clock> Hash for Ticks<Clock> {
+        fn hash<H: Hasher>(&self, state: &mut H) {
+            Clock::SCALING_FACTOR.hash(state);
+            self.integer.hash(state);
+        }
+    }
 
-            impl<T: TimeInt, Rhs: Duration> ops::Add<Rhs> for $name<T>
-            where
-                Rhs: FixedPoint,
-                Self: TryFrom<Rhs>,
-            {
-                type Output = Self;
+    impl<Clock: crate::Clock> Duration for Ticks<Clock> {}
 
-                /// See [Add/Sub](trait.Duration.html#addsub)
-                fn add(self, rhs: Rhs) -> Self::Output {
-                    <Self as FixedPoint>::add(self, rhs)
-                }
-            }
+    impl<Clock: crate::Clock> FixedPoint for Ticks<Clock> {
+        type T = Clock::T;
+        const SCALING_FACTOR: Fraction = Clock::SCALING_FACTOR;
 
-            impl<T: TimeInt, Rhs: Duration> ops::Sub<Rhs> for $name<T>
-            where
-                Self: TryFrom<Rhs>,
-                Rhs: FixedPoint,
-            {
-                type Output = Self;
+        /// See [Constructing a duration](trait.Duration.html#constructing-a-duration)
+        fn new(value: Self::T) -> Self {
+            Self::new(value)
+        }
 
-                /// See [Add/Sub](trait.Duration.html#addsub)
-                fn sub(self, rhs: Rhs) -> Self::Output {
-                    <Self as FixedPoint>::sub(self, rhs)
-                }
-            }
+        /// See [Get the integer part](trait.Duration.html#get-the-integer-part)
+        fn integer(&self) -> Self::T {
+            self.integer
+        }
+    }
 
-            impl<T: TimeInt, Clock: crate::Clock> ops::Add<crate::Instant<Clock>> for $name<T>
-            where
-                Clock::T: TryFrom<T>,
-            {
-                type Output = crate::Instant<Clock>;
-
-                // Symmetric version of Instant + Duration
-                fn add(self, rhs: crate::Instant<Clock>) -> Self::Output {
-                    if let Some(v) = rhs.checked_add(self) {
-                        v
-                    } else {
-                        panic!("Add failed")
-                    }
-                }
-            }
+    impl<Clock: crate::Clock> fmt::Display for Ticks<Clock> {
+        /// See [Formatting](trait.Duration.html#formatting)
+        fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+            fmt::Display::fmt(&self.integer, f)
+        }
+    }
 
-            impl<T: TimeInt> ops::Mul<T> for $name<T> {
-                type Output = Self;
+    #[cfg(feature = "defmt")]
+    impl<Clock: crate::Clock> defmt::Format for Ticks<Clock>
+    where
+        Clock::T: defmt::Format,
+    {
+        fn format(&self, f: defmt::Formatter<'_>) {
+            defmt::write!(f, "{} ticks", self.integer)
+        }
+    }
 
-                /// See [Mul/Div](trait.Duration.html#muldiv)
-                fn mul(self, rhs: T) -> Self::Output {
-                    <Self as FixedPoint>::mul(self, rhs)
-                }
-            }
+    #[cfg(feature = "ufmt")]
+    impl<Clock: crate::Clock> ufmt::uDisplay for Ticks<Clock>
+    where
+        Clock::T: ufmt::uDisplay,
+    {
+        /// See [Formatting](trait.Duration.html#formatting)
+        fn fmt<W>(&self, f: &mut ufmt::Formatter<'_, W>) -> Result<(), W::Error>
+        where
+            W: ufmt::uWrite + ?Sized,
+        {
+            ufmt::uDisplay::fmt(&self.integer, f)
+        }
+    }
 
-            impl<T: TimeInt> ops::Div<T> for $name<T> {
-                type Output = Self;
+    #[cfg(feature = "ufmt")]
+    impl<Clock: crate::Clock> ufmt::uDebug for Ticks<Clock>
+    where
+        Clock::T: ufmt::uDebug,
+    {
+        fn fmt<W>(&self, f: &mut ufmt::Formatter<'_, W>) -> Result<(), W::Error>
+        where
+            W: ufmt::uWrite + ?Sized,
+        {
+            f.debug_tuple("Ticks")?.field(&self.integer)?.finish()
+        }
+    }
 
-                /// See [Mul/Div](trait.Duration.html#muldiv)
-                fn div(self, rhs: T) -> Self::Output {
-                    <Self as FixedPoint>::div(self, rhs)
-                }
-            }
+    impl<Clock: crate::Clock, Rhs: Duration> ops::Add<Rhs> for Ticks<Clock>
+    where
+        Rhs: FixedPoint,
+        Self: TryFrom<Rhs>,
+    {
+        type Output = Self;
 
-            impl<T: TimeInt, Rhs: Duration> ops::Rem<Rhs> for $name<T>
-            where
-                Self: TryFrom<Rhs>,
-                Rhs: FixedPoint,
-            {
-                type Output = Self;
+        /// See [Add/Sub](trait.Duration.html#addsub)
+        fn add(self, rhs: Rhs) -> Self::Output {
+            <Self as FixedPoint>::add(self, rhs)
+        }
+    }
 
-                /// See [Remainder](trait.Duration.html#remainder)
-                fn rem(self, rhs: Rhs) -> Self::Output {
-                    <Self as FixedPoint>::rem(self, rhs)
-                }
-            }
+    impl<Clock: crate::Clock, Rhs: Duration> ops::Sub<Rhs> for Ticks<Clock>
+    where
+        Rhs: FixedPoint,
+        Self: TryFrom<Rhs>,
+    {
+        type Output = Self;
 
-            impl<SourceInt: TimeInt, DestInt: TimeInt> TryFrom<Generic<SourceInt>>
-                for $name<DestInt>
-            where
-                DestInt: TryFrom<SourceInt>,
-            {
-                type Error = ConversionError;
+        /// See [Add/Sub](trait.Duration.html#addsub)
+        fn sub(self, rhs: Rhs) -> Self::Output {
+            <Self as FixedPoint>::sub(self, rhs)
+        }
+    }
 
-                /// See [Converting from a `Generic`
-                /// `Duration`](trait.Duration.html#converting-from-a-generic-duration)
-                fn try_from(generic_duration: Generic<SourceInt>) -> Result<Self, Self::Error> {
-                    fixed_point::FixedPoint::from_ticks(
-                        generic_duration.integer,
-                        generic_duration.scaling_factor,
-                    )
-                }
-            }
+    impl<Clock: crate::Clock> ops::Add<crate::Instant<Clock>> for Ticks<Clock> {
+        type Output = crate::Instant<Clock>;
 
-            impl<T: TimeInt> From<$name<T>> for Generic<T> {
-                /// See [Converting to a `Generic`
-                /// `Duration`](trait.Duration.html#converting-to-a-generic-duration)
-                fn from(duration: $name<T>) -> Self {
-                    Self::new(duration.integer(), $name::<T>::SCALING_FACTOR)
-                }
+        // Symmetric version of Instant + Duration
+        fn add(self, rhs: crate::Instant<Clock>) -> Self::Output {
+            if let Some(v) = rhs.checked_add(self) {
+                v
+            } else {
+                panic!("Add failed")
             }
-        };
+        }
+    }
 
-        ( $name:ident, ($numer:expr, $denom:expr), ge_secs ) => {
-            impl_duration![$name, ($numer, $denom)];
+    impl<Clock: crate::Clock> ops::Mul<Clock::T> for Ticks<Clock> {
+        type Output = Self;
 
-            // TODO: Make this more custom (seconds and higher<u32> can be `From`) and comprehensive
-            // (allow u64 durations)
-            impl TryFrom<$name<u32>> for core::time::Duration {
-                type Error = ConversionError;
+        /// See [Mul/Div](trait.Duration.html#muldiv)
+        fn mul(self, rhs: Clock::T) -> Self::Output {
+            <Self as FixedPoint>::mul(self, rhs)
+        }
+    }
 
-                /// See [Converting to `core`
-                /// types](trait.Duration.html#converting-to-core-types)
-                fn try_from(duration: $name<u32>) -> Result<Self, Self::Error> {
-                    let seconds: Seconds<u64> = duration.into();
-                    Ok(Self::from_secs(seconds.integer()))
-                }
-            }
+    impl<Clock: crate::Clock> ops::Div<Clock::T> for Ticks<Clock> {
+        type Output = Self;
 
-            impl TryFrom<core::time::Duration> for $name<u32> {
-                type Error = ConversionError;
+        /// See [Mul/Div](trait.Duration.html#muldiv)
+        fn div(self, rhs: Clock::T) -> Self::Output {
+            <Self as FixedPoint>::div(self, rhs)
+        }
+    }
 
-                /// See [Converting from `core`
-                /// types](trait.Duration.html#converting-from-core-types)
-                fn try_from(core_duration: core::time::Duration) -> Result<Self, Self::Error> {
-                    let seconds = Seconds(core_duration.as_secs());
-                    seconds.try_into()
-                }
-            }
+    impl<Clock: crate::Clock, Rhs: Duration> ops::Rem<Rhs> for Ticks<Clock>
+    where
+        Rhs: FixedPoint,
+        Self: TryFrom<Rhs>,
+    {
+        type Output = Self;
 
-            impl From<core::time::Duration> for $name<u64> {
-                /// See [Converting from `core`
-                /// types](trait.Duration.html#converting-from-core-types)
-                fn from(core_duration: core::time::Duration) -> Self {
-                    let seconds = Seconds(core_duration.as_secs());
-                    seconds.into()
-                }
-            }
-        };
-        ( $name:ident, ($numer:expr, $denom:expr), $from_core_dur:ident, $as_core_dur:ident ) => {
-            impl_duration![$name, ($numer, $denom)];
+        /// See [Remainder](trait.Duration.html#remainder)
+        fn rem(self, rhs: Rhs) -> Self::Output {
+            <Self as FixedPoint>::rem(self, rhs)
+        }
+    }
 
-            impl<T: TimeInt> TryFrom<$name<T>> for core::time::Duration
-            where
-                u64: From<T>,
-            {
-                type Error = ConversionError;
+    impl<Clock: crate::Clock, SourceInt: TimeInt> TryFrom<Generic<SourceInt>> for Ticks<Clock>
+    where
+        Clock::T: TryFrom<SourceInt>,
+    {
+        type Error = ConversionError;
 
-                /// See [Converting to `core` types](trait.Duration.html#converting-to-core-types)
-                fn try_from(duration: $name<T>) -> Result<Self, Self::Error> {
-                    Ok(Self::$from_core_dur(duration.integer().into()))
-                }
-            }
+        /// See [Converting from a `Generic`
+        /// `Duration`](trait.Duration.html#converting-from-a-generic-duration)
+        fn try_from(generic_duration: Generic<SourceInt>) -> Result<Self, Self::Error> {
+            fixed_point::FixedPoint::from_ticks(
+                generic_duration.integer,
+                generic_duration.scaling_factor,
+            )
+        }
+    }
 
-            impl<T: TimeInt> TryFrom<core::time::Duration> for $name<T>
-            where
-                T: TryFrom<u128>,
-            {
-                type Error = ConversionError;
+    impl<Clock: crate::Clock> From<Ticks<Clock>> for Generic<Clock::T> {
+        /// See [Converting to a `Generic`
+        /// `Duration`](trait.Duration.html#converting-to-a-generic-duration)
+        fn from(duration: Ticks<Clock>) -> Self {
+            Self::new(duration.integer, Clock::SCALING_FACTOR)
+        }
+    }
 
-                /// See [Converting from `core`
-                /// types](trait.Duration.html#converting-from-core-types)
-                fn try_from(core_duration: core::time::Duration) -> Result<Self, Self::Error> {
-                    Ok(Self(
-                        core_duration
-                            .$as_core_dur()
-                            .try_into()
-                            .map_err(|_| ConversionError::ConversionFailure)?,
-                    ))
-                }
-            }
-        };
+    impl<Clock: crate::Clock, SourceInt: TimeInt, const NUM: u32, const DENOM: u32>
+        TryFrom<Scaled<SourceInt, NUM, DENOM>> for Ticks<Clock>
+    where
+        Clock::T: TryFrom<SourceInt>,
+    {
+        type Error = ConversionError;
+
+        /// See [Converting between `Duration`s](trait.Duration.html#converting-between-durations)
+        fn try_from(duration: Scaled<SourceInt, NUM, DENOM>) -> Result<Self, Self::Error> {
+            duration.try_convert()
+        }
     }
-    impl_duration![Hours, (3600, 1), ge_secs];
-    impl_duration![Minutes, (60, 1), ge_secs];
-    impl_duration![Seconds, (1, 1), ge_secs];
-    impl_duration![Milliseconds, (1, 1_000), from_millis, as_millis];
-    impl_duration![Microseconds, (1, 1_000_000), from_micros, as_micros];
-    impl_duration![Nanoseconds, (1, 1_000_000_000), from_nanos, as_nanos];
 
-    macro_rules! impl_partial_eq {
-        ($name:ident) => {
-            impl<T: TimeInt, RhsInt: TimeInt> cmp::PartialEq<$name<RhsInt>> for $name<T>
-            where
-                T: TryFrom<RhsInt>,
-            {
-                /// See [Comparisons](trait.Duration.html#comparisons)
-                fn eq(&self, rhs: &$name<RhsInt>) -> bool {
-                    match T::try_from(rhs.integer()) {
-                        Ok(rhs_integer) => self.integer() == rhs_integer,
-                        Err(_) => false,
-                    }
-                }
-            }
-        };
+    impl<Clock: crate::Clock, DestInt: TimeInt, const NUM: u32, const DENOM: u32>
+        TryFrom<Ticks<Clock>> for Scaled<DestInt, NUM, DENOM>
+    where
+        DestInt: TryFrom<Clock::T>,
+    {
+        type Error = ConversionError;
+
+        /// See [Converting between `Duration`s](trait.Duration.html#converting-between-durations)
+        fn try_from(duration: Ticks<Clock>) -> Result<Self, Self::Error> {
+            fixed_point::FixedPoint::from_fixed_point(duration)
+        }
     }
-    impl_partial_eq![Hours];
-    impl_partial_eq![Minutes];
-    impl_partial_eq![Seconds];
-    impl_partial_eq![Milliseconds];
-    impl_partial_eq![Microseconds];
-    impl_partial_eq![Nanoseconds];
 
     macro_rules! impl_big_partial_eq_small {
         ($big:ident) => {};
@@ -781,12 +2972,15 @@ pub mod units {
         };
     }
     impl_big_partial_eq_small![
+        Weeks,
+        Days,
         Hours,
         Minutes,
         Seconds,
         Milliseconds,
         Microseconds,
-        Nanoseconds
+        Nanoseconds,
+        Picoseconds
     ];
 
     macro_rules! impl_small_partial_eq_big {
@@ -812,37 +3006,17 @@ pub mod units {
 
     }
     impl_small_partial_eq_big![
+        Picoseconds,
         Nanoseconds,
         Microseconds,
         Milliseconds,
         Seconds,
         Minutes,
-        Hours
+        Hours,
+        Days,
+        Weeks
     ];
 
-    macro_rules! impl_partial_ord {
-        ($name:ident) => {
-            impl<T: TimeInt, RhsInt: TimeInt> PartialOrd<$name<RhsInt>> for $name<T>
-            where
-                T: TryFrom<RhsInt>,
-            {
-                /// See [Comparisons](trait.Duration.html#comparisons)
-                fn partial_cmp(&self, rhs: &$name<RhsInt>) -> Option<core::cmp::Ordering> {
-                    match T::try_from(rhs.integer()) {
-                        Ok(rhs_integer) => Some(self.integer().cmp(&rhs_integer)),
-                        Err(_) => Some(core::cmp::Ordering::Less),
-                    }
-                }
-            }
-        };
-    }
-    impl_partial_ord![Hours];
-    impl_partial_ord![Minutes];
-    impl_partial_ord![Seconds];
-    impl_partial_ord![Milliseconds];
-    impl_partial_ord![Microseconds];
-    impl_partial_ord![Nanoseconds];
-
     macro_rules! impl_big_partial_ord_small {
         ($big:ident) => {};
         ($big:ident, $($small:ident),+) => {
@@ -865,12 +3039,15 @@ pub mod units {
         };
     }
     impl_big_partial_ord_small![
+        Weeks,
+        Days,
         Hours,
         Minutes,
         Seconds,
         Milliseconds,
         Microseconds,
-        Nanoseconds
+        Nanoseconds,
+        Picoseconds
     ];
 
     macro_rules! impl_small_partial_ord_big {
@@ -896,45 +3073,17 @@ pub mod units {
 
     }
     impl_small_partial_ord_big![
+        Picoseconds,
         Nanoseconds,
         Microseconds,
         Milliseconds,
         Seconds,
         Minutes,
-        Hours
+        Hours,
+        Days,
+        Weeks
     ];
 
-    macro_rules! impl_from {
-        ($name:ident) => {
-            impl From<$name<u32>> for $name<u64> {
-                /// See [Converting between
-                /// `Duration`s](trait.Duration.html#converting-between-durations)
-                fn from(source: $name<u32>) -> Self {
-                    Self::new(u64::from(source.integer()))
-                }
-            }
-
-            impl TryFrom<$name<u64>> for $name<u32> {
-                type Error = ConversionError;
-
-                /// See [Converting between
-                /// `Duration`s](trait.Duration.html#converting-between-durations)
-                fn try_from(source: $name<u64>) -> Result<Self, Self::Error> {
-                    fixed_point::FixedPoint::from_ticks(
-                        source.integer(),
-                        $name::<u64>::SCALING_FACTOR,
-                    )
-                }
-            }
-        };
-    }
-    impl_from![Hours];
-    impl_from![Minutes];
-    impl_from![Seconds];
-    impl_from![Milliseconds];
-    impl_from![Microseconds];
-    impl_from![Nanoseconds];
-
     macro_rules! impl_from_smaller {
         ($name:ident) => {};
         ($big:ident, $($small:ident),+) => {
@@ -943,7 +3092,7 @@ pub mod units {
                 {
                     /// See [Converting between `Duration`s](trait.Duration.html#converting-between-durations)
                     fn from(small: $small<T>) -> Self {
-                        if let Ok(v) = fixed_point::FixedPoint::from_ticks(small.integer(), $small::<T>::SCALING_FACTOR) {
+                        if let Ok(v) = fixed_point::FixedPoint::from_fixed_point(small) {
                             v
                         } else {
                             panic!("From failed")
@@ -955,7 +3104,7 @@ pub mod units {
                 {
                     /// See [Converting between `Duration`s](trait.Duration.html#converting-between-durations)
                     fn from(small: $small<u32>) -> Self {
-                        if let Ok(v) = fixed_point::FixedPoint::from_ticks(small.integer(), $small::<u32>::SCALING_FACTOR) {
+                        if let Ok(v) = fixed_point::FixedPoint::from_fixed_point(small) {
                             v
                         } else {
                             panic!("From failed")
@@ -969,10 +3118,7 @@ pub mod units {
 
                     /// See [Converting between `Duration`s](trait.Duration.html#converting-between-durations)
                     fn try_from(small: $small<u64>) -> Result<Self, Self::Error> {
-                        fixed_point::FixedPoint::from_ticks(
-                            small.integer(),
-                            $small::<u64>::SCALING_FACTOR,
-                        )
+                        fixed_point::FixedPoint::from_fixed_point(small)
                     }
                 }
             )+
@@ -982,40 +3128,28 @@ pub mod units {
 
     }
     impl_from_smaller![
+        Weeks,
+        Days,
         Hours,
         Minutes,
         Seconds,
         Milliseconds,
         Microseconds,
-        Nanoseconds
+        Nanoseconds,
+        Picoseconds
     ];
 
     macro_rules! impl_from_bigger {
         ($small:ident) => {};
         ($small:ident, $($big:ident),+) => {
             $(
-                impl From<$big<u32>> for $small<u64>
-                {
-                    /// See [Converting between `Duration`s](trait.Duration.html#converting-between-durations)
-                    fn from(big: $big<u32>) -> Self {
-                        if let Ok(v) = fixed_point::FixedPoint::from_ticks(big.integer(), $big::<u32>::SCALING_FACTOR) {
-                            v
-                        } else {
-                            panic!("From failed")
-                        }
-                    }
-                }
-
                 impl<T: TimeInt> TryFrom<$big<T>> for $small<T>
                 {
                     type Error = ConversionError;
 
                     /// See [Converting between `Duration`s](trait.Duration.html#converting-between-durations)
                     fn try_from(big: $big<T>) -> Result<Self, Self::Error> {
-                        fixed_point::FixedPoint::from_ticks(
-                            big.integer(),
-                            $big::<T>::SCALING_FACTOR,
-                        )
+                        fixed_point::FixedPoint::from_fixed_point(big)
                     }
                 }
 
@@ -1025,10 +3159,7 @@ pub mod units {
 
                     /// See [Converting between `Duration`s](trait.Duration.html#converting-between-durations)
                     fn try_from(big: $big<u64>) -> Result<Self, Self::Error> {
-                        fixed_point::FixedPoint::from_ticks(
-                            big.integer(),
-                            $big::<u64>::SCALING_FACTOR,
-                        )
+                        fixed_point::FixedPoint::from_fixed_point(big)
                     }
                 }
             )+
@@ -1038,12 +3169,101 @@ pub mod units {
     }
 
     impl_from_bigger![
+        Picoseconds,
+        Nanoseconds,
+        Microseconds,
+        Milliseconds,
+        Seconds,
+        Minutes,
+        Hours,
+        Days,
+        Weeks
+    ];
+
+    macro_rules! impl_widen_from_bigger {
+        ($small:ident: $($big:ident),*) => {
+            $(
+                impl From<$big<u32>> for $small<u64>
+                {
+                    /// See [Converting between `Duration`s](trait.Duration.html#converting-between-durations)
+                    fn from(big: $big<u32>) -> Self {
+                        if let Ok(v) = fixed_point::FixedPoint::from_fixed_point(big) {
+                            v
+                        } else {
+                            panic!("From failed")
+                        }
+                    }
+                }
+            )*
+        };
+    }
+    // only the pairs where `u32::MAX` of the bigger unit fits in a `u64` of the smaller one
+    impl_widen_from_bigger![Picoseconds: Nanoseconds, Microseconds, Milliseconds];
+    impl_widen_from_bigger![Nanoseconds: Microseconds, Milliseconds, Seconds];
+    impl_widen_from_bigger![Microseconds: Milliseconds, Seconds, Minutes, Hours];
+    impl_widen_from_bigger![Milliseconds: Seconds, Minutes, Hours, Days, Weeks];
+    impl_widen_from_bigger![Seconds: Minutes, Hours, Days, Weeks];
+    impl_widen_from_bigger![Minutes: Hours, Days, Weeks];
+    impl_widen_from_bigger![Hours: Days, Weeks];
+    impl_widen_from_bigger![Days: Weeks];
+
+    macro_rules! impl_widen_try_from_bigger {
+        ($small:ident: $($big:ident),*) => {
+            $(
+                impl TryFrom<$big<u32>> for $small<u64>
+                {
+                    type Error = ConversionError;
+
+                    /// See [Converting between `Duration`s](trait.Duration.html#converting-between-durations)
+                    fn try_from(big: $big<u32>) -> Result<Self, Self::Error> {
+                        fixed_point::FixedPoint::from_fixed_point(big)
+                    }
+                }
+            )*
+        };
+    }
+    // the rest can overflow even the `u64`
+    impl_widen_try_from_bigger![Picoseconds: Seconds, Minutes, Hours, Days, Weeks];
+    impl_widen_try_from_bigger![Nanoseconds: Minutes, Hours, Days, Weeks];
+    impl_widen_try_from_bigger![Microseconds: Days, Weeks];
+
+    macro_rules! impl_add_finer {
+        ($fine:ident) => {};
+        ($fine:ident, $($coarse:ident),+) => {
+            $(
+                impl<T: TimeInt> AddFiner<$coarse<T>> for $fine<T> {
+                    type Output = Self;
+
+                    /// See [`AddFiner`]
+                    fn checked_add_finer(self, rhs: $coarse<T>) -> Result<Self, ConversionError> {
+                        Duration::checked_add(self, rhs)
+                    }
+                }
+
+                impl<T: TimeInt> AddFiner<$fine<T>> for $coarse<T> {
+                    type Output = $fine<T>;
+
+                    /// See [`AddFiner`]
+                    fn checked_add_finer(self, rhs: $fine<T>) -> Result<$fine<T>, ConversionError> {
+                        Duration::checked_add($fine::<T>::try_from(self)?, rhs)
+                    }
+                }
+            )+
+
+            impl_add_finer![$fine];
+            impl_add_finer![$($coarse),+];
+        };
+    }
+    impl_add_finer![
+        Picoseconds,
         Nanoseconds,
         Microseconds,
         Milliseconds,
         Seconds,
         Minutes,
-        Hours
+        Hours,
+        Days,
+        Weeks
     ];
 
     /// Create duration-based extensions from primitive numeric types.
@@ -1051,14 +3271,21 @@ pub mod units {
     /// ```rust
     /// use embedded_time::duration::*;
     ///
+    /// assert_eq!(5.picoseconds(), Picoseconds(5_u32));
     /// assert_eq!(5.nanoseconds(), Nanoseconds(5_u32));
     /// assert_eq!(5.microseconds(), Microseconds(5_u32));
     /// assert_eq!(5.milliseconds(), Milliseconds(5_u32));
     /// assert_eq!(5.seconds(), Seconds(5_u32));
     /// assert_eq!(5.minutes(), Minutes(5_u32));
     /// assert_eq!(5.hours(), Hours(5_u32));
+    /// assert_eq!(5.days(), Days(5_u32));
+    /// assert_eq!(5.weeks(), Weeks(5_u32));
     /// ```
     pub trait Extensions: TimeInt {
+        /// picoseconds
+        fn picoseconds(self) -> Picoseconds<Self> {
+            Picoseconds::new(self)
+        }
         /// nanoseconds
         fn nanoseconds(self) -> Nanoseconds<Self> {
             Nanoseconds::new(self)
@@ -1083,6 +3310,14 @@ pub mod units {
         fn hours(self) -> Hours<Self> {
             Hours::new(self)
         }
+        /// days
+        fn days(self) -> Days<Self> {
+            Days::new(self)
+        }
+        /// weeks
+        fn weeks(self) -> Weeks<Self> {
+            Weeks::new(self)
+        }
     }
 
     impl Extensions for u32 {}
@@ -1,7 +1,7 @@
 //! Fixed-point values
-use crate::{fraction::Fraction, time_int::TimeInt, ConversionError};
-use core::{convert::TryFrom, mem::size_of, prelude::v1::*};
-use num::{Bounded, CheckedDiv, CheckedMul};
+use crate::{fraction::Fraction, time_int::TimeInt, ConversionError, Rounding};
+use core::{cmp, convert::TryFrom, marker::PhantomData, prelude::v1::*};
+use num::{Bounded, CheckedAdd, CheckedDiv, CheckedMul, CheckedSub};
 
 /// Fixed-point value type
 ///
@@ -33,11 +33,12 @@ pub trait FixedPoint: Sized + Copy {
     ///
     /// # Errors
     ///
-    /// Failure will only occur if the provided value does not fit in the selected destination type.
+    /// The conversion is exact (see [`FixedPoint::into_ticks()`]), so failure will only occur if the
+    /// provided value does not fit in the selected destination type.
     ///
-    /// - [`ConversionError::Unspecified`]
-    /// - [`ConversionError::Overflow`]
-    /// - [`ConversionError::ConversionFailure`]
+    /// - [`ConversionError::Overflow`] : The result doesn't fit in either integer type.
+    /// - [`ConversionError::ConversionFailure`] : The result only fits in the source integer type.
+    /// - [`ConversionError::DivByZero`] : A _scaling factor_ is `0`.
     #[doc(hidden)]
     fn from_ticks<SourceInt: TimeInt>(
         ticks: SourceInt,
@@ -46,37 +47,61 @@ pub trait FixedPoint: Sized + Copy {
     where
         Self::T: TryFrom<SourceInt>,
     {
-        if size_of::<Self::T>() > size_of::<SourceInt>() {
-            // the dest integer is wider than the source, first promote the source integer to the
-            // dest type
-            let ticks = Self::T::try_from(ticks).map_err(|_| ConversionError::ConversionFailure)?;
-            let ticks =
-                Self::convert_ticks(ticks, scaling_factor).ok_or(ConversionError::Unspecified)?;
-            Ok(Self::new(ticks))
-        } else {
-            let ticks =
-                Self::convert_ticks(ticks, scaling_factor).ok_or(ConversionError::Unspecified)?;
-            let ticks = Self::T::try_from(ticks).map_err(|_| ConversionError::ConversionFailure)?;
-            Ok(Self::new(ticks))
+        let ticks = scale_ticks(
+            ticks.into_u128(),
+            scaling_factor,
+            Self::SCALING_FACTOR,
+            Rounding::Floor,
+        )?;
+        narrow::<SourceInt, Self::T>(ticks).map(Self::new)
+    }
+
+    /// Converts from another fixed-point value (truncated)
+    ///
+    /// Values with the same _scaling factor_ (eg. [`Ticks`](crate::duration::Ticks) of a clock
+    /// added to an [`Instant`](crate::Instant) of that clock) only have their _integer_ converted.
+    /// When the conversion between the two _scaling factors_ is known at compile time to fit a
+    /// [`FastConversion`], it is performed with a multiplication and a multiply-shift instead of a
+    /// division. Otherwise, or if the fast path overflows, it falls back to
+    /// [`FixedPoint::from_ticks()`].
+    ///
+    /// # Errors
+    ///
+    /// The same as [`FixedPoint::from_ticks()`].
+    #[doc(hidden)]
+    fn from_fixed_point<Source: FixedPoint>(source: Source) -> Result<Self, ConversionError>
+    where
+        Self::T: TryFrom<Source::T>,
+    {
+        if Source::SCALING_FACTOR == Self::SCALING_FACTOR {
+            return Self::T::try_from(source.integer())
+                .map(Self::new)
+                .map_err(|_| ConversionError::ConversionFailure);
+        }
+
+        if let Some(conversion) = Conversion::<Source, Self>::FAST {
+            let ticks = u64::try_from(source.integer().into_u128())
+                .ok()
+                .and_then(|ticks| conversion.convert(ticks))
+                .and_then(|ticks| Self::T::try_from_u128(ticks.into()));
+            if let Some(ticks) = ticks {
+                return Ok(Self::new(ticks));
+            }
         }
+
+        Self::from_ticks(source.integer(), Source::SCALING_FACTOR)
     }
 
     #[doc(hidden)]
     fn convert_ticks<T: TimeInt>(ticks: T, scaling_factor: Fraction) -> Option<T> {
-        if (scaling_factor >= Fraction::new(1, 1) && Self::SCALING_FACTOR <= Fraction::new(1, 1))
-            || (scaling_factor <= Fraction::new(1, 1)
-                && Self::SCALING_FACTOR >= Fraction::new(1, 1))
-        {
-            TimeInt::checked_div_fraction(
-                &TimeInt::checked_mul_fraction(&ticks, &scaling_factor)?,
-                &Self::SCALING_FACTOR,
-            )
-        } else {
-            TimeInt::checked_mul_fraction(
-                &ticks,
-                &scaling_factor.checked_div(&Self::SCALING_FACTOR)?,
-            )
-        }
+        scale_ticks(
+            ticks.into_u128(),
+            scaling_factor,
+            Self::SCALING_FACTOR,
+            Rounding::Floor,
+        )
+        .ok()
+        .and_then(T::try_from_u128)
     }
 
     /// Returns the _integer_ of the fixed-point value after converting to the _scaling factor_
@@ -92,57 +117,107 @@ pub trait FixedPoint: Sized + Copy {
     ///
     /// # Errors
     ///
-    /// Failure will only occur if the provided value does not fit in the selected destination type.
+    /// The conversion is computed exactly with [`u128`] intermediates ([`u64`] ones where they
+    /// suffice), so failure will only occur if the result does not fit in the selected destination
+    /// type.
+    ///
+    /// [`ConversionError::Overflow`] : The result doesn't fit in either integer type.
     ///
-    /// [`ConversionError::Overflow`] : The conversion of the _scaling factor_ causes an overflow.
-    /// [`ConversionError::ConversionFailure`] : The _integer_ type cast to that of the destination
-    /// fails.
+    /// [`ConversionError::ConversionFailure`] : The result only fits in the source integer type.
+    ///
+    /// [`ConversionError::DivByZero`] : A _scaling factor_ is `0`.
     #[doc(hidden)]
     fn into_ticks<T: TimeInt>(self, fraction: Fraction) -> Result<T, ConversionError>
     where
         Self::T: TimeInt,
         T: TryFrom<Self::T>,
     {
-        if size_of::<T>() > size_of::<Self::T>() {
-            let ticks =
-                T::try_from(self.integer()).map_err(|_| ConversionError::ConversionFailure)?;
-
-            if fraction > Fraction::new(1, 1) {
-                TimeInt::checked_div_fraction(
-                    &TimeInt::checked_mul_fraction(&ticks, &Self::SCALING_FACTOR)
-                        .ok_or(ConversionError::Unspecified)?,
-                    &fraction,
-                )
-                .ok_or(ConversionError::Unspecified)
-            } else {
-                TimeInt::checked_mul_fraction(
-                    &ticks,
-                    &Self::SCALING_FACTOR
-                        .checked_div(&fraction)
-                        .ok_or(ConversionError::Unspecified)?,
-                )
-                .ok_or(ConversionError::Unspecified)
-            }
-        } else {
-            let ticks = if Self::SCALING_FACTOR > Fraction::new(1, 1) {
-                TimeInt::checked_div_fraction(
-                    &TimeInt::checked_mul_fraction(&self.integer(), &Self::SCALING_FACTOR)
-                        .ok_or(ConversionError::Unspecified)?,
-                    &fraction,
-                )
-                .ok_or(ConversionError::Unspecified)?
-            } else {
-                TimeInt::checked_mul_fraction(
-                    &self.integer(),
-                    &Self::SCALING_FACTOR
-                        .checked_div(&fraction)
-                        .ok_or(ConversionError::Unspecified)?,
-                )
-                .ok_or(ConversionError::Unspecified)?
-            };
-
-            T::try_from(ticks).map_err(|_| ConversionError::ConversionFailure)
-        }
+        let ticks = scale_ticks(
+            self.integer().into_u128(),
+            Self::SCALING_FACTOR,
+            fraction,
+            Rounding::Floor,
+        )?;
+        narrow::<Self::T, T>(ticks)
+    }
+
+    /// Returns the _integer_ of the fixed-point value after converting to the _scaling factor_
+    /// provided, rounded according to the [`Rounding`] mode
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use embedded_time::{duration::*, fraction::Fraction, Rounding};
+    /// #
+    /// assert_eq!(
+    ///     Microseconds(100_u32).into_ticks_rounded(Fraction::new(1, 32_768), Rounding::Ceil),
+    ///     Ok(4_u32)
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// [`ConversionError::Overflow`] : The result doesn't fit in the destination type.
+    ///
+    /// [`ConversionError::DivByZero`] : The _scaling factor_ provided is `0`.
+    #[doc(hidden)]
+    fn into_ticks_rounded<T>(
+        self,
+        fraction: Fraction,
+        rounding: Rounding,
+    ) -> Result<T, ConversionError>
+    where
+        T: TimeInt + TryFrom<Self::T>,
+    {
+        let ticks = scale_ticks(
+            self.integer().into_u128(),
+            Self::SCALING_FACTOR,
+            fraction,
+            rounding,
+        )?;
+        T::try_from_u128(ticks).ok_or(ConversionError::Overflow)
+    }
+
+    /// Converts from another fixed-point value, rounding according to the [`Rounding`] mode
+    ///
+    /// The conversion is performed in a single step, so the result is the correctly rounded
+    /// value (the plain conversions truncate).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use embedded_time::{duration::*, rate::*, Rounding};
+    /// #
+    /// assert_eq!(
+    ///     Milliseconds::<u32>::try_from_rounded(Microseconds(1_001_u32), Rounding::Ceil),
+    ///     Ok(Milliseconds(2_u32))
+    /// );
+    /// assert_eq!(
+    ///     Kilohertz::<u32>::try_from_rounded(Hertz(1_500_u32), Rounding::NearestEven),
+    ///     Ok(Kilohertz(2_u32))
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// [`ConversionError::Overflow`] : The result doesn't fit in the type.
+    ///
+    /// ```rust
+    /// # use embedded_time::{duration::*, ConversionError, Rounding};
+    /// #
+    /// assert_eq!(
+    ///     Milliseconds::<u32>::try_from_rounded(Seconds(u32::MAX), Rounding::Floor),
+    ///     Err(ConversionError::Overflow)
+    /// );
+    /// ```
+    fn try_from_rounded<Source>(source: Source, rounding: Rounding) -> Result<Self, ConversionError>
+    where
+        Source: FixedPoint,
+        Self::T: TryFrom<Source::T>,
+    {
+        Ok(Self::new(
+            source.into_ticks_rounded(Self::SCALING_FACTOR, rounding)?,
+        ))
     }
 
     /// Panicky addition
@@ -196,6 +271,83 @@ pub trait FixedPoint: Sized + Copy {
         Some(Self::new((self.integer()).checked_div(rhs)?))
     }
 
+    /// Saturating addition of any fixed-point value convertible into this type
+    ///
+    /// Returns the maximum value instead of overflowing.
+    ///
+    /// ```rust
+    /// # use embedded_time::{duration::*, rate::*};
+    /// #
+    /// assert_eq!(Milliseconds(1_u32).saturating_add(Seconds(1_u32)), Milliseconds(1_001_u32));
+    /// assert_eq!(Milliseconds(1_u32).saturating_add(Seconds(u32::MAX)), Milliseconds(u32::MAX));
+    /// assert_eq!(Hertz(u32::MAX).saturating_add(Hertz(1_u32)), Hertz(u32::MAX));
+    /// ```
+    fn saturating_add<Rhs: FixedPoint>(self, rhs: Rhs) -> Self
+    where
+        Self: TryFrom<Rhs>,
+    {
+        Self::try_from(rhs)
+            .ok()
+            .and_then(|rhs| self.integer().checked_add(&rhs.integer()))
+            .map_or_else(|| Self::new(Self::max_value()), Self::new)
+    }
+
+    /// Saturating subtraction of any fixed-point value convertible into this type
+    ///
+    /// Returns `0` instead of underflowing.
+    ///
+    /// ```rust
+    /// # use embedded_time::duration::*;
+    /// #
+    /// assert_eq!(Milliseconds(2_001_u32).saturating_sub(Seconds(1_u32)), Milliseconds(1_001_u32));
+    /// assert_eq!(Milliseconds(1_u32).saturating_sub(Seconds(1_u32)), Milliseconds(0_u32));
+    /// ```
+    fn saturating_sub<Rhs: FixedPoint>(self, rhs: Rhs) -> Self
+    where
+        Self: TryFrom<Rhs>,
+    {
+        Self::try_from(rhs)
+            .ok()
+            .and_then(|rhs| self.integer().checked_sub(&rhs.integer()))
+            .map_or_else(|| Self::new(Self::min_value()), Self::new)
+    }
+
+    /// Saturating multiplication
+    ///
+    /// Returns the maximum value instead of overflowing.
+    ///
+    /// ```rust
+    /// # use embedded_time::duration::*;
+    /// #
+    /// assert_eq!(Seconds(2_u32).saturating_mul(3), Seconds(6_u32));
+    /// assert_eq!(Seconds(2_u32).saturating_mul(u32::MAX), Seconds(u32::MAX));
+    /// ```
+    fn saturating_mul(self, rhs: Self::T) -> Self {
+        Self::new(
+            self.integer()
+                .checked_mul(&rhs)
+                .unwrap_or_else(Self::max_value),
+        )
+    }
+
+    /// Converts from any fixed-point value, returning the maximum value if it doesn't fit
+    ///
+    /// The result is the same as that of `TryFrom` when the conversion succeeds.
+    ///
+    /// ```rust
+    /// # use embedded_time::duration::*;
+    /// #
+    /// assert_eq!(Milliseconds::<u32>::saturating_from(Seconds(5_u64)), Milliseconds(5_000_u32));
+    /// assert_eq!(Milliseconds::<u32>::saturating_from(Seconds(u64::MAX)), Milliseconds(u32::MAX));
+    /// ```
+    fn saturating_from<Source: FixedPoint>(source: Source) -> Self
+    where
+        Self::T: TryFrom<Source::T>,
+    {
+        Self::from_ticks(source.integer(), Source::SCALING_FACTOR)
+            .unwrap_or_else(|_| Self::new(Self::max_value()))
+    }
+
     /// Panicky remainder
     #[doc(hidden)]
     fn rem<Rhs: FixedPoint>(self, rhs: Rhs) -> Self
@@ -225,6 +377,287 @@ pub trait FixedPoint: Sized + Copy {
     }
 }
 
+/// Returns `ticks` of `scaling_factor` converted to ticks of `fraction`, rounded according to the
+/// [`Rounding`] mode
+///
+/// The conversion is exact: it is performed in a single step, in a [`u64`] when the values allow
+/// (eg. [`u32`] _integers_ and small _scaling factors_) and in a [`u128`] otherwise. The conversion
+/// factor is only reduced if the product overflows.
+///
+/// Fails with [`ConversionError::DivByZero`] if a _scaling factor_ is `0` and with
+/// [`ConversionError::Overflow`] if the reduced product still overflows a [`u128`].
+fn scale_ticks(
+    ticks: u128,
+    scaling_factor: Fraction,
+    fraction: Fraction,
+    rounding: Rounding,
+) -> Result<u128, ConversionError> {
+    let mut numerator =
+        u128::from(*scaling_factor.numerator()) * u128::from(*fraction.denominator());
+    let mut denominator =
+        u128::from(*scaling_factor.denominator()) * u128::from(*fraction.numerator());
+    if denominator == 0 {
+        return Err(ConversionError::DivByZero);
+    }
+
+    let product = match ticks.checked_mul(numerator) {
+        Some(product) => product,
+        None => {
+            let gcd = const_gcd(numerator, denominator);
+            numerator /= gcd;
+            denominator /= gcd;
+            ticks
+                .checked_mul(numerator)
+                .ok_or(ConversionError::Overflow)?
+        }
+    };
+
+    match (u64::try_from(product), u64::try_from(denominator)) {
+        (Ok(product), Ok(denominator)) => rounding.div(product, denominator).map(u128::from),
+        _ => rounding.div(product, denominator),
+    }
+    .ok_or(ConversionError::Overflow)
+}
+
+/// Returns the reciprocal of `integer` ticks of `scaling_factor` as (truncated) ticks of `fraction`
+///
+/// With a _scaling factor_ of `a / b` and a `fraction` of `c / d`, the result is
+/// `b × d / (a × c × integer)`. `b × d / (a × c)` is computed exactly by [`scale_ticks()`] and then
+/// divided by `integer`, which truncates the same as a single division.
+///
+/// Fails with [`ConversionError::DivByZero`] if the _integer_ or a _scaling factor_ is `0`.
+pub(crate) fn reciprocal_ticks(
+    integer: u128,
+    scaling_factor: Fraction,
+    fraction: Fraction,
+) -> Result<u128, ConversionError> {
+    let reciprocal = Fraction::new(*scaling_factor.denominator(), *scaling_factor.numerator());
+    scale_ticks(1, reciprocal, fraction, Rounding::Floor)?
+        .checked_div(integer)
+        .ok_or(ConversionError::DivByZero)
+}
+
+/// Returns the converted ticks in the destination integer type
+///
+/// Fails with [`ConversionError::Overflow`] if the ticks fit in neither integer type and with
+/// [`ConversionError::ConversionFailure`] if they only fit in the (wider) source type.
+fn narrow<SourceInt: TimeInt, DestInt: TimeInt>(ticks: u128) -> Result<DestInt, ConversionError> {
+    match DestInt::try_from_u128(ticks) {
+        Some(ticks) => Ok(ticks),
+        None if SourceInt::try_from_u128(ticks).is_some() => {
+            Err(ConversionError::ConversionFailure)
+        }
+        None => Err(ConversionError::Overflow),
+    }
+}
+
+/// Converts `source` into `Dest`, failing with [`ConversionError::PrecisionLoss`] unless the
+/// conversion is exact
+pub(crate) fn try_from_exact<Dest, Source>(source: Source) -> Result<Dest, ConversionError>
+where
+    Dest: FixedPoint + TryFrom<Source>,
+    Source: FixedPoint,
+    ConversionError: From<<Dest as TryFrom<Source>>::Error>,
+{
+    let dest = Dest::try_from(source)?;
+    if cmp_values(&dest, &source) == Some(cmp::Ordering::Equal) {
+        Ok(dest)
+    } else {
+        Err(ConversionError::PrecisionLoss)
+    }
+}
+
+/// Compares the values of two fixed-point values with any _scaling factors_ exactly
+///
+/// Returns [`None`] if either _scaling factor_ has a denominator of `0`.
+#[doc(hidden)]
+pub fn cmp_values<Lhs: FixedPoint, Rhs: FixedPoint>(lhs: &Lhs, rhs: &Rhs) -> Option<cmp::Ordering> {
+    // lhs = a / b and rhs = c / d, where neither product can overflow
+    let mut a = lhs.integer().into_u128() * u128::from(*Lhs::SCALING_FACTOR.numerator());
+    let mut b = u128::from(*Lhs::SCALING_FACTOR.denominator());
+    let mut c = rhs.integer().into_u128() * u128::from(*Rhs::SCALING_FACTOR.numerator());
+    let mut d = u128::from(*Rhs::SCALING_FACTOR.denominator());
+    if b == 0 || d == 0 {
+        return None;
+    }
+
+    // compare the continued fraction expansions term by term
+    loop {
+        let (lhs_quotient, rhs_quotient) = (a / b, c / d);
+        if lhs_quotient != rhs_quotient {
+            return Some(lhs_quotient.cmp(&rhs_quotient));
+        }
+
+        let (lhs_remainder, rhs_remainder) = (a % b, c % d);
+        match (lhs_remainder, rhs_remainder) {
+            (0, 0) => return Some(cmp::Ordering::Equal),
+            (0, _) => return Some(cmp::Ordering::Less),
+            (_, 0) => return Some(cmp::Ordering::Greater),
+            // lhs_remainder / b < rhs_remainder / d ⇔ d / rhs_remainder < b / lhs_remainder
+            _ => (a, b, c, d) = (d, rhs_remainder, b, lhs_remainder),
+        }
+    }
+}
+
+/// Converts `ticks` of `scaling_factor` into (truncated) ticks of `fraction`
+///
+/// Usable in `const` contexts. Like [`scale_ticks()`], the conversion factor is reduced if the
+/// product overflows. Returns [`None`] if `fraction` is `0` or the reduced product still overflows
+/// a `u128`.
+#[doc(hidden)]
+pub const fn const_convert_ticks(
+    ticks: u64,
+    scaling_factor: Fraction,
+    fraction: Fraction,
+) -> Option<u128> {
+    let mut numerator = *scaling_factor.numerator() as u128 * *fraction.denominator() as u128;
+    let mut denominator = *scaling_factor.denominator() as u128 * *fraction.numerator() as u128;
+    if denominator == 0 {
+        return None;
+    }
+
+    let product = match (ticks as u128).checked_mul(numerator) {
+        Some(product) => product,
+        None => {
+            let gcd = const_gcd(numerator, denominator);
+            numerator /= gcd;
+            denominator /= gcd;
+            match (ticks as u128).checked_mul(numerator) {
+                Some(product) => product,
+                None => return None,
+            }
+        }
+    };
+
+    Some(product / denominator)
+}
+
+/// A `ticks × numerator / denominator` conversion between two _scaling factors_ with the division
+/// replaced by a multiply-shift
+///
+/// Targets without a hardware divider (eg. Cortex-M0 or RV32I) implement integer division in
+/// software, whereas a multiplication is at most a few instructions. Division by the constant
+/// `denominator` is performed as described in _Division by Invariant Integers using
+/// Multiplication_ (Granlund and Montgomery, 1994): a [`u64`] multiplied by the precomputed
+/// `magic` number, keeping the high half, then shifted.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub(crate) struct FastConversion {
+    numerator: u64,
+    denominator: u64,
+    magic: u64,
+    shift: u32,
+}
+
+impl FastConversion {
+    /// Returns the conversion from ticks of `scaling_factor` to ticks of `fraction`
+    ///
+    /// Returns [`None`] if either _scaling factor_ is `0` or the reduced conversion factor doesn't
+    /// fit in a [`u64`] numerator and denominator.
+    pub(crate) const fn new(scaling_factor: Fraction, fraction: Fraction) -> Option<Self> {
+        let numerator = *scaling_factor.numerator() as u128 * *fraction.denominator() as u128;
+        let denominator = *scaling_factor.denominator() as u128 * *fraction.numerator() as u128;
+        if numerator == 0 || denominator == 0 {
+            return None;
+        }
+
+        let gcd = const_gcd(numerator, denominator);
+        let (numerator, denominator) = (numerator / gcd, denominator / gcd);
+        if numerator > u64::MAX as u128 || denominator > u64::MAX as u128 {
+            return None;
+        }
+        let (numerator, denominator) = (numerator as u64, denominator as u64);
+
+        // shift = ⌈log2(denominator)⌉
+        let shift = u64::BITS - (denominator - 1).leading_zeros();
+        let magic = if denominator == 1 {
+            0
+        } else {
+            // ⌊2^64 × (2^shift - denominator) / denominator⌋ + 1, which always fits in a u64
+            let excess = (1_u128 << shift) - denominator as u128;
+            ((excess << 64) / denominator as u128 + 1) as u64
+        };
+
+        Some(Self {
+            numerator,
+            denominator,
+            magic,
+            shift,
+        })
+    }
+
+    /// Returns `⌊ticks × numerator / denominator⌋` or [`None`] if `ticks × numerator` overflows
+    pub(crate) fn convert(&self, ticks: u64) -> Option<u64> {
+        let ticks = ticks.checked_mul(self.numerator)?;
+        if self.denominator == 1 {
+            return Some(ticks);
+        }
+
+        let high = ((u128::from(self.magic) * u128::from(ticks)) >> 64) as u64;
+        Some((high + ((ticks - high) >> 1)) >> (self.shift - 1))
+    }
+}
+
+/// Returns the greatest common divisor, usable in `const` contexts
+const fn const_gcd(mut a: u128, mut b: u128) -> u128 {
+    while b != 0 {
+        let remainder = a % b;
+        a = b;
+        b = remainder;
+    }
+    a
+}
+
+/// The [`FastConversion`] from `Source` to `Dest`, evaluated at compile time
+pub(crate) struct Conversion<Source, Dest>(PhantomData<(Source, Dest)>);
+
+impl<Source: FixedPoint, Dest: FixedPoint> Conversion<Source, Dest> {
+    /// [`None`] if the conversion factor doesn't fit a [`FastConversion`]
+    pub(crate) const FAST: Option<FastConversion> =
+        FastConversion::new(Source::SCALING_FACTOR, Dest::SCALING_FACTOR);
+}
+
+/// Implements the `const` conversions of a fixed-point type with a concrete integer type
+#[doc(hidden)]
+#[macro_export]
+macro_rules! impl_const_conversions {
+    ($int:ident) => {
+        /// Returns the _integer_ after converting to the _scaling factor_ provided, usable in
+        /// `const` contexts
+        ///
+        /// The result is truncated. Returns [`None`] if the result doesn't fit in the integer
+        /// type or the _scaling factor_ provided is `0`.
+        pub const fn const_checked_into_ticks(
+            self,
+            fraction: $crate::fraction::Fraction,
+        ) -> Option<$int> {
+            match $crate::fixed_point::const_convert_ticks(
+                self.0 as u64,
+                <Self as $crate::fixed_point::FixedPoint>::SCALING_FACTOR,
+                fraction,
+            ) {
+                Some(ticks) if ticks <= $int::MAX as u128 => Some(ticks as $int),
+                _ => None,
+            }
+        }
+
+        /// Returns the _integer_ after converting to the _scaling factor_ provided, usable in
+        /// `const` contexts
+        ///
+        /// The result is truncated.
+        ///
+        /// # Panics
+        ///
+        /// If the result doesn't fit in the integer type or the _scaling factor_ provided is `0`.
+        /// In a `const` context, this is a compile-time error.
+        pub const fn const_into_ticks(self, fraction: $crate::fraction::Fraction) -> $int {
+            match self.const_checked_into_ticks(fraction) {
+                Some(ticks) => ticks,
+                None => ::core::panic!("Conversion failed"),
+            }
+        }
+    };
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -242,4 +675,65 @@ mod tests {
             Ok(Seconds(200_000_u64))
         );
     }
+
+    #[test]
+    fn fast_conversion() {
+        let denominators = [
+            1_u64,
+            2,
+            3,
+            7,
+            10,
+            1_000,
+            32_768,
+            1_000_000_007,
+            12_288_000,
+            u32::MAX as u64,
+            u64::MAX / 3,
+            u64::MAX - 1,
+            u64::MAX,
+        ];
+        let ticks = [
+            0_u64,
+            1,
+            2,
+            999,
+            1_000,
+            32_767,
+            u32::MAX as u64,
+            u64::MAX / 7,
+            u64::MAX,
+        ];
+
+        for &denominator in &denominators {
+            let conversion =
+                FastConversion::new(Fraction::new(1, denominator), Fraction::new(1, 1)).unwrap();
+            for &ticks in &ticks {
+                for ticks in ticks.saturating_sub(1)..=ticks.saturating_add(1) {
+                    assert_eq!(
+                        conversion.convert(ticks),
+                        Some(ticks / denominator),
+                        "{} / {}",
+                        ticks,
+                        denominator
+                    );
+                }
+            }
+        }
+
+        // reduced to 3 / 2
+        let conversion =
+            FastConversion::new(Fraction::new(3, 1_000), Fraction::new(2, 1_000)).unwrap();
+        assert_eq!(conversion.convert(5), Some(7));
+        assert_eq!(conversion.convert(u64::MAX), None);
+
+        assert_eq!(
+            FastConversion::new(Fraction::new(1, u64::MAX), Fraction::new(u64::MAX, 1)),
+            None
+        );
+        assert_eq!(
+            FastConversion::new(Fraction::new(1, 1), Fraction::new(0, 1)),
+            None
+        );
+    }
 }
@@ -0,0 +1,120 @@
+//! Correctly rounded conversions between fixed-point values and floating-point numbers
+
+use crate::{fraction::Fraction, ConversionError};
+
+/// Returns `integer × scaling_factor` rounded to the nearest `f32` (ties to even)
+#[cfg(feature = "f32")]
+pub(crate) fn to_f32(integer: u64, scaling_factor: &Fraction) -> f32 {
+    let (numerator, denominator) = ratio(integer, scaling_factor);
+    match scaled_quotient(numerator, denominator) {
+        // the int-to-float cast rounds to nearest and multiplying by a power of 2 is exact
+        Some((quotient, shift)) => quotient as f32 * f32::from_bits((127 - shift) << 23),
+        None if numerator == 0 => 0.0,
+        None => f32::INFINITY,
+    }
+}
+
+/// Returns `integer × scaling_factor` rounded to the nearest `f64` (ties to even)
+#[cfg(feature = "f64")]
+pub(crate) fn to_f64(integer: u64, scaling_factor: &Fraction) -> f64 {
+    let (numerator, denominator) = ratio(integer, scaling_factor);
+    match scaled_quotient(numerator, denominator) {
+        // the int-to-float cast rounds to nearest and multiplying by a power of 2 is exact
+        Some((quotient, shift)) => quotient as f64 * f64::from_bits(u64::from(1_023 - shift) << 52),
+        None if numerator == 0 => 0.0,
+        None => f64::INFINITY,
+    }
+}
+
+/// Returns `integer × scaling_factor` as a numerator and denominator
+fn ratio(integer: u64, scaling_factor: &Fraction) -> (u128, u128) {
+    (
+        u128::from(integer) * u128::from(*scaling_factor.numerator()),
+        u128::from(*scaling_factor.denominator()),
+    )
+}
+
+/// Returns `numerator × 2^shift / denominator` with enough bits to be rounded to any float, and
+/// `shift`
+///
+/// The lowest bit of the quotient is set if the division is inexact (a _sticky_ bit) so that
+/// the value is still rounded correctly. Returns [`None`] if either value is `0`.
+fn scaled_quotient(numerator: u128, denominator: u128) -> Option<(u128, u32)> {
+    if numerator == 0 || denominator == 0 {
+        return None;
+    }
+
+    // the scaled numerator is at least 2^126 (or already uses all 128 bits if it can't be shifted)
+    // and the denominator is at most 2^64, leaving at least 62 significant bits for the quotient
+    let shift = numerator.leading_zeros().saturating_sub(1);
+    let numerator = numerator << shift;
+    let quotient = numerator / denominator;
+    let remainder = numerator % denominator;
+
+    // the quotient is wider than any float mantissa, so the sticky bit never changes its magnitude
+    Some((quotient | u128::from(remainder != 0), shift))
+}
+
+/// Returns `value / scaling_factor` rounded to the nearest integer (ties to even)
+///
+/// # Errors
+///
+/// - [`ConversionError::ConversionFailure`] : `value` is negative or NaN
+/// - [`ConversionError::Overflow`] : `value` is infinite or the result doesn't fit in a `u128`
+/// - [`ConversionError::DivByZero`] : the _scaling factor_ is `0`
+pub(crate) fn to_ticks(value: f64, scaling_factor: &Fraction) -> Result<u128, ConversionError> {
+    const MANTISSA_BITS: u32 = 52;
+    const EXPONENT_BIAS: i32 = 1_023;
+
+    let numerator = u128::from(*scaling_factor.denominator());
+    let denominator = u128::from(*scaling_factor.numerator());
+
+    if value.is_nan() || value < 0.0 {
+        return Err(ConversionError::ConversionFailure);
+    } else if value.is_infinite() {
+        return Err(ConversionError::Overflow);
+    } else if denominator == 0 {
+        return Err(ConversionError::DivByZero);
+    }
+
+    // value = mantissa × 2^exponent
+    let bits = value.to_bits();
+    let biased_exponent = ((bits >> MANTISSA_BITS) & 0x7ff) as i32;
+    let mantissa = bits & ((1 << MANTISSA_BITS) - 1);
+    let (mantissa, exponent) = if biased_exponent == 0 {
+        // subnormal
+        (mantissa, 1 - EXPONENT_BIAS - MANTISSA_BITS as i32)
+    } else {
+        (
+            mantissa | (1 << MANTISSA_BITS),
+            biased_exponent - EXPONENT_BIAS - MANTISSA_BITS as i32,
+        )
+    };
+
+    let scaled = u128::from(mantissa)
+        .checked_mul(numerator)
+        .ok_or(ConversionError::Overflow)?;
+    let (numerator, denominator) = if exponent >= 0 {
+        if scaled != 0 && scaled.leading_zeros() <= exponent as u32 {
+            return Err(ConversionError::Overflow);
+        }
+        (scaled << exponent, denominator)
+    } else {
+        match denominator.checked_shl((-exponent) as u32) {
+            // keep room for doubling the remainder below
+            Some(shifted) if shifted.leading_zeros() > 0 && shifted >> -exponent == denominator => {
+                (scaled, shifted)
+            }
+            // the divisor is larger than 2^127, more than twice the largest possible `scaled`
+            _ => return Ok(0),
+        }
+    };
+
+    let quotient = numerator / denominator;
+    let remainder = numerator % denominator;
+    if remainder * 2 > denominator || (remainder * 2 == denominator && quotient & 1 == 1) {
+        quotient.checked_add(1).ok_or(ConversionError::Overflow)
+    } else {
+        Ok(quotient)
+    }
+}
@@ -1,36 +1,41 @@
 //! Fractional/Rational values
-use crate::ConversionError;
-use core::ops;
-use num::{rational::Ratio, CheckedDiv, CheckedMul, Zero};
+use crate::{decimal, time_int::TimeInt, ConversionError};
+use core::{cmp::Ordering, fmt, ops, str::FromStr};
+use num::{rational::Ratio, CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, Zero};
 
 /// A fractional value
 ///
 /// Used primarily to define the _scaling factor_ for the [`Duration`], [`Rate`], [`Instant`] and
 /// [`Clock`] traits and types.
 ///
+/// The numerator and denominator are [`u64`]s so that _scaling factors_ such as that of a
+/// picosecond (`1/1_000_000_000_000`) or of a multi-gigahertz clock can be represented. Scaling
+/// an integer by a `Fraction` uses [`u128`] intermediates and can only overflow if the result
+/// doesn't fit.
+///
 /// [`Duration`]: duration/trait.Duration.html
 /// [`Rate`]: rate/trait.Rate.html
 /// [`Clock`]: clock/trait.Clock.html
 /// [`Instant`]: instant/struct.Instant.html
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
-pub struct Fraction(Ratio<u32>);
+pub struct Fraction(Ratio<u64>);
 
 impl Fraction {
     /// Construct a new `Fraction`.
     ///
     /// A reduction is **not** performed. Also there is no check for a denominator of `0`. If these
     /// features are needed, use [`Fraction::new_reduce()`]
-    pub const fn new(numerator: u32, denominator: u32) -> Self {
+    pub const fn new(numerator: u64, denominator: u64) -> Self {
         Self(Ratio::new_raw(numerator, denominator))
     }
 
     /// Return the numerator of the fraction
-    pub const fn numerator(&self) -> &u32 {
+    pub const fn numerator(&self) -> &u64 {
         self.0.numer()
     }
 
     /// Return the denominator of the fraction
-    pub const fn denominator(&self) -> &u32 {
+    pub const fn denominator(&self) -> &u64 {
         self.0.denom()
     }
 }
@@ -44,7 +49,7 @@ impl Fraction {
     ///
     /// [`ConversionError::DivByZero`] : A `0` denominator was detected
     // TODO: add example
-    pub fn new_reduce(numerator: u32, denominator: u32) -> Result<Self, ConversionError> {
+    pub fn new_reduce(numerator: u64, denominator: u64) -> Result<Self, ConversionError> {
         if !denominator.is_zero() {
             Ok(Self(Ratio::new(numerator, denominator)))
         } else {
@@ -53,14 +58,14 @@ impl Fraction {
     }
 
     /// Returns the value truncated to an integer
-    pub fn to_integer(&self) -> u32 {
+    pub fn to_integer(&self) -> u64 {
         self.0.to_integer()
     }
 
     /// Constructs a `Fraction` from an integer.
     ///
     /// Equivalent to `Fraction::new(value,1)`.
-    pub fn from_integer(value: u32) -> Self {
+    pub fn from_integer(value: u64) -> Self {
         Self(Ratio::from_integer(value))
     }
 
@@ -81,7 +86,11 @@ impl Fraction {
     /// assert_eq!(Fraction::new(1000, 1).checked_mul(&Fraction::new(5,5)),
     ///     Some(Fraction::new(5_000, 5)));
     ///
-    /// assert_eq!(Fraction::new(u32::MAX, 1).checked_mul(&Fraction::new(2,1)),
+    /// // the operands are reduced first, so only a reduced result that doesn't fit overflows
+    /// assert_eq!(Fraction::new(u64::MAX, 3).checked_mul(&Fraction::new(3, 1)),
+    ///     Some(Fraction::new(u64::MAX, 1)));
+    ///
+    /// assert_eq!(Fraction::new(u64::MAX, 1).checked_mul(&Fraction::new(2,1)),
     ///     None);
     /// ```
     pub fn checked_mul(&self, v: &Self) -> Option<Self> {
@@ -100,51 +109,369 @@ impl Fraction {
     /// assert_eq!(Fraction::new(1000, 1).checked_div(&Fraction::new(10, 1000)),
     ///     Some(Fraction::new(1_000_000, 10)));
     ///
-    /// assert_eq!(Fraction::new(1, u32::MAX).checked_div(&Fraction::new(2,1)),
+    /// assert_eq!(Fraction::new(1, u64::MAX).checked_div(&Fraction::new(2,1)),
     ///     None);
     /// ```
     pub fn checked_div(&self, v: &Self) -> Option<Self> {
         self.0.checked_div(&v.0).map(Self)
     }
-}
 
-impl ops::Mul<Fraction> for u32 {
-    type Output = Self;
+    /// Checked `Fraction` + `Fraction` = `Fraction`
+    ///
+    /// Returns [`None`] for any errors
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use embedded_time::fraction::Fraction;
+    /// #
+    /// assert_eq!(Fraction::new(1, 3).checked_add(&Fraction::new(1, 6)),
+    ///     Some(Fraction::new(1, 2)));
+    ///
+    /// assert_eq!(Fraction::new(u64::MAX, 1).checked_add(&Fraction::new(1, 1)),
+    ///     None);
+    /// ```
+    pub fn checked_add(&self, v: &Self) -> Option<Self> {
+        self.0.checked_add(&v.0).map(Self)
+    }
 
-    /// Panicky u32 × `Fraction` = u32
-    fn mul(self, rhs: Fraction) -> Self::Output {
-        (rhs.0 * self).to_integer()
+    /// Checked `Fraction` - `Fraction` = `Fraction`
+    ///
+    /// Returns [`None`] for any errors, including a negative result
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use embedded_time::fraction::Fraction;
+    /// #
+    /// assert_eq!(Fraction::new(1, 2).checked_sub(&Fraction::new(1, 6)),
+    ///     Some(Fraction::new(1, 3)));
+    ///
+    /// assert_eq!(Fraction::new(1, 6).checked_sub(&Fraction::new(1, 2)),
+    ///     None);
+    /// ```
+    pub fn checked_sub(&self, v: &Self) -> Option<Self> {
+        self.0.checked_sub(&v.0).map(Self)
     }
-}
 
-impl ops::Div<Fraction> for u32 {
-    type Output = Self;
+    /// Returns the largest integer less than or equal to the value
+    ///
+    /// # Panics
+    ///
+    /// If the denominator is `0`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use embedded_time::fraction::Fraction;
+    /// #
+    /// assert_eq!(Fraction::new(5, 2).floor(), 2);
+    /// assert_eq!(Fraction::new(4, 2).floor(), 2);
+    /// ```
+    pub fn floor(&self) -> u64 {
+        self.to_integer()
+    }
 
-    /// Panicky u32 / `Fraction` = u32
-    #[allow(clippy::suspicious_arithmetic_impl)]
-    fn div(self, rhs: Fraction) -> Self::Output {
-        (rhs.0.recip() * self).to_integer()
+    /// Returns the smallest integer greater than or equal to the value
+    ///
+    /// # Panics
+    ///
+    /// If the denominator is `0`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use embedded_time::fraction::Fraction;
+    /// #
+    /// assert_eq!(Fraction::new(5, 2).ceil(), 3);
+    /// assert_eq!(Fraction::new(4, 2).ceil(), 2);
+    /// assert_eq!(Fraction::new(u64::MAX, 2).ceil(), u64::MAX / 2 + 1);
+    /// ```
+    pub fn ceil(&self) -> u64 {
+        let (numerator, denominator) = (*self.numerator(), *self.denominator());
+        numerator / denominator + u64::from(numerator % denominator != 0)
     }
-}
 
-impl ops::Mul<Fraction> for u64 {
-    type Output = Self;
+    /// Returns the nearest integer to the value, rounding half-way cases away from zero
+    ///
+    /// # Panics
+    ///
+    /// If the denominator is `0`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use embedded_time::fraction::Fraction;
+    /// #
+    /// assert_eq!(Fraction::new(5, 2).round(), 3);
+    /// assert_eq!(Fraction::new(7, 3).round(), 2);
+    /// assert_eq!(Fraction::new(u64::MAX, u64::MAX - 1).round(), 1);
+    /// ```
+    pub fn round(&self) -> u64 {
+        let (numerator, denominator) = (*self.numerator(), *self.denominator());
+        let remainder = numerator % denominator;
+        numerator / denominator + u64::from(remainder >= denominator - remainder)
+    }
+
+    /// Returns a [`DisplayDecimal`] that formats the value as a decimal number with `precision`
+    /// fractional digits
+    ///
+    /// The value is truncated, not rounded. A fraction with a denominator of `0` is written as a
+    /// fraction instead (eg. `1/0`).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use embedded_time::fraction::Fraction;
+    /// #
+    /// assert_eq!(format!("{}", Fraction::new(1, 32_768).to_decimal(9)), "0.000030517");
+    /// assert_eq!(format!("{}", Fraction::new(3, 2).to_decimal(3)), "1.500");
+    /// assert_eq!(format!("{}", Fraction::new(3, 2).to_decimal(0)), "1");
+    /// assert_eq!(format!("{}", Fraction::new(1, 0).to_decimal(3)), "1/0");
+    /// ```
+    pub fn to_decimal(&self, precision: usize) -> DisplayDecimal {
+        DisplayDecimal {
+            fraction: *self,
+            precision,
+        }
+    }
+
+    /// Returns the closest `Fraction` to `numerator / denominator` whose numerator and denominator
+    /// both fit in a [`u32`], along with an upper bound of its error
+    ///
+    /// The approximation is found using continued fractions. This is useful to choose the
+    /// `u32` _scaling factor_ of a [`Scaled`](crate::duration::Scaled) type or of a
+    /// [`fugit`](https://docs.rs/fugit) type for an odd crystal or PLL frequency.
+    ///
+    /// # Errors
+    ///
+    /// - [`ConversionError::DivByZero`] : `denominator` is `0`
+    /// - [`ConversionError::Overflow`] : the value is greater than [`u32::MAX`]
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use embedded_time::{fraction::Fraction, ConversionError};
+    /// #
+    /// // the period of a 12.288 MHz crystal is exact
+    /// let approximation = Fraction::approximate(1, 12_288_000)?;
+    /// assert_eq!(approximation.fraction(), Fraction::new(1, 12_288_000));
+    /// assert!(approximation.is_exact());
+    ///
+    /// // 6_000_000_000 / 7 doesn't fit
+    /// let approximation = Fraction::approximate(7, 6_000_000_000)?;
+    /// assert_eq!(approximation.fraction(), Fraction::new(5, 4_285_714_286));
+    /// assert!(approximation.error() < Fraction::new(1, 10_000_000_000_000_000));
+    ///
+    /// assert_eq!(Fraction::approximate(1, 0), Err(ConversionError::DivByZero));
+    /// assert_eq!(Fraction::approximate(u64::MAX, 1), Err(ConversionError::Overflow));
+    /// # Ok::<(), ConversionError>(())
+    /// ```
+    pub fn approximate(numerator: u64, denominator: u64) -> Result<Approximation, ConversionError> {
+        if denominator.is_zero() {
+            Err(ConversionError::DivByZero)
+        } else if numerator / denominator > u32::MAX.into() {
+            Err(ConversionError::Overflow)
+        } else {
+            Ok(Approximation::of(numerator.into(), denominator.into()))
+        }
+    }
+
+    /// Returns the closest `Fraction` to `value` whose numerator and denominator both fit in a
+    /// [`u32`], along with an upper bound of its error
+    ///
+    /// See [`Fraction::approximate()`]. Values smaller than `2^-44` may lose some precision before
+    /// being approximated.
+    ///
+    /// # Errors
+    ///
+    /// - [`ConversionError::ConversionFailure`] : `value` is negative or NaN
+    /// - [`ConversionError::Overflow`] : `value` is greater than [`u32::MAX`]
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use embedded_time::{fraction::Fraction, ConversionError};
+    /// #
+    /// assert_eq!(Fraction::from_f64(0.25)?.fraction(), Fraction::new(1, 4));
+    /// assert_eq!(Fraction::from_f64(1. / 14_745_600.)?.fraction(), Fraction::new(1, 14_745_600));
+    /// assert_eq!(Fraction::from_f64(core::f64::consts::PI)?.fraction(),
+    ///     Fraction::new(2_698_940_791, 859_099_536));
+    ///
+    /// assert_eq!(Fraction::from_f64(-1.), Err(ConversionError::ConversionFailure));
+    /// assert_eq!(Fraction::from_f64(1e10), Err(ConversionError::Overflow));
+    /// # Ok::<(), ConversionError>(())
+    /// ```
+    #[cfg(feature = "f64")]
+    pub fn from_f64(value: f64) -> Result<Approximation, ConversionError> {
+        if value.is_nan() || value.is_sign_negative() && value != 0. {
+            return Err(ConversionError::ConversionFailure);
+        } else if value > f64::from(u32::MAX) {
+            return Err(ConversionError::Overflow);
+        }
+
+        // value = mantissa × 2^exponent exactly
+        let bits = value.to_bits();
+        let biased_exponent = ((bits >> 52) & 0x7ff) as i32;
+        let (mut mantissa, mut exponent) = if biased_exponent == 0 {
+            (bits & 0xf_ffff_ffff_ffff, -1074)
+        } else {
+            (
+                (bits & 0xf_ffff_ffff_ffff) | (1 << 52),
+                biased_exponent - 1075,
+            )
+        };
 
-    /// Panicky u64 × `Fraction` = u64
-    fn mul(self, rhs: Fraction) -> Self::Output {
-        (Ratio::new_raw((*rhs.numerator()).into(), (*rhs.denominator()).into()) * self).to_integer()
+        if mantissa == 0 {
+            return Ok(Approximation::of(0, 1));
+        }
+        let trailing_zeros = mantissa.trailing_zeros();
+        mantissa >>= trailing_zeros;
+        exponent += trailing_zeros as i32;
+
+        if exponent >= 0 {
+            // an integer no greater than `u32::MAX`
+            Ok(Approximation::of(u128::from(mantissa) << exponent, 1))
+        } else {
+            // keep the denominator within the range supported by `Approximation::of()`
+            let shift = (-exponent - 96).max(0) as u32;
+            let mantissa = mantissa.checked_shr(shift).unwrap_or(0);
+            Ok(Approximation::of(
+                mantissa.into(),
+                1 << (-exponent as u32 - shift),
+            ))
+        }
     }
 }
 
-impl ops::Div<Fraction> for u64 {
-    type Output = Self;
+/// The closest [`Fraction`] to a value with a [`u32`] numerator and denominator
+///
+/// Returned by [`Fraction::approximate()`] and `Fraction::from_f64()`.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Approximation {
+    fraction: Fraction,
+    error: Fraction,
+}
+
+impl Approximation {
+    /// Finds the best approximation of `numerator / denominator`
+    ///
+    /// Both `numerator` and `denominator` must be no greater than `2^96`, `denominator` must not be
+    /// `0` and the value must not be greater than [`u32::MAX`] so that none of the products below
+    /// overflow.
+    fn of(numerator: u128, denominator: u128) -> Self {
+        const MAX: u128 = u32::MAX as u128;
 
-    /// Panicky u64 / `Fraction` = u64
-    #[allow(clippy::suspicious_arithmetic_impl)]
-    fn div(self, rhs: Fraction) -> Self::Output {
-        (Ratio::new_raw((*rhs.denominator()).into(), (*rhs.numerator()).into()) * self).to_integer()
+        // |p × denominator - numerator × q|
+        let error = |p: u128, q: u128| {
+            (p * denominator).max(numerator * q) - (p * denominator).min(numerator * q)
+        };
+
+        // the last two convergents
+        let (mut p0, mut q0, mut p1, mut q1) = (0_u128, 1_u128, 1_u128, 0_u128);
+        let (mut n, mut d) = (numerator, denominator);
+        let (p, q) = loop {
+            let a = n / d;
+            let p2 = a.checked_mul(p1).and_then(|p| p.checked_add(p0));
+            let q2 = a.checked_mul(q1).and_then(|q| q.checked_add(q0));
+            match (p2, q2) {
+                (Some(p2), Some(q2)) if p2 <= MAX && q2 <= MAX => {
+                    p0 = p1;
+                    q0 = q1;
+                    p1 = p2;
+                    q1 = q2;
+
+                    let remainder = n - a * d;
+                    if remainder == 0 {
+                        break (p1, q1);
+                    }
+                    n = d;
+                    d = remainder;
+                }
+                _ => {
+                    // the best semiconvergent that fits, if any, may be closer than the last
+                    // convergent
+                    let k = core::cmp::min(
+                        (MAX - p0).checked_div(p1).unwrap_or(MAX),
+                        (MAX - q0).checked_div(q1).unwrap_or(MAX),
+                    );
+                    let (p2, q2) = (k * p1 + p0, k * q1 + q0);
+                    if k > 0 && error(p2, q2) * q1 < error(p1, q1) * q2 {
+                        break (p2, q2);
+                    }
+                    break (p1, q1);
+                }
+            }
+        };
+
+        // |p/q - numerator/denominator| = |p × denominator - numerator × q| / (q × denominator)
+        let error_numerator = error(p, q);
+        let error_denominator = q * denominator;
+        let gcd = num::integer::gcd(error_numerator, error_denominator);
+        let (mut error_numerator, mut error_denominator) =
+            (error_numerator / gcd, error_denominator / gcd);
+
+        // round the error up until it fits
+        while error_denominator > u64::MAX.into() {
+            error_numerator = (error_numerator >> 1) + (error_numerator & 1);
+            error_denominator >>= 1;
+        }
+
+        Self {
+            fraction: Fraction::new(p as u64, q as u64),
+            error: Fraction::new_reduce(error_numerator as u64, error_denominator as u64)
+                .unwrap_or_default(),
+        }
+    }
+
+    /// Returns the approximating `Fraction`
+    pub fn fraction(&self) -> Fraction {
+        self.fraction
     }
+
+    /// Returns an upper bound of the absolute difference between the approximating `Fraction` and
+    /// the approximated value
+    pub fn error(&self) -> Fraction {
+        self.error
+    }
+
+    /// Returns `true` if the approximating `Fraction` is equal to the approximated value
+    pub fn is_exact(&self) -> bool {
+        self.error.numerator().is_zero()
+    }
+}
+
+macro_rules! impl_int_ops {
+    ($int:ident) => {
+        impl ops::Mul<Fraction> for $int {
+            type Output = Self;
+
+            #[doc = concat!("Panicky ", stringify!($int), " × `Fraction` = ", stringify!($int))]
+            fn mul(self, rhs: Fraction) -> Self::Output {
+                if let Some(product) = self.checked_mul_fraction(&rhs) {
+                    product
+                } else {
+                    panic!("Mul failed")
+                }
+            }
+        }
+
+        impl ops::Div<Fraction> for $int {
+            type Output = Self;
+
+            #[doc = concat!("Panicky ", stringify!($int), " / `Fraction` = ", stringify!($int))]
+            fn div(self, rhs: Fraction) -> Self::Output {
+                if let Some(quotient) = self.checked_div_fraction(&rhs) {
+                    quotient
+                } else {
+                    panic!("Div failed")
+                }
+            }
+        }
+    };
 }
+impl_int_ops![u32];
+impl_int_ops![u64];
 
 impl ops::Mul for Fraction {
     type Output = Self;
@@ -174,11 +501,151 @@ impl ops::Div for Fraction {
     }
 }
 
+impl ops::Add for Fraction {
+    type Output = Self;
+
+    /// Panicky `Fraction` + `Fraction` = `Fraction`
+    ///
+    /// # Panics
+    ///
+    /// The same reason the integer operation would panic. Namely, if the
+    /// result overflows the type.
+    fn add(self, rhs: Self) -> Self::Output {
+        Self(self.0 + rhs.0)
+    }
+}
+
+impl ops::Sub for Fraction {
+    type Output = Self;
+
+    /// Panicky `Fraction` - `Fraction` = `Fraction`
+    ///
+    /// # Panics
+    ///
+    /// The same reason the integer operation would panic. Namely, if the
+    /// result is negative.
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self(self.0 - rhs.0)
+    }
+}
+
+impl PartialEq<u64> for Fraction {
+    fn eq(&self, other: &u64) -> bool {
+        self.0 == Ratio::from_integer(*other)
+    }
+}
+
+impl PartialOrd<u64> for Fraction {
+    fn partial_cmp(&self, other: &u64) -> Option<Ordering> {
+        self.0.partial_cmp(&Ratio::from_integer(*other))
+    }
+}
+
 impl Default for Fraction {
     fn default() -> Self {
         Self::new(1, 1)
     }
 }
 
+impl fmt::Display for Fraction {
+    /// Formats the fraction as `numerator/denominator` (eg. `1/32768`)
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.numerator(), self.denominator())
+    }
+}
+
+impl FromStr for Fraction {
+    type Err = ConversionError;
+
+    /// Parses a fraction such as `"1/32768"` or an integer such as `"3"`
+    ///
+    /// The fraction is not reduced.
+    ///
+    /// # Errors
+    ///
+    /// - [`ConversionError::DivByZero`] : The denominator is `0`
+    /// - [`ConversionError::ParseFailure`] : The string is not a fraction or an integer
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use embedded_time::{fraction::Fraction, ConversionError};
+    /// #
+    /// assert_eq!("1/32768".parse(), Ok(Fraction::new(1, 32_768)));
+    /// assert_eq!("5000 / 5".parse(), Ok(Fraction::new(5_000, 5)));
+    /// assert_eq!("3".parse(), Ok(Fraction::new(3, 1)));
+    ///
+    /// assert_eq!("1/0".parse::<Fraction>(), Err(ConversionError::DivByZero));
+    /// assert_eq!("1/x".parse::<Fraction>(), Err(ConversionError::ParseFailure));
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.splitn(2, '/').map(|part| part.trim().parse::<u64>());
+        let (numerator, denominator) = match (parts.next(), parts.next()) {
+            (Some(Ok(numerator)), Some(Ok(denominator))) => (numerator, denominator),
+            (Some(Ok(numerator)), None) => (numerator, 1),
+            _ => return Err(ConversionError::ParseFailure),
+        };
+
+        if denominator.is_zero() {
+            Err(ConversionError::DivByZero)
+        } else {
+            Ok(Self::new(numerator, denominator))
+        }
+    }
+}
+
+/// Formats a [`Fraction`] as a decimal number with a fixed number of fractional digits
+///
+/// Returned by [`Fraction::to_decimal()`].
+#[derive(Copy, Clone, Debug)]
+pub struct DisplayDecimal {
+    fraction: Fraction,
+    precision: usize,
+}
+
+impl fmt::Display for DisplayDecimal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let numerator = u128::from(*self.fraction.numerator());
+        let denominator = u128::from(*self.fraction.denominator());
+        if denominator == 0 {
+            return write!(f, "{}", self.fraction);
+        }
+        write!(f, "{}", numerator / denominator)?;
+        decimal::write_fraction_digits(f, numerator % denominator, denominator, self.precision)
+    }
+}
+
+#[cfg(feature = "ufmt")]
+impl ufmt::uDebug for Fraction {
+    fn fmt<W>(&self, f: &mut ufmt::Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: ufmt::uWrite + ?Sized,
+    {
+        /// Formats the inner ratio like its [`core::fmt::Debug`] implementation
+        struct Ratio<'a>(&'a Fraction);
+
+        impl ufmt::uDebug for Ratio<'_> {
+            fn fmt<W>(&self, f: &mut ufmt::Formatter<'_, W>) -> Result<(), W::Error>
+            where
+                W: ufmt::uWrite + ?Sized,
+            {
+                f.debug_struct("Ratio")?
+                    .field("numer", self.0.numerator())?
+                    .field("denom", self.0.denominator())?
+                    .finish()
+            }
+        }
+
+        f.debug_tuple("Fraction")?.field(&Ratio(self))?.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for Fraction {
+    fn format(&self, f: defmt::Formatter<'_>) {
+        defmt::write!(f, "{}/{}", self.numerator(), self.denominator())
+    }
+}
+
 #[cfg(test)]
 mod tests {}
@@ -0,0 +1,193 @@
+//! Interoperability with the [`fugit`](https://docs.rs/fugit) crate
+//!
+//! With the `fugit` feature, the duration units convert to and from [`fugit::Duration`], the
+//! frequency units to and from [`fugit::Rate`] and an [`Instant`] to and from [`fugit::Instant`].
+//!
+//! The _scaled_ units have the same representation as the `fugit` types (a compile-time
+//! `NOM / DENOM` _scaling factor_) so those conversions are lossless [`From`]s. All others are
+//! [`TryFrom`]s.
+//!
+//! ```rust
+//! use core::convert::TryFrom;
+//! use embedded_time::{duration::*, rate::*};
+//!
+//! let duration = fugit::MillisDurationU32::from_ticks(1_500);
+//! assert_eq!(Microseconds::<u32>::try_from(duration), Ok(Microseconds(1_500_000_u32)));
+//! assert_eq!(
+//!     fugit::SecsDurationU32::try_from(Milliseconds(3_000_u32)),
+//!     Ok(fugit::SecsDurationU32::from_ticks(3))
+//! );
+//!
+//! let rate = fugit::KilohertzU32::from_raw(32);
+//! assert_eq!(Hertz::<u32>::try_from(rate), Ok(Hertz(32_000_u32)));
+//! ```
+
+use crate::{
+    duration::{self, *},
+    fixed_point::FixedPoint,
+    fraction::Fraction,
+    rate::{self, *},
+    time_int::TimeInt,
+    ConversionError, Instant,
+};
+use core::{convert::TryFrom, marker::PhantomData};
+
+/// A [`Clock`](crate::Clock) reading the [`fugit::Instant`]s of a `fugit`-based timer
+///
+/// The instants are read by calling the provided function, typically a closure around a HAL timer.
+/// The _scaling factor_ of the clock is `NOM / DENOM` seconds and its integer type `T` is that of
+/// the `fugit` instants (`u32` or `u64`).
+///
+/// ```rust
+/// use core::cell::Cell;
+/// use embedded_time::{duration::*, fugit::FugitClock, Clock as _};
+///
+/// let ticks = Cell::new(0_u32);
+/// let clock = FugitClock::new(|| fugit::TimerInstantU32::<1_000>::from_ticks(ticks.get()));
+///
+/// let start = clock.try_now().unwrap();
+/// ticks.set(1_500);
+///
+/// assert_eq!(
+///     Milliseconds::<u32>::try_from(clock.try_now().unwrap() - start),
+///     Ok(Milliseconds(1_500_u32))
+/// );
+/// # use core::convert::TryFrom;
+/// ```
+#[derive(Debug)]
+pub struct FugitClock<T, F, const NOM: u32, const DENOM: u32>
+where
+    F: Fn() -> fugit::Instant<T, NOM, DENOM>,
+{
+    now: F,
+    _int: PhantomData<T>,
+}
+
+impl<T, F, const NOM: u32, const DENOM: u32> FugitClock<T, F, NOM, DENOM>
+where
+    F: Fn() -> fugit::Instant<T, NOM, DENOM>,
+{
+    /// Constructs a clock reading the instants returned by `now`
+    pub fn new(now: F) -> Self {
+        Self {
+            now,
+            _int: PhantomData,
+        }
+    }
+}
+
+macro_rules! impl_fugit {
+    ($int:ident) => {
+        impl<F, const NOM: u32, const DENOM: u32> crate::Clock for FugitClock<$int, F, NOM, DENOM>
+        where
+            F: Fn() -> fugit::Instant<$int, NOM, DENOM>,
+        {
+            type T = $int;
+            const SCALING_FACTOR: Fraction = Fraction::new(NOM as u64, DENOM as u64);
+
+            fn try_now(&self) -> Result<Instant<Self>, crate::clock::Error> {
+                Ok(Instant::new((self.now)().ticks()))
+            }
+        }
+
+        impl<const NOM: u32, const DENOM: u32> From<fugit::Duration<$int, NOM, DENOM>>
+            for duration::Scaled<$int, NOM, DENOM>
+        {
+            fn from(duration: fugit::Duration<$int, NOM, DENOM>) -> Self {
+                Self(duration.ticks())
+            }
+        }
+
+        impl<const NOM: u32, const DENOM: u32> From<duration::Scaled<$int, NOM, DENOM>>
+            for fugit::Duration<$int, NOM, DENOM>
+        {
+            fn from(duration: duration::Scaled<$int, NOM, DENOM>) -> Self {
+                Self::from_ticks(duration.0)
+            }
+        }
+
+        impl<const NOM: u32, const DENOM: u32> From<fugit::Rate<$int, NOM, DENOM>>
+            for rate::Scaled<$int, NOM, DENOM>
+        {
+            fn from(rate: fugit::Rate<$int, NOM, DENOM>) -> Self {
+                Self(rate.raw())
+            }
+        }
+
+        impl<const NOM: u32, const DENOM: u32> From<rate::Scaled<$int, NOM, DENOM>>
+            for fugit::Rate<$int, NOM, DENOM>
+        {
+            fn from(rate: rate::Scaled<$int, NOM, DENOM>) -> Self {
+                Self::from_raw(rate.0)
+            }
+        }
+
+        impl<Clock: crate::Clock, const NOM: u32, const DENOM: u32>
+            TryFrom<fugit::Instant<$int, NOM, DENOM>> for Instant<Clock>
+        where
+            Clock::T: TryFrom<$int>,
+        {
+            type Error = ConversionError;
+
+            /// The ticks since the epoch are converted to the clock's _scaling factor_
+            fn try_from(instant: fugit::Instant<$int, NOM, DENOM>) -> Result<Self, Self::Error> {
+                Ok(Self::new(
+                    duration::Scaled::<$int, NOM, DENOM>(instant.ticks())
+                        .into_ticks(Clock::SCALING_FACTOR)?,
+                ))
+            }
+        }
+
+        impl<Clock: crate::Clock, const NOM: u32, const DENOM: u32> TryFrom<Instant<Clock>>
+            for fugit::Instant<$int, NOM, DENOM>
+        where
+            $int: TryFrom<Clock::T>,
+        {
+            type Error = ConversionError;
+
+            /// The ticks since the epoch are converted to the _scaling factor_ `NOM / DENOM`
+            fn try_from(instant: Instant<Clock>) -> Result<Self, Self::Error> {
+                let ticks =
+                    duration::Scaled::<$int, NOM, DENOM>::try_from(instant.duration_since_epoch())?;
+                Ok(Self::from_ticks(ticks.0))
+            }
+        }
+
+        impl_fugit![$int, duration, Duration, from_ticks, ticks; Weeks, Days, Hours, Minutes,
+            Seconds, Milliseconds, Microseconds, Nanoseconds, Picoseconds];
+        impl_fugit![$int, rate, Rate, from_raw, raw; Mebihertz, Megahertz, Kibihertz, Kilohertz,
+            Hertz, Decihertz, Centihertz, Millihertz, Microhertz];
+    };
+
+    ($int:ident, $module:ident, $fugit:ident, $from:ident, $into:ident; $($name:ident),+) => {
+        $(
+            impl<DestInt: TimeInt, const NOM: u32, const DENOM: u32>
+                TryFrom<fugit::$fugit<$int, NOM, DENOM>> for $name<DestInt>
+            where
+                DestInt: TryFrom<$int>,
+            {
+                type Error = ConversionError;
+
+                fn try_from(value: fugit::$fugit<$int, NOM, DENOM>) -> Result<Self, Self::Error> {
+                    $module::Scaled::<$int, NOM, DENOM>(value.$into()).try_convert()
+                }
+            }
+
+            impl<SourceInt: TimeInt, const NOM: u32, const DENOM: u32> TryFrom<$name<SourceInt>>
+                for fugit::$fugit<$int, NOM, DENOM>
+            where
+                $int: TryFrom<SourceInt>,
+            {
+                type Error = ConversionError;
+
+                fn try_from(value: $name<SourceInt>) -> Result<Self, Self::Error> {
+                    Ok(Self::$from(
+                        $module::Scaled::<$int, NOM, DENOM>::try_from(value)?.0,
+                    ))
+                }
+            }
+        )+
+    };
+}
+impl_fugit![u32];
+impl_fugit![u64];
@@ -1,8 +1,9 @@
 //! An instant of time
 
 use crate::{
-    duration::{self, Duration},
+    duration::{self, Duration, Ticks},
     fixed_point::FixedPoint,
+    Rounding,
 };
 use core::{
     cmp::Ordering,
@@ -57,8 +58,8 @@ impl<Clock: crate::Clock> Instant<Clock> {
         Self { ticks }
     }
 
-    /// Returns the amount of time elapsed from another instant to this one as a
-    /// [`duration::Generic`] or [`None`] if the other instant is later than
+    /// Returns the amount of time elapsed from another instant to this one as
+    /// [`duration::Ticks`] of the clock or [`None`] if the other instant is later than
     /// this one.
     ///
     /// # Examples
@@ -80,26 +81,23 @@ impl<Clock: crate::Clock> Instant<Clock> {
     ///
     /// // Given `instant1` at 3 `Clock` ticks
     /// // Given `instant2` at 5 `Clock` ticks
-    /// let generic_duration = instant2.checked_duration_since(&instant1).unwrap();
+    /// let ticks = instant2.checked_duration_since(&instant1).unwrap();
     ///
     /// // Convert into a _named_ `Duration`
-    /// let microseconds: Microseconds<u32> = generic_duration.try_into().unwrap();
+    /// let microseconds: Microseconds<u32> = ticks.try_into().unwrap();
     ///
     /// assert_eq!(microseconds, Microseconds(2_000_u32));
     /// ```
-    pub fn checked_duration_since(&self, other: &Self) -> Option<duration::Generic<Clock::T>> {
+    pub fn checked_duration_since(&self, other: &Self) -> Option<duration::Ticks<Clock>> {
         if self >= other {
-            Some(duration::Generic::new(
-                self.ticks.wrapping_sub(&other.ticks),
-                Clock::SCALING_FACTOR,
-            ))
+            Some(duration::Ticks::new(self.ticks.wrapping_sub(&other.ticks)))
         } else {
             None
         }
     }
 
-    /// Returns the amount of time elapsed from self until that given instant
-    /// [`duration::Generic`] or [`None`] if the other instant is later than
+    /// Returns the amount of time elapsed from self until that given instant as
+    /// [`duration::Ticks`] of the clock or [`None`] if the other instant is later than
     /// this one.
     ///
     /// # Examples
@@ -121,19 +119,16 @@ impl<Clock: crate::Clock> Instant<Clock> {
     ///
     /// // Given `instant1` at 3 `Clock` ticks
     /// // Given `instant2` at 5 `Clock` ticks
-    /// let generic_duration = instant1.checked_duration_until(&instant2).unwrap();
+    /// let ticks = instant1.checked_duration_until(&instant2).unwrap();
     ///
     /// // Convert into a _named_ `Duration`
-    /// let microseconds: Microseconds<u32> = generic_duration.try_into().unwrap();
+    /// let microseconds: Microseconds<u32> = ticks.try_into().unwrap();
     ///
     /// assert_eq!(microseconds, Microseconds(2_000_u32));
     /// ```
-    pub fn checked_duration_until(&self, other: &Self) -> Option<duration::Generic<Clock::T>> {
+    pub fn checked_duration_until(&self, other: &Self) -> Option<duration::Ticks<Clock>> {
         if self <= other {
-            Some(duration::Generic::new(
-                other.ticks.wrapping_sub(&self.ticks),
-                Clock::SCALING_FACTOR,
-            ))
+            Some(duration::Ticks::new(other.ticks.wrapping_sub(&self.ticks)))
         } else {
             None
         }
@@ -177,7 +172,9 @@ impl<Clock: crate::Clock> Instant<Clock> {
         Dur: FixedPoint,
         Clock::T: TryFrom<Dur::T> + core::ops::Div<Output = Clock::T>,
     {
-        let add_ticks: Clock::T = duration.into_ticks(Clock::SCALING_FACTOR).ok()?;
+        let add_ticks: Clock::T = Ticks::<Clock>::from_fixed_point(duration)
+            .map(|ticks| ticks.integer())
+            .ok()?;
         if add_ticks <= (<Clock::T as num::Bounded>::max_value() / 2.into()) {
             Some(Self {
                 ticks: self.ticks.wrapping_add(&add_ticks),
@@ -215,7 +212,9 @@ impl<Clock: crate::Clock> Instant<Clock> {
         Dur: FixedPoint,
         Clock::T: TryFrom<Dur::T> + core::ops::Div<Output = Clock::T>,
     {
-        let sub_ticks: Clock::T = duration.into_ticks(Clock::SCALING_FACTOR).ok()?;
+        let sub_ticks: Clock::T = Ticks::<Clock>::from_fixed_point(duration)
+            .map(|ticks| ticks.integer())
+            .ok()?;
         if sub_ticks <= (<Clock::T as num::Bounded>::max_value() / 2.into()) {
             Some(Self {
                 ticks: self.ticks.wrapping_sub(&sub_ticks),
@@ -224,6 +223,173 @@ impl<Clock: crate::Clock> Instant<Clock> {
             None
         }
     }
+
+    /// This `Instant` + [`Duration`] = later (future) `Instant`, with the [`Duration`] rounded to
+    /// clock ticks according to the [`Rounding`] mode
+    ///
+    /// [`checked_add()`](Instant::checked_add) truncates the [`Duration`], so a deadline computed
+    /// with it may be early. Use [`Rounding::Ceil`] to never be early.
+    ///
+    /// Returns [`None`] if the [`Duration`] is too large
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use embedded_time::{fraction::Fraction, duration::*, Instant, Rounding};
+    /// # #[derive(Debug)]
+    /// struct Clock;
+    /// impl embedded_time::Clock for Clock {
+    ///     type T = u32;
+    ///     const SCALING_FACTOR: Fraction = Fraction::new(1, 32_768);
+    ///     // ...
+    /// # fn try_now(&self) -> Result<Instant<Self>, embedded_time::clock::Error> {unimplemented!()}
+    /// }
+    ///
+    /// // 100 µs is 3.2768 ticks
+    /// assert_eq!(
+    ///     Instant::<Clock>::new(0).checked_add(Microseconds(100_u32)),
+    ///     Some(Instant::<Clock>::new(3))
+    /// );
+    /// assert_eq!(
+    ///     Instant::<Clock>::new(0).checked_add_rounded(Microseconds(100_u32), Rounding::Ceil),
+    ///     Some(Instant::<Clock>::new(4))
+    /// );
+    /// ```
+    pub fn checked_add_rounded<Dur>(self, duration: Dur, rounding: Rounding) -> Option<Self>
+    where
+        Dur: Duration + FixedPoint,
+        Clock::T: TryFrom<Dur::T> + core::ops::Div<Output = Clock::T>,
+    {
+        let add_ticks: Clock::T = duration
+            .into_ticks_rounded(Clock::SCALING_FACTOR, rounding)
+            .ok()?;
+        if add_ticks <= (<Clock::T as num::Bounded>::max_value() / 2.into()) {
+            Some(Self {
+                ticks: self.ticks.wrapping_add(&add_ticks),
+            })
+        } else {
+            None
+        }
+    }
+
+    /// This `Instant` - [`Duration`] = earlier `Instant`, with the [`Duration`] rounded to clock
+    /// ticks according to the [`Rounding`] mode
+    ///
+    /// Returns [`None`] if the [`Duration`] is too large
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use embedded_time::{fraction::Fraction, duration::*, Instant, Rounding};
+    /// # #[derive(Debug)]
+    /// struct Clock;
+    /// impl embedded_time::Clock for Clock {
+    ///     type T = u32;
+    ///     const SCALING_FACTOR: Fraction = Fraction::new(1, 32_768);
+    ///     // ...
+    /// # fn try_now(&self) -> Result<Instant<Self>, embedded_time::clock::Error> {unimplemented!()}
+    /// }
+    ///
+    /// assert_eq!(
+    ///     Instant::<Clock>::new(10).checked_sub_rounded(Microseconds(100_u32), Rounding::Ceil),
+    ///     Some(Instant::<Clock>::new(6))
+    /// );
+    /// ```
+    pub fn checked_sub_rounded<Dur>(self, duration: Dur, rounding: Rounding) -> Option<Self>
+    where
+        Dur: Duration + FixedPoint,
+        Clock::T: TryFrom<Dur::T> + core::ops::Div<Output = Clock::T>,
+    {
+        let sub_ticks: Clock::T = duration
+            .into_ticks_rounded(Clock::SCALING_FACTOR, rounding)
+            .ok()?;
+        if sub_ticks <= (<Clock::T as num::Bounded>::max_value() / 2.into()) {
+            Some(Self {
+                ticks: self.ticks.wrapping_sub(&sub_ticks),
+            })
+        } else {
+            None
+        }
+    }
+
+    /// This `Instant` + [`Duration`] = later (future) `Instant`, clamping the [`Duration`] to the
+    /// largest that can be added (half the range of the clock)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use embedded_time::{fraction::Fraction, duration::*, Instant};
+    /// # #[derive(Debug)]
+    /// struct Clock;
+    /// impl embedded_time::Clock for Clock {
+    ///     type T = u32;
+    ///     const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+    ///     // ...
+    /// # fn try_now(&self) -> Result<Instant<Self>, embedded_time::clock::Error> {unimplemented!()}
+    /// }
+    ///
+    /// assert_eq!(
+    ///     Instant::<Clock>::new(0).saturating_add(Seconds(1_u32)),
+    ///     Instant::<Clock>::new(1_000)
+    /// );
+    /// assert_eq!(
+    ///     Instant::<Clock>::new(0).saturating_add(Seconds(u32::MAX)),
+    ///     Instant::<Clock>::new(u32::MAX / 2)
+    /// );
+    /// ```
+    pub fn saturating_add<Dur>(self, duration: Dur) -> Self
+    where
+        Dur: Duration + FixedPoint,
+        Clock::T: TryFrom<Dur::T> + core::ops::Div<Output = Clock::T>,
+    {
+        Self {
+            ticks: self.ticks.wrapping_add(&Self::saturating_ticks(duration)),
+        }
+    }
+
+    /// This `Instant` - [`Duration`] = earlier `Instant`, clamping the [`Duration`] to the
+    /// largest that can be subtracted (half the range of the clock)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use embedded_time::{fraction::Fraction, duration::*, Instant};
+    /// # #[derive(Debug)]
+    /// struct Clock;
+    /// impl embedded_time::Clock for Clock {
+    ///     type T = u32;
+    ///     const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+    ///     // ...
+    /// # fn try_now(&self) -> Result<Instant<Self>, embedded_time::clock::Error> {unimplemented!()}
+    /// }
+    ///
+    /// assert_eq!(
+    ///     Instant::<Clock>::new(u32::MAX).saturating_sub(Seconds(u32::MAX)),
+    ///     Instant::<Clock>::new(u32::MAX - u32::MAX / 2)
+    /// );
+    /// ```
+    pub fn saturating_sub<Dur>(self, duration: Dur) -> Self
+    where
+        Dur: Duration + FixedPoint,
+        Clock::T: TryFrom<Dur::T> + core::ops::Div<Output = Clock::T>,
+    {
+        Self {
+            ticks: self.ticks.wrapping_sub(&Self::saturating_ticks(duration)),
+        }
+    }
+
+    /// Returns the ticks of the duration, clamped to half the range of the clock
+    fn saturating_ticks<Dur>(duration: Dur) -> Clock::T
+    where
+        Dur: Duration + FixedPoint,
+        Clock::T: TryFrom<Dur::T> + core::ops::Div<Output = Clock::T>,
+    {
+        let max_ticks = <Clock::T as num::Bounded>::max_value() / 2.into();
+        match Ticks::<Clock>::from_fixed_point(duration).map(|ticks| ticks.integer()) {
+            Ok(ticks) if ticks <= max_ticks => ticks,
+            _ => max_ticks,
+        }
+    }
 }
 
 impl<Clock: crate::Clock> Copy for Instant<Clock> {}
@@ -408,9 +574,9 @@ where
 }
 
 impl<Clock: crate::Clock> ops::Sub<Instant<Clock>> for Instant<Clock> {
-    type Output = duration::Generic<Clock::T>;
+    type Output = duration::Ticks<Clock>;
 
-    /// Subtract a two `Instant`s resulting in a `Duration`
+    /// Subtract a two `Instant`s resulting in [`duration::Ticks`] of the clock
     ///
     /// # Examples
     ///
@@ -463,5 +629,30 @@ impl<Clock: crate::clock::Clock> Hash for Instant<Clock> {
     }
 }
 
+#[cfg(feature = "ufmt")]
+impl<Clock: crate::Clock> ufmt::uDebug for Instant<Clock>
+where
+    Clock::T: ufmt::uDebug,
+{
+    fn fmt<W>(&self, f: &mut ufmt::Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: ufmt::uWrite + ?Sized,
+    {
+        f.debug_struct("Instant")?
+            .field("ticks", &self.ticks)?
+            .finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl<Clock: crate::Clock> defmt::Format for Instant<Clock>
+where
+    Clock::T: defmt::Format,
+{
+    fn format(&self, f: defmt::Formatter<'_>) {
+        defmt::write!(f, "Instant({} ticks)", self.ticks)
+    }
+}
+
 #[cfg(test)]
 mod tests {}
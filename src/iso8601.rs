@@ -0,0 +1,143 @@
+//! ISO 8601 duration encoding and decoding (eg. `PT1H30M0.250S`)
+
+use crate::{decimal::Decimal, fraction::Fraction, ConversionError};
+use core::fmt::{self, Write};
+
+const SECONDS_PER_MINUTE: u128 = 60;
+const SECONDS_PER_HOUR: u128 = 60 * SECONDS_PER_MINUTE;
+const SECONDS_PER_DAY: u128 = 24 * SECONDS_PER_HOUR;
+const SECONDS_PER_WEEK: u128 = 7 * SECONDS_PER_DAY;
+
+/// The most fractional digits of the seconds that are written
+const MAX_DIGITS: u32 = 38;
+
+/// Writes `integer × scaling_factor` seconds as an ISO 8601 duration
+///
+/// Only the hours, minutes and seconds designators are used. If the duration has a fractional
+/// part, the seconds are written with the number of digits of the _scaling factor_'s resolution
+/// (eg. 3 for milliseconds).
+///
+/// Returns [`fmt::Error`] if the value cannot be written exactly.
+pub(crate) fn write<W: Write>(w: &mut W, integer: u64, scaling_factor: &Fraction) -> fmt::Result {
+    let numerator = u128::from(integer) * u128::from(*scaling_factor.numerator());
+    let denominator = u128::from(*scaling_factor.denominator());
+    if denominator == 0 {
+        return Err(fmt::Error);
+    }
+
+    let seconds = numerator / denominator;
+    let mut remainder = numerator % denominator;
+    let hours = seconds / SECONDS_PER_HOUR;
+    let minutes = seconds % SECONDS_PER_HOUR / SECONDS_PER_MINUTE;
+    let seconds = seconds % SECONDS_PER_MINUTE;
+
+    w.write_str("PT")?;
+    if hours > 0 {
+        write!(w, "{}H", hours)?;
+    }
+    if minutes > 0 {
+        write!(w, "{}M", minutes)?;
+    }
+
+    if remainder > 0 {
+        let digits = fraction_digits(1, denominator)
+            .or_else(|| fraction_digits(remainder, denominator))
+            .ok_or(fmt::Error)?;
+
+        write!(w, "{}.", seconds)?;
+        for _ in 0..digits {
+            // the remainder is less than the denominator, so the digit is always less than 10
+            remainder *= 10;
+            w.write_char(char::from(b'0' + (remainder / denominator) as u8))?;
+            remainder %= denominator;
+        }
+        w.write_char('S')
+    } else if seconds > 0 || (hours == 0 && minutes == 0) {
+        write!(w, "{}S", seconds)
+    } else {
+        Ok(())
+    }
+}
+
+/// The number of decimal digits needed to write `remainder / denominator` exactly (if finite)
+fn fraction_digits(remainder: u128, denominator: u128) -> Option<u32> {
+    let mut remainder = remainder % denominator;
+    let mut digits = 0;
+    while remainder != 0 {
+        if digits == MAX_DIGITS {
+            return None;
+        }
+        remainder = remainder * 10 % denominator;
+        digits += 1;
+    }
+
+    Some(digits)
+}
+
+/// Parses an ISO 8601 duration into seconds
+///
+/// The weeks (`W`) and days (`D`) designators of the date part are accepted as exactly 7 and 1
+/// days. Years and months are rejected as they don't have a fixed length.
+pub(crate) fn parse(s: &str) -> Result<Decimal, ConversionError> {
+    const DATE_UNITS: [(char, u128); 2] = [('W', SECONDS_PER_WEEK), ('D', SECONDS_PER_DAY)];
+    const TIME_UNITS: [(char, u128); 3] =
+        [('H', SECONDS_PER_HOUR), ('M', SECONDS_PER_MINUTE), ('S', 1)];
+
+    let s = s.strip_prefix('P').ok_or(ConversionError::ParseFailure)?;
+    let (date, time) = match s.find('T') {
+        Some(separator) => (&s[..separator], Some(&s[separator + 1..])),
+        None => (s, None),
+    };
+
+    let mut seconds = Decimal::ZERO;
+    let date_components = parse_components(date, &DATE_UNITS, &mut seconds)?;
+    let time_components = match time {
+        Some(time) => match parse_components(time, &TIME_UNITS, &mut seconds)? {
+            0 => return Err(ConversionError::ParseFailure),
+            components => components,
+        },
+        None => 0,
+    };
+
+    if date_components + time_components == 0 {
+        Err(ConversionError::ParseFailure)
+    } else {
+        Ok(seconds)
+    }
+}
+
+/// Adds the value of each component (eg. `30M`) to `seconds`, returning the number of components
+///
+/// The designators must appear in the order of `units`, each at most once.
+fn parse_components(
+    mut s: &str,
+    units: &[(char, u128)],
+    seconds: &mut Decimal,
+) -> Result<usize, ConversionError> {
+    let mut units = units.iter();
+    let mut components = 0;
+    while !s.is_empty() {
+        let (value, rest) = Decimal::split_iso8601(s)?;
+        let designator = rest.chars().next().ok_or(ConversionError::ParseFailure)?;
+        let &(_, unit_seconds) = units
+            .find(|&&(unit, _)| unit == designator)
+            .ok_or(ConversionError::ParseFailure)?;
+
+        *seconds = seconds.checked_add(&value.checked_mul(unit_seconds)?)?;
+        s = &rest[designator.len_utf8()..];
+        components += 1;
+    }
+
+    Ok(components)
+}
+
+/// Converts seconds into an exact number of ticks of the _scaling factor_
+pub(crate) fn seconds_to_ticks(
+    seconds: &Decimal,
+    scaling_factor: &Fraction,
+) -> Result<u128, ConversionError> {
+    seconds.checked_mul_ratio(
+        u128::from(*scaling_factor.denominator()),
+        u128::from(*scaling_factor.numerator()),
+    )
+}
@@ -124,12 +124,15 @@
 //!
 //! | Units        | Extension    |
 //! | :----------- | :----------- |
+//! | Weeks        | weeks        |
+//! | Days         | days         |
 //! | Hours        | hours        |
 //! | Minutes      | minutes      |
 //! | Seconds      | seconds      |
 //! | Milliseconds | milliseconds |
 //! | Microseconds | microseconds |
 //! | Nanoseconds  | nanoseconds  |
+//! | Picoseconds  | picoseconds  |
 //!
 //! - Conversion from `Rate` types
 //! ```rust
@@ -278,23 +281,98 @@
 #![warn(missing_docs)]
 #![deny(broken_intra_doc_links)]
 
+// lets the doc links of the unit macros resolve both in this crate and in user crates
+extern crate self as embedded_time;
+
+#[cfg(feature = "chrono")]
+mod chrono;
 pub mod clock;
+mod decimal;
 pub mod duration;
 pub mod fixed_point;
+#[cfg(any(feature = "f32", feature = "f64"))]
+mod float;
 pub mod fraction;
+#[cfg(feature = "fugit")]
+pub mod fugit;
 mod instant;
+mod iso8601;
 pub mod rate;
+#[cfg(feature = "serde")]
+mod serde;
+#[cfg(feature = "time")]
+mod time;
 mod time_int;
 pub mod timer;
 
 pub use clock::Clock;
+use core::convert::Infallible;
 pub use instant::Instant;
-pub use time_int::TimeInt;
+pub use time_int::{SignedTimeInt, TimeInt};
 pub use timer::Timer;
 
+/// The rounding mode of a conversion that can't be represented exactly
+///
+/// Conversions that don't take a `Rounding` truncate (equivalent to `Floor` as all values are
+/// unsigned).
+///
+/// ```rust
+/// use embedded_time::{duration::*, Rounding};
+///
+/// let round = |micros, rounding| Milliseconds::<u32>::try_from_rounded(Microseconds(micros), rounding);
+///
+/// assert_eq!(round(1_500_u32, Rounding::Floor), Ok(Milliseconds(1_u32)));
+/// assert_eq!(round(1_500_u32, Rounding::Ceil), Ok(Milliseconds(2_u32)));
+/// assert_eq!(round(1_500_u32, Rounding::NearestEven), Ok(Milliseconds(2_u32)));
+/// assert_eq!(round(2_500_u32, Rounding::NearestEven), Ok(Milliseconds(2_u32)));
+/// ```
+#[non_exhaustive]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum Rounding {
+    /// Round down (toward zero)
+    Floor,
+    /// Round up (away from zero)
+    Ceil,
+    /// Round to the nearest value, ties to the even value
+    NearestEven,
+}
+
+impl Rounding {
+    /// Returns `numerator / denominator` rounded according to the mode
+    ///
+    /// Returns [`None`] upon division by zero or overflow.
+    pub(crate) fn div<T>(self, numerator: T, denominator: T) -> Option<T>
+    where
+        T: Copy + num::Integer + num::CheckedAdd,
+    {
+        if denominator.is_zero() {
+            return None;
+        }
+
+        let (quotient, remainder) = numerator.div_rem(&denominator);
+        let round_up = match self {
+            Rounding::Floor => false,
+            Rounding::Ceil => !remainder.is_zero(),
+            Rounding::NearestEven => {
+                // compare the remainder to the distance to the next multiple to avoid overflow
+                let rest = denominator - remainder;
+                remainder > rest || (remainder == rest && quotient.is_odd())
+            }
+        };
+
+        if round_up {
+            quotient.checked_add(&T::one())
+        } else {
+            Some(quotient)
+        }
+    }
+}
+
 /// Crate errors
 #[non_exhaustive]
 #[derive(Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "ufmt", derive(ufmt::derive::uDebug))]
 pub enum TimeError {
     /// Exact cause of failure is unknown
     Unspecified,
@@ -306,6 +384,10 @@ pub enum TimeError {
     DivByZero,
     /// Resulting [`Duration`](duration/trait.Duration.html) is negative (not allowed)
     NegDuration,
+    /// The value cannot be represented exactly in the destination type
+    PrecisionLoss,
+    /// The string could not be parsed
+    ParseFailure,
     /// [`Clock`]-implementation-specific error
     Clock(clock::Error),
 }
@@ -325,6 +407,8 @@ impl Default for TimeError {
 /// Conversion errors
 #[non_exhaustive]
 #[derive(Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "ufmt", derive(ufmt::derive::uDebug))]
 pub enum ConversionError {
     /// Exact cause of failure is unknown
     Unspecified,
@@ -336,6 +420,10 @@ pub enum ConversionError {
     DivByZero,
     /// Resulting [`Duration`](duration/trait.Duration.html) is negative (not allowed)
     NegDuration,
+    /// The value cannot be represented exactly in the destination type
+    PrecisionLoss,
+    /// The string could not be parsed
+    ParseFailure,
 }
 
 impl From<ConversionError> for TimeError {
@@ -346,10 +434,18 @@ impl From<ConversionError> for TimeError {
             ConversionError::Overflow => TimeError::Overflow,
             ConversionError::DivByZero => TimeError::DivByZero,
             ConversionError::NegDuration => TimeError::NegDuration,
+            ConversionError::PrecisionLoss => TimeError::PrecisionLoss,
+            ConversionError::ParseFailure => TimeError::ParseFailure,
         }
     }
 }
 
+impl From<Infallible> for ConversionError {
+    fn from(error: Infallible) -> Self {
+        match error {}
+    }
+}
+
 impl Default for ConversionError {
     fn default() -> Self {
         Self::Unspecified
@@ -1,16 +1,19 @@
 //! Rate-based types/units
 
+#[cfg(any(feature = "f32", feature = "f64"))]
+use crate::float;
 pub use crate::fraction::Fraction;
 use crate::{
+    decimal::{self, Decimal},
     duration,
     fixed_point::{self, FixedPoint},
     time_int::TimeInt,
     ConversionError,
 };
-use core::{convert::TryFrom, mem::size_of, prelude::v1::*};
+use core::{convert::TryFrom, fmt, prelude::v1::*, str::FromStr};
 #[doc(hidden)]
 pub use fixed_point::FixedPoint as _;
-use num::{CheckedDiv, CheckedMul};
+use num::{CheckedAdd, CheckedSub};
 #[doc(inline)]
 pub use units::*;
 
@@ -46,6 +49,86 @@ pub use units::*;
 /// assert_eq!(format!("{}", Hertz(123_u32)), "123");
 /// ```
 ///
+/// For human-readable output with a prefix and class symbol, each unit (and [`Generic`]) has a
+/// `display_human()` method. See [`DisplayHuman`].
+///
+/// ```rust
+/// use embedded_time::rate::*;
+///
+/// assert_eq!(format!("{}", Hertz(16_000_000_u32).display_human()), "16 MHz");
+/// assert_eq!(format!("{}", Baud(115_200_u32).display_human()), "115.2 kBd");
+/// ```
+///
+/// # Parsing
+///
+/// `Rate`s (including [`Generic`]) implement [`FromStr`]. The string consists
+/// of an integer or decimal value followed by an optional prefix (`k`, `M`, `Ki` or `Mi`) and a
+/// class symbol: `Hz`, `bps` (bits/s), `Bps` (bytes/s) or `Bd` (baud). Whitespace is allowed
+/// between the value and the unit.
+///
+/// ```rust
+/// use embedded_time::rate::*;
+///
+/// assert_eq!("16MHz".parse(), Ok(Hertz(16_000_000_u32)));
+/// assert_eq!("115200 Bd".parse(), Ok(Baud(115_200_u32)));
+/// assert_eq!("1.5 kBps".parse(), Ok(BitsPerSecond(12_000_u32)));
+/// assert_eq!("2 Kibps".parse(), Ok(BitsPerSecond(2_048_u32)));
+/// ```
+///
+/// A [`Generic`] `Rate` uses the coarsest unit of the class that represents the value exactly.
+///
+/// ```rust
+/// use embedded_time::rate::*;
+///
+/// assert_eq!(
+///     "12.5 Hz".parse::<Generic<u32>>().map(|generic| *generic.scaling_factor()),
+///     Ok(Fraction::new(1, 10))
+/// );
+/// ```
+///
+/// ## Errors
+///
+/// [`ConversionError::ParseFailure`] : The string is not a valid rate
+///
+/// ```rust
+/// use embedded_time::{rate::*, ConversionError};
+///
+/// assert_eq!("115200".parse::<Baud>(), Err(ConversionError::ParseFailure));
+/// assert_eq!("16 GHz".parse::<Hertz>(), Err(ConversionError::ParseFailure));
+/// ```
+///
+/// ---
+///
+/// [`ConversionError::ConversionFailure`] : The class of the string doesn't match the destination
+/// type (bits and bytes are interchangeable)
+///
+/// ```rust
+/// use embedded_time::{rate::*, ConversionError};
+///
+/// assert_eq!("9600 Bd".parse::<Hertz>(), Err(ConversionError::ConversionFailure));
+/// ```
+///
+/// ---
+///
+/// [`ConversionError::PrecisionLoss`] : The value cannot be represented exactly in the
+/// destination type
+///
+/// ```rust
+/// use embedded_time::{rate::*, ConversionError};
+///
+/// assert_eq!("1.5 Hz".parse::<Hertz>(), Err(ConversionError::PrecisionLoss));
+/// ```
+///
+/// ---
+///
+/// [`ConversionError::Overflow`] : The value doesn't fit in the destination type
+///
+/// ```rust
+/// use embedded_time::{rate::*, ConversionError};
+///
+/// assert_eq!("5 MHz".parse::<Millihertz<u32>>(), Err(ConversionError::Overflow));
+/// ```
+///
 /// # Converting between `Rate`s
 ///
 /// Many intra-rate conversions can be done using `From`/`Into`:
@@ -73,6 +156,25 @@ pub use units::*;
 /// assert_eq!(hertz.integer(), 23_000_u32);
 /// ```
 ///
+/// # Constant evaluation
+///
+/// Construction, [`integer()`](Hertz::integer), widening and conversions to any _scaling
+/// factor_ of units with a concrete integer type are `const fn`, so prescaler and baud-rate
+/// divisor values can be computed at compile time. A conversion that fails in a `const` context
+/// is a compile-time error.
+///
+/// ```rust
+/// use embedded_time::rate::*;
+///
+/// const SYSCLK: Megahertz<u32> = Megahertz::new(16);
+/// const SYSCLK_HZ: u32 = SYSCLK.const_into_ticks(Hertz::<u32>::SCALING_FACTOR);
+/// const BAUD_DIVISOR: u32 = SYSCLK_HZ / 115_200;
+///
+/// assert_eq!(SYSCLK_HZ, 16_000_000);
+/// assert_eq!(BAUD_DIVISOR, 138);
+/// assert_eq!(SYSCLK.const_widen(), Megahertz(16_u64));
+/// ```
+///
 /// # Converting from a [`Generic`] `Rate`
 ///
 /// ## Examples
@@ -97,7 +199,7 @@ pub use units::*;
 ///
 /// ---
 ///
-/// [`ConversionError::Unspecified`]
+/// [`ConversionError::Overflow`] : The result doesn't fit in either integer type.
 ///
 /// ```rust
 /// use embedded_time::{rate::*, ConversionError};
@@ -105,7 +207,7 @@ pub use units::*;
 ///
 /// assert_eq!(
 ///     Hertz::<u32>::try_from(Generic::new(u32::MAX, Fraction::new(10,1))),
-///     Err(ConversionError::Unspecified)
+///     Err(ConversionError::Overflow)
 /// );
 /// ```
 ///
@@ -145,7 +247,17 @@ pub use units::*;
 ///
 /// # Creating a custom `Rate`
 ///
+/// [`Scaled`] is a frequency unit with any _scaling factor_ known at compile time:
+///
+/// ```rust
+/// use core::convert::TryFrom;
+/// use embedded_time::rate::*;
+///
+/// // 16 MHz prescaled by 3
+/// type Prescaled = Scaled<u32, 16_000_000, 3>;
 ///
+/// assert_eq!(Hertz::<u32>::try_from(Prescaled::new(3)), Ok(Hertz(16_000_000_u32)));
+/// ```
 ///
 /// # Add/Sub
 ///
@@ -173,10 +285,15 @@ pub use units::*;
 /// let _ = Hertz(u32::MAX) + Hertz(1_u32);
 /// ```
 ///
+/// [`saturating_add()`](fixed_point::FixedPoint::saturating_add) and
+/// [`saturating_sub()`](fixed_point::FixedPoint::saturating_sub) clamp the result instead.
+/// [`checked_add()`](Rate::checked_add) and [`checked_sub()`](Rate::checked_sub) return an
+/// error instead.
+///
 /// # Mul/Div
 ///
-/// Rates may also be multiplied and divided by integers. The result is of the LHS type. Both
-/// _panicky_ and _checked_ operations are available.
+/// Rates may also be multiplied and divided by integers. The result is of the LHS type.
+/// _Panicky_, _checked_ and _saturating_ (multiplication only) operations are available.
 ///
 /// # Comparisons
 ///
@@ -219,14 +336,14 @@ pub trait Rate: Sized + Copy {
     ///
     /// ---
     ///
-    /// [`ConversionError::Unspecified`]
+    /// [`ConversionError::Overflow`] : The result doesn't fit in either integer type.
     ///
     /// ```rust
     /// # use embedded_time::{rate::*, ConversionError};
     ///
     /// assert_eq!(
     ///     Hertz(u32::MAX).to_generic::<u32>(Fraction::new(1, 2)),
-    ///     Err(ConversionError::Unspecified)
+    ///     Err(ConversionError::Overflow)
     /// );
     /// ```
     ///
@@ -278,13 +395,13 @@ pub trait Rate: Sized + Copy {
     ///
     /// ---
     ///
-    /// [`ConversionError::Overflow`] : The conversion of the _scaling factor_ causes an overflow.
+    /// [`ConversionError::Overflow`] : The result doesn't fit in the destination type.
     ///
     /// ```rust
     /// use embedded_time::{duration::*, rate::*, ConversionError};
     ///
     /// assert_eq!(
-    ///     Megahertz(u32::MAX).to_duration::<Hours<u32>>(),
+    ///     Millihertz(1_u32).to_duration::<Nanoseconds<u32>>(),
     ///     Err(ConversionError::Overflow)
     /// );
     /// ```
@@ -307,35 +424,216 @@ pub trait Rate: Sized + Copy {
         Self: FixedPoint,
         Duration::T: TryFrom<Self::T>,
     {
-        let conversion_factor = Self::SCALING_FACTOR
-            .checked_mul(&Duration::SCALING_FACTOR)
-            .ok_or(ConversionError::Unspecified)?
-            .recip();
-
-        if size_of::<Self::T>() >= size_of::<Duration::T>() {
-            fixed_point::FixedPoint::from_ticks(
-                Self::T::from(*conversion_factor.numerator())
-                    .checked_div(
-                        &self
-                            .integer()
-                            .checked_mul(&Self::T::from(*conversion_factor.denominator()))
-                            .ok_or(ConversionError::Overflow)?,
-                    )
-                    .ok_or(ConversionError::DivByZero)?,
-                Duration::SCALING_FACTOR,
-            )
+        let ticks = fixed_point::reciprocal_ticks(
+            self.integer().into_u128(),
+            Self::SCALING_FACTOR,
+            Duration::SCALING_FACTOR,
+        )?;
+        Ok(FixedPoint::new(
+            Duration::T::try_from_u128(ticks).ok_or(ConversionError::Overflow)?,
+        ))
+    }
+
+    /// Returns the rate in hertz (events per second) as an `f32`, rounded to the nearest
+    /// representable value
+    ///
+    /// Data rates are in bits per second.
+    ///
+    /// ```rust
+    /// use embedded_time::rate::*;
+    ///
+    /// assert_eq!(Kilohertz(32_u32).as_hz_f32(), 32_000.0);
+    /// assert_eq!(BytesPerSecond(1_u32).as_hz_f32(), 8.0);
+    /// ```
+    #[cfg(feature = "f32")]
+    fn as_hz_f32(&self) -> f32
+    where
+        Self: FixedPoint,
+        u64: From<Self::T>,
+    {
+        float::to_f32(self.integer().into(), &Self::SCALING_FACTOR)
+    }
+
+    /// Returns the rate in hertz (events per second) as an `f64`, rounded to the nearest
+    /// representable value
+    ///
+    /// Data rates are in bits per second.
+    ///
+    /// ```rust
+    /// use embedded_time::rate::*;
+    ///
+    /// assert_eq!(Millihertz(1_500_u32).as_hz_f64(), 1.5);
+    /// ```
+    #[cfg(feature = "f64")]
+    fn as_hz_f64(&self) -> f64
+    where
+        Self: FixedPoint,
+        u64: From<Self::T>,
+    {
+        float::to_f64(self.integer().into(), &Self::SCALING_FACTOR)
+    }
+
+    /// Constructs a rate from hertz (events per second) as an `f32`, rounded to the nearest
+    /// _integer_ (ties to even)
+    ///
+    /// Data rates are in bits per second.
+    ///
+    /// ```rust
+    /// use embedded_time::rate::*;
+    ///
+    /// assert_eq!(Kilohertz::<u32>::from_hz_f32(1.5e6), Ok(Kilohertz(1_500_u32)));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// - [`ConversionError::ConversionFailure`] : The value is negative or NaN
+    /// - [`ConversionError::Overflow`] : The value is infinite or doesn't fit in the type
+    #[cfg(feature = "f32")]
+    fn from_hz_f32(hz: f32) -> Result<Self, ConversionError>
+    where
+        Self: FixedPoint,
+        Self::T: TryFrom<u128>,
+    {
+        let ticks = float::to_ticks(f64::from(hz), &Self::SCALING_FACTOR)?;
+        Ok(Self::new(
+            Self::T::try_from(ticks).map_err(|_| ConversionError::Overflow)?,
+        ))
+    }
+
+    /// Constructs a rate from hertz (events per second) as an `f64`, rounded to the nearest
+    /// _integer_ (ties to even)
+    ///
+    /// Data rates are in bits per second.
+    ///
+    /// ```rust
+    /// use embedded_time::rate::*;
+    ///
+    /// assert_eq!(Hertz::<u32>::from_hz_f64(49.9), Ok(Hertz(50_u32)));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// - [`ConversionError::ConversionFailure`] : The value is negative or NaN
+    /// - [`ConversionError::Overflow`] : The value is infinite or doesn't fit in the type
+    ///
+    /// ```rust
+    /// use embedded_time::{rate::*, ConversionError};
+    ///
+    /// assert_eq!(Hertz::<u32>::from_hz_f64(-1.0), Err(ConversionError::ConversionFailure));
+    /// assert_eq!(Hertz::<u32>::from_hz_f64(f64::INFINITY), Err(ConversionError::Overflow));
+    /// ```
+    #[cfg(feature = "f64")]
+    fn from_hz_f64(hz: f64) -> Result<Self, ConversionError>
+    where
+        Self: FixedPoint,
+        Self::T: TryFrom<u128>,
+    {
+        let ticks = float::to_ticks(hz, &Self::SCALING_FACTOR)?;
+        Ok(Self::new(
+            Self::T::try_from(ticks).map_err(|_| ConversionError::Overflow)?,
+        ))
+    }
+
+    /// Checked addition of a rate of any compatible unit
+    ///
+    /// The RHS is converted into the LHS type first, which fails rather than truncating a RHS of a
+    /// finer unit. Unlike the `+` operator, this never panics.
+    ///
+    /// ```rust
+    /// use embedded_time::{rate::*, ConversionError};
+    ///
+    /// assert_eq!(Hertz(500_u32).checked_add(Kilohertz(1_u32)), Ok(Hertz(1_500_u32)));
+    /// assert_eq!(Hertz(u32::MAX).checked_add(Kilohertz(1_u32)), Err(ConversionError::Overflow));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// - [`ConversionError::Overflow`] : The result doesn't fit in the LHS type
+    /// - [`ConversionError::PrecisionLoss`] : The RHS can't be represented exactly in the LHS unit
+    /// - Any error from converting the RHS into the LHS type
+    fn checked_add<Rhs>(self, rhs: Rhs) -> Result<Self, ConversionError>
+    where
+        Self: FixedPoint + TryFrom<Rhs>,
+        Rhs: Rate + FixedPoint,
+        ConversionError: From<<Self as TryFrom<Rhs>>::Error>,
+    {
+        let rhs: Self = fixed_point::try_from_exact(rhs)?;
+        self.integer()
+            .checked_add(&rhs.integer())
+            .map(Self::new)
+            .ok_or(ConversionError::Overflow)
+    }
+
+    /// Checked subtraction of a rate of any compatible unit
+    ///
+    /// The RHS is converted into the LHS type first, which fails rather than truncating a RHS of a
+    /// finer unit. Unlike the `-` operator, this never panics.
+    ///
+    /// A negative difference is [`ConversionError::Overflow`] because
+    /// [`ConversionError::NegDuration`] only applies to durations (see
+    /// [`Duration::checked_sub()`](duration::Duration::checked_sub)).
+    ///
+    /// ```rust
+    /// use embedded_time::{rate::*, ConversionError};
+    ///
+    /// assert_eq!(Hertz(1_500_u32).checked_sub(Kilohertz(1_u32)), Ok(Hertz(500_u32)));
+    /// assert_eq!(Hertz(500_u32).checked_sub(Kilohertz(1_u32)), Err(ConversionError::Overflow));
+    /// assert_eq!(
+    ///     Kilohertz(2_u32).checked_sub(Hertz(1_500_u32)),
+    ///     Err(ConversionError::PrecisionLoss)
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// - [`ConversionError::Overflow`] : The result is negative
+    /// - [`ConversionError::PrecisionLoss`] : The RHS can't be represented exactly in the LHS unit
+    /// - Any error from converting the RHS into the LHS type
+    fn checked_sub<Rhs>(self, rhs: Rhs) -> Result<Self, ConversionError>
+    where
+        Self: FixedPoint + TryFrom<Rhs>,
+        Rhs: Rate + FixedPoint,
+        ConversionError: From<<Self as TryFrom<Rhs>>::Error>,
+    {
+        let rhs: Self = fixed_point::try_from_exact(rhs)?;
+        self.integer()
+            .checked_sub(&rhs.integer())
+            .map(Self::new)
+            .ok_or(ConversionError::Overflow)
+    }
+
+    /// Checked remainder of a division by a rate of any compatible unit
+    ///
+    /// The RHS is converted into the LHS type first, which fails rather than truncating a RHS of a
+    /// finer unit. Unlike the `%` operator, a zero RHS is an error.
+    ///
+    /// ```rust
+    /// use embedded_time::{rate::*, ConversionError};
+    ///
+    /// assert_eq!(Hertz(2_037_u32).checked_rem(Kilohertz(1_u32)), Ok(Hertz(37_u32)));
+    /// assert_eq!(Hertz(2_037_u32).checked_rem(Kilohertz(0_u32)), Err(ConversionError::DivByZero));
+    /// assert_eq!(
+    ///     Kilohertz(5_u32).checked_rem(Hertz(500_u32)),
+    ///     Err(ConversionError::PrecisionLoss)
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// - [`ConversionError::DivByZero`] : The RHS is `0`
+    /// - [`ConversionError::PrecisionLoss`] : The RHS can't be represented exactly in the LHS unit
+    /// - Any error from converting the RHS into the LHS type
+    fn checked_rem<Rhs>(self, rhs: Rhs) -> Result<Self, ConversionError>
+    where
+        Self: FixedPoint + TryFrom<Rhs>,
+        Rhs: Rate + FixedPoint,
+        ConversionError: From<<Self as TryFrom<Rhs>>::Error>,
+    {
+        let rhs: Self = fixed_point::try_from_exact(rhs)?;
+        if rhs.integer() == Self::T::from(0) {
+            Err(ConversionError::DivByZero)
         } else {
-            fixed_point::FixedPoint::from_ticks(
-                Duration::T::from(*conversion_factor.numerator())
-                    .checked_div(
-                        &Duration::T::try_from(self.integer())
-                            .map_err(|_| ConversionError::Overflow)?
-                            .checked_mul(&Duration::T::from(*conversion_factor.denominator()))
-                            .ok_or(ConversionError::Overflow)?,
-                    )
-                    .ok_or(ConversionError::DivByZero)?,
-                Duration::SCALING_FACTOR,
-            )
+            Ok(Self::new(self.integer() % rhs.integer()))
         }
     }
 }
@@ -369,237 +667,1287 @@ impl<T: TimeInt> Generic<T> {
     pub fn scaling_factor(&self) -> &Fraction {
         &self.scaling_factor
     }
-}
 
-impl<T: TimeInt> Rate for Generic<T> {}
-
-/// Rate-type units
-#[doc(hidden)]
-pub mod units {
-    use super::*;
-    use crate::{
-        fixed_point::{self, FixedPoint},
-        fraction::Fraction,
-        time_int::TimeInt,
-        ConversionError,
-    };
-    use core::{
-        cmp,
-        convert::TryFrom,
-        fmt::{self, Formatter},
-        ops,
-    };
-    #[doc(hidden)]
-    pub use Extensions as _;
+    /// Returns a [`DisplayHuman`] that formats the rate in hertz with the largest fitting prefix
+    ///
+    /// Like the binary units (eg. [`Kibihertz`]), a rate whose _scaling factor_ is a whole multiple
+    /// of 1,024 Hz is written with a binary prefix (`Ki`/`Mi`). Any other rate is written with a
+    /// decimal prefix (`k`/`M`).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use embedded_time::rate::*;
+    ///
+    /// let generic = Generic::new(32_768_u32, Fraction::new(1, 1));
+    /// assert_eq!(format!("{}", generic.display_human()), "32.768 kHz");
+    ///
+    /// let generic = Generic::from(Kibihertz(32_u32));
+    /// assert_eq!(format!("{}", generic.display_human()), "32 KiHz");
+    /// ```
+    pub fn display_human(&self) -> DisplayHuman
+    where
+        u64: From<T>,
+    {
+        DisplayHuman::new(
+            self.integer.into(),
+            self.scaling_factor,
+            Class::Frequency,
+            Prefixes::of(&self.scaling_factor),
+        )
+    }
 
-    macro_rules! impl_rate {
-        ( $name:ident, ($numer:expr, $denom:expr), $desc:literal ) => {
-            #[doc = $desc]
-            #[derive(Copy, Clone, Eq, Ord, Hash, Debug, Default)]
-            #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-            pub struct $name<T: TimeInt = u32>(pub T);
-
-            impl<T: TimeInt> $name<T> {
-                /// See [Constructing a rate](trait.Rate.html#constructing-a-rate)
-                pub fn new(value: T) -> Self {
-                    Self(value)
-                }
-            }
+    /// Returns the rate in hertz as an `f32`, rounded to the nearest representable value
+    ///
+    /// ```rust
+    /// use embedded_time::rate::*;
+    ///
+    /// assert_eq!(Generic::new(3_u32, Fraction::new(1, 2)).as_hz_f32(), 1.5);
+    /// ```
+    #[cfg(feature = "f32")]
+    pub fn as_hz_f32(&self) -> f32
+    where
+        u64: From<T>,
+    {
+        float::to_f32(self.integer.into(), &self.scaling_factor)
+    }
 
-            impl<T: TimeInt> Rate for $name<T> {}
+    /// Returns the rate in hertz as an `f64`, rounded to the nearest representable value
+    ///
+    /// ```rust
+    /// use embedded_time::rate::*;
+    ///
+    /// assert_eq!(Generic::new(3_u32, Fraction::new(1, 2)).as_hz_f64(), 1.5);
+    /// ```
+    #[cfg(feature = "f64")]
+    pub fn as_hz_f64(&self) -> f64
+    where
+        u64: From<T>,
+    {
+        float::to_f64(self.integer.into(), &self.scaling_factor)
+    }
 
-            impl<T: TimeInt> FixedPoint for $name<T> {
-                type T = T;
-                const SCALING_FACTOR: Fraction = Fraction::new($numer, $denom);
+    /// Constructs a rate with the given _scaling factor_ from hertz as an `f32`, rounded to the
+    /// nearest _integer_ (ties to even)
+    ///
+    /// # Errors
+    ///
+    /// - [`ConversionError::ConversionFailure`] : The value is negative or NaN
+    /// - [`ConversionError::Overflow`] : The value is infinite or doesn't fit in the type
+    /// - [`ConversionError::DivByZero`] : The _scaling factor_ is `0`
+    #[cfg(feature = "f32")]
+    pub fn from_hz_f32(hz: f32, scaling_factor: Fraction) -> Result<Self, ConversionError>
+    where
+        T: TryFrom<u128>,
+    {
+        let ticks = float::to_ticks(f64::from(hz), &scaling_factor)?;
+        Ok(Self::new(
+            T::try_from(ticks).map_err(|_| ConversionError::Overflow)?,
+            scaling_factor,
+        ))
+    }
 
-                /// See [Constructing a rate](trait.Rate.html#constructing-a-rate)
-                fn new(value: Self::T) -> Self {
-                    Self(value)
-                }
+    /// Constructs a rate with the given _scaling factor_ from hertz as an `f64`, rounded to the
+    /// nearest _integer_ (ties to even)
+    ///
+    /// ```rust
+    /// use embedded_time::rate::*;
+    ///
+    /// assert_eq!(
+    ///     Generic::<u32>::from_hz_f64(1.5, Fraction::new(1, 2)),
+    ///     Ok(Generic::new(3_u32, Fraction::new(1, 2)))
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// - [`ConversionError::ConversionFailure`] : The value is negative or NaN
+    /// - [`ConversionError::Overflow`] : The value is infinite or doesn't fit in the type
+    /// - [`ConversionError::DivByZero`] : The _scaling factor_ is `0`
+    #[cfg(feature = "f64")]
+    pub fn from_hz_f64(hz: f64, scaling_factor: Fraction) -> Result<Self, ConversionError>
+    where
+        T: TryFrom<u128>,
+    {
+        let ticks = float::to_ticks(hz, &scaling_factor)?;
+        Ok(Self::new(
+            T::try_from(ticks).map_err(|_| ConversionError::Overflow)?,
+            scaling_factor,
+        ))
+    }
+}
 
-                /// See [Get the integer part](trait.Rate.html#get-the-integer-part)
-                fn integer(&self) -> Self::T {
-                    self.0
-                }
-            }
+impl<T: TimeInt> Rate for Generic<T> {}
 
-            impl<T: TimeInt> fmt::Display for $name<T> {
-                /// See [Formatting](trait.Rate.html#formatting)
-                fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-                    fmt::Display::fmt(&self.0, f)
-                }
-            }
+#[cfg(feature = "defmt")]
+impl<T: TimeInt + defmt::Format> defmt::Format for Generic<T> {
+    fn format(&self, f: defmt::Formatter<'_>) {
+        defmt::write!(f, "{} x {} Hz", self.integer, self.scaling_factor)
+    }
+}
 
-            impl<T: TimeInt, Rhs: Rate> ops::Add<Rhs> for $name<T>
-            where
-                Rhs: FixedPoint,
-                Self: TryFrom<Rhs>,
-            {
-                type Output = Self;
+#[cfg(feature = "ufmt")]
+impl<T: TimeInt + ufmt::uDebug> ufmt::uDebug for Generic<T> {
+    fn fmt<W>(&self, f: &mut ufmt::Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: ufmt::uWrite + ?Sized,
+    {
+        f.debug_struct("Generic")?
+            .field("integer", &self.integer)?
+            .field("scaling_factor", &self.scaling_factor)?
+            .finish()
+    }
+}
 
-                /// See [Add/Sub](trait.Rate.html#addsub)
-                fn add(self, rhs: Rhs) -> Self::Output {
-                    <Self as FixedPoint>::add(self, rhs)
-                }
+impl<T: TimeInt> FromStr for Generic<T>
+where
+    T: TryFrom<u128>,
+{
+    type Err = ConversionError;
+
+    /// See [Parsing](trait.Rate.html#parsing)
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (value, multiplier, class) = parse(s)?;
+        let (integer, scaling_factor) = class
+            .scaling_factors()
+            .iter()
+            .find_map(|scaling_factor| {
+                value
+                    .checked_mul_ratio(
+                        multiplier * u128::from(*scaling_factor.denominator()),
+                        u128::from(*scaling_factor.numerator()),
+                    )
+                    .ok()
+                    .map(|ticks| (ticks, *scaling_factor))
+            })
+            .ok_or(ConversionError::PrecisionLoss)?;
+
+        Ok(Self::new(
+            T::try_from(integer).map_err(|_| ConversionError::Overflow)?,
+            scaling_factor,
+        ))
+    }
+}
+
+/// Human-readable formatting of a rate
+///
+/// Returned by the `display_human()` method of each rate unit and of [`Generic`]. The rate is
+/// written with the largest prefix of the unit's family (`k`/`M` or `Ki`/`Mi`) that keeps the
+/// value at or above 1, followed by the class symbol (`Hz`, `bps`, `Bps` or `Bd`).
+///
+/// By default, up to 9 fractional digits are written, omitting trailing zeros. The precision of
+/// the formatter sets a fixed number of fractional digits instead. The width, fill and alignment
+/// are honoured as for strings (left-aligned by default).
+///
+/// **The value is truncated, not rounded**.
+///
+/// # Examples
+///
+/// ```rust
+/// use embedded_time::rate::*;
+///
+/// assert_eq!(format!("{}", Hertz(16_000_000_u32).display_human()), "16 MHz");
+/// assert_eq!(format!("{}", Baud(115_200_u32).display_human()), "115.2 kBd");
+/// assert_eq!(format!("{}", KibibytesPerSecond(1_536_u32).display_human()), "1.5 MiBps");
+/// assert_eq!(format!("{:.3}", Millihertz(500_u32).display_human()), "0.500 Hz");
+/// assert_eq!(format!("[{:>8}]", Hertz(16_000_000_u32).display_human()), "[  16 MHz]");
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct DisplayHuman {
+    integer: u64,
+    scaling_factor: Fraction,
+    class: Class,
+    prefixes: Prefixes,
+}
+
+impl DisplayHuman {
+    #[doc(hidden)]
+    pub fn new(integer: u64, scaling_factor: Fraction, class: Class, prefixes: Prefixes) -> Self {
+        Self {
+            integer,
+            scaling_factor,
+            class,
+            prefixes,
+        }
+    }
+
+    /// Writes the value and unit, ignoring the width, fill and alignment of the formatter
+    fn write_unpadded(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // the value in the class' base unit is numerator / denominator
+        let numerator = u128::from(self.integer) * u128::from(*self.scaling_factor.numerator());
+        let denominator =
+            u128::from(*self.scaling_factor.denominator()) * self.class.bits_per_symbol();
+        if denominator == 0 {
+            return write!(
+                f,
+                "{} x {} {}",
+                self.integer,
+                self.scaling_factor,
+                self.class.symbol()
+            );
+        }
+
+        let &(prefix, multiplier) = self
+            .prefixes
+            .table()
+            .iter()
+            .find(|&&(_, multiplier)| numerator >= multiplier * denominator)
+            .unwrap_or(&("", 1));
+
+        decimal::write(f, numerator, multiplier * denominator, 9)?;
+        write!(f, " {}{}", prefix, self.class.symbol())
+    }
+}
+
+impl fmt::Display for DisplayHuman {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        decimal::pad(f, |f| self.write_unpadded(f))
+    }
+}
+
+/// What a rate counts per second
+#[doc(hidden)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Class {
+    Frequency,
+    Bits,
+    Bytes,
+    Symbols,
+}
+
+impl Class {
+    const ALL: [Self; 4] = [Self::Frequency, Self::Bits, Self::Bytes, Self::Symbols];
+
+    fn symbol(self) -> &'static str {
+        match self {
+            Self::Frequency => "Hz",
+            Self::Bits => "bps",
+            Self::Bytes => "Bps",
+            Self::Symbols => "Bd",
+        }
+    }
+
+    /// The _scaling factors_ are in bits for data rates
+    fn bits_per_symbol(self) -> u128 {
+        match self {
+            Self::Bytes => 8,
+            _ => 1,
+        }
+    }
+
+    /// Bits and bytes can be converted into each other
+    fn is_compatible(self, other: Self) -> bool {
+        match (self, other) {
+            (Self::Bits, Self::Bytes) | (Self::Bytes, Self::Bits) => true,
+            _ => self == other,
+        }
+    }
+
+    /// The _scaling factors_ of the class' units, from coarsest to finest
+    fn scaling_factors(self) -> &'static [Fraction] {
+        const FREQUENCY: [Fraction; 9] = [
+            Mebihertz::<u32>::SCALING_FACTOR,
+            Megahertz::<u32>::SCALING_FACTOR,
+            Kibihertz::<u32>::SCALING_FACTOR,
+            Kilohertz::<u32>::SCALING_FACTOR,
+            Hertz::<u32>::SCALING_FACTOR,
+            Decihertz::<u32>::SCALING_FACTOR,
+            Centihertz::<u32>::SCALING_FACTOR,
+            Millihertz::<u32>::SCALING_FACTOR,
+            Microhertz::<u32>::SCALING_FACTOR,
+        ];
+        const BITS: [Fraction; 5] = [
+            MebibitsPerSecond::<u32>::SCALING_FACTOR,
+            MegabitsPerSecond::<u32>::SCALING_FACTOR,
+            KibibitsPerSecond::<u32>::SCALING_FACTOR,
+            KilobitsPerSecond::<u32>::SCALING_FACTOR,
+            BitsPerSecond::<u32>::SCALING_FACTOR,
+        ];
+        const BYTES: [Fraction; 5] = [
+            MebibytesPerSecond::<u32>::SCALING_FACTOR,
+            MegabytesPerSecond::<u32>::SCALING_FACTOR,
+            KibibytesPerSecond::<u32>::SCALING_FACTOR,
+            KilobytesPerSecond::<u32>::SCALING_FACTOR,
+            BytesPerSecond::<u32>::SCALING_FACTOR,
+        ];
+        const SYMBOLS: [Fraction; 5] = [
+            Mebibaud::<u32>::SCALING_FACTOR,
+            Megabaud::<u32>::SCALING_FACTOR,
+            Kibibaud::<u32>::SCALING_FACTOR,
+            Kilobaud::<u32>::SCALING_FACTOR,
+            Baud::<u32>::SCALING_FACTOR,
+        ];
+
+        match self {
+            Self::Frequency => &FREQUENCY,
+            Self::Bits => &BITS,
+            Self::Bytes => &BYTES,
+            Self::Symbols => &SYMBOLS,
+        }
+    }
+}
+
+/// The prefix family used for human-readable output
+#[doc(hidden)]
+#[derive(Copy, Clone, Debug)]
+pub enum Prefixes {
+    Decimal,
+    Binary,
+}
+
+impl Prefixes {
+    /// All prefixes, longest first so that `Mi` is matched before `M`
+    const ALL: [(&'static str, u128); 5] = [
+        ("Mi", 1_048_576),
+        ("Ki", 1_024),
+        ("M", 1_000_000),
+        ("k", 1_000),
+        ("", 1),
+    ];
+
+    /// The family of the frequency units with the _scaling factor_: binary for whole multiples
+    /// of 1,024 Hz, decimal otherwise
+    fn of(scaling_factor: &Fraction) -> Self {
+        let numerator = u128::from(*scaling_factor.numerator());
+        let denominator = u128::from(*scaling_factor.denominator());
+
+        if numerator != 0 && denominator != 0 && numerator % (1_024 * denominator) == 0 {
+            Self::Binary
+        } else {
+            Self::Decimal
+        }
+    }
+
+    /// The prefixes of the family from largest to smallest (excluding no prefix)
+    fn table(self) -> &'static [(&'static str, u128)] {
+        match self {
+            Self::Decimal => &[("M", 1_000_000), ("k", 1_000)],
+            Self::Binary => &[("Mi", 1_048_576), ("Ki", 1_024)],
+        }
+    }
+}
+
+/// Parses a rate string (see [Parsing](trait.Rate.html#parsing))
+///
+/// Returns the value, the multiplier of the prefix (in bits for data rates) and the class.
+fn parse(s: &str) -> Result<(Decimal, u128, Class), ConversionError> {
+    let (value, rest) = Decimal::split(s.trim())?;
+    let unit = rest.trim_start();
+
+    Prefixes::ALL
+        .iter()
+        .filter(|(prefix, _)| unit.starts_with(prefix))
+        .find_map(|&(prefix, multiplier)| {
+            Class::ALL
+                .iter()
+                .find(|class| &unit[prefix.len()..] == class.symbol())
+                .map(|&class| (value, multiplier * class.bits_per_symbol(), class))
+        })
+        .ok_or(ConversionError::ParseFailure)
+}
+
+/// Parses a rate string (see [Parsing](trait.Rate.html#parsing)) of a class compatible with the
+/// given one into a number of ticks of the _scaling factor_
+#[doc(hidden)]
+pub fn parse_class_ticks(
+    s: &str,
+    scaling_factor: &Fraction,
+    expected: Class,
+) -> Result<u128, ConversionError> {
+    let (value, multiplier, class) = parse(s)?;
+    if !class.is_compatible(expected) {
+        return Err(ConversionError::ConversionFailure);
+    }
+
+    value.checked_mul_ratio(
+        multiplier * u128::from(*scaling_factor.denominator()),
+        u128::from(*scaling_factor.numerator()),
+    )
+}
+
+/// Defines a frequency unit type with the given _scaling factor_ (in hertz)
+///
+/// The built-in units such as [`Kilohertz`] are generated by this macro too, so the unit has the
+/// same implementations as the built-in frequency units: [`Rate`] and [`FixedPoint`], formatting
+/// (including `display_human()`), parsing, the arithmetic operators, `const` conversions,
+/// conversions to and from [`Generic`] and [`Scaled`] rates and conversions to and from each of the
+/// built-in frequency units. Two user-defined units convert through [`Generic`].
+///
+/// Attributes (including doc comments and additional derives such as `serde`'s) are applied to the
+/// generated struct.
+///
+/// ```rust
+/// use core::convert::TryFrom;
+/// use embedded_time::{define_rate_unit, rate::*};
+///
+/// define_rate_unit!(
+///     /// Revolutions per minute
+///     pub Rpm, (1, 60)
+/// );
+///
+/// assert_eq!(Rpm::<u32>::try_from(Hertz(50_u32)), Ok(Rpm(3_000_u32)));
+/// assert_eq!(Millihertz::<u32>::try_from(Rpm(3_u32)), Ok(Millihertz(50_u32)));
+/// assert_eq!(Rpm(60_u32) + Hertz(1_u32), Rpm(120_u32));
+/// assert_eq!(format!("{}", Rpm(90_u32).display_human()), "1.5 Hz");
+/// assert_eq!("2 Hz".parse(), Ok(Rpm(120_u32)));
+/// ```
+#[macro_export]
+macro_rules! define_rate_unit {
+    (
+        @unit $(#[$attr:meta])* $vis:vis $name:ident, ($numer:expr, $denom:expr),
+        $class:ident, $prefixes:ident
+    ) => {
+        $(#[$attr])*
+        #[derive(Copy, Clone, Eq, Ord, Hash, Debug, Default)]
+        $vis struct $name<T: $crate::TimeInt = u32>(pub T);
+
+        impl<T: $crate::TimeInt> $name<T> {
+            /// See [Constructing a rate](::embedded_time::rate::Rate#constructing-a-rate)
+            pub const fn new(value: T) -> Self {
+                Self(value)
             }
 
-            impl<T: TimeInt, Rhs: Rate> ops::Sub<Rhs> for $name<T>
+            /// See [Get the integer part](::embedded_time::rate::Rate#get-the-integer-part)
+            pub const fn integer(&self) -> T {
+                self.0
+            }
+
+            /// See [Formatting](::embedded_time::rate::Rate#formatting)
+            pub fn display_human(&self) -> $crate::rate::DisplayHuman
             where
-                Self: TryFrom<Rhs>,
-                Rhs: FixedPoint,
+                u64: ::core::convert::From<T>,
             {
-                type Output = Self;
+                $crate::rate::DisplayHuman::new(
+                    self.0.into(),
+                    <Self as $crate::fixed_point::FixedPoint>::SCALING_FACTOR,
+                    $crate::rate::Class::$class,
+                    $crate::rate::Prefixes::$prefixes,
+                )
+            }
+        }
 
-                /// See [Add/Sub](trait.Rate.html#addsub)
-                fn sub(self, rhs: Rhs) -> Self::Output {
-                    <Self as FixedPoint>::sub(self, rhs)
-                }
+        impl $name<u32> {
+            $crate::impl_const_conversions![u32];
+
+            /// See [Constant evaluation](::embedded_time::rate::Rate#constant-evaluation)
+            pub const fn const_widen(self) -> $name<u64> {
+                $name(self.0 as u64)
             }
+        }
 
-            impl<T: TimeInt> ops::Mul<T> for $name<T> {
-                type Output = Self;
+        impl $name<u64> {
+            $crate::impl_const_conversions![u64];
+        }
 
-                /// See [Mul/Div](trait.Rate.html#muldiv)
-                fn mul(self, rhs: T) -> Self::Output {
-                    <Self as FixedPoint>::mul(self, rhs)
-                }
+        impl<T: $crate::TimeInt> $crate::rate::Rate for $name<T> {}
+
+        impl<T: $crate::TimeInt> $crate::fixed_point::FixedPoint for $name<T> {
+            type T = T;
+            const SCALING_FACTOR: $crate::fraction::Fraction =
+                $crate::fraction::Fraction::new($numer, $denom);
+
+            /// See [Constructing a rate](::embedded_time::rate::Rate#constructing-a-rate)
+            fn new(value: Self::T) -> Self {
+                Self(value)
+            }
+
+            /// See [Get the integer part](::embedded_time::rate::Rate#get-the-integer-part)
+            fn integer(&self) -> Self::T {
+                self.0
+            }
+        }
+
+        impl<T: $crate::TimeInt> ::core::fmt::Display for $name<T> {
+            /// See [Formatting](::embedded_time::rate::Rate#formatting)
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                ::core::fmt::Display::fmt(&self.0, f)
             }
+        }
 
-            impl<T: TimeInt> ops::Div<T> for $name<T> {
-                type Output = Self;
+        impl<T: $crate::TimeInt> ::core::str::FromStr for $name<T>
+        where
+            T: ::core::convert::TryFrom<u128>,
+        {
+            type Err = $crate::ConversionError;
+
+            /// See [Parsing](::embedded_time::rate::Rate#parsing)
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                let ticks = $crate::rate::parse_class_ticks(
+                    s,
+                    &<Self as $crate::fixed_point::FixedPoint>::SCALING_FACTOR,
+                    $crate::rate::Class::$class,
+                )?;
+                Ok(Self(
+                    <T as ::core::convert::TryFrom<u128>>::try_from(ticks)
+                        .map_err(|_| $crate::ConversionError::Overflow)?,
+                ))
+            }
+        }
+
+        impl<T: $crate::TimeInt, RhsInt: $crate::TimeInt> ::core::cmp::PartialEq<$name<RhsInt>>
+            for $name<T>
+        where
+            T: ::core::convert::TryFrom<RhsInt>,
+        {
+            /// See [Comparisons](::embedded_time::rate::Rate#comparisons)
+            fn eq(&self, rhs: &$name<RhsInt>) -> bool {
+                match <T as ::core::convert::TryFrom<RhsInt>>::try_from(rhs.0) {
+                    Ok(rhs_integer) => self.0 == rhs_integer,
+                    Err(_) => false,
+                }
+            }
+        }
 
-                /// See [Mul/Div](trait.Rate.html#muldiv)
-                fn div(self, rhs: T) -> Self::Output {
-                    <Self as FixedPoint>::div(self, rhs)
+        impl<T: $crate::TimeInt, RhsInt: $crate::TimeInt> ::core::cmp::PartialOrd<$name<RhsInt>>
+            for $name<T>
+        where
+            T: ::core::convert::TryFrom<RhsInt>,
+        {
+            /// See [Comparisons](::embedded_time::rate::Rate#comparisons)
+            fn partial_cmp(&self, rhs: &$name<RhsInt>) -> Option<::core::cmp::Ordering> {
+                match <T as ::core::convert::TryFrom<RhsInt>>::try_from(rhs.0) {
+                    Ok(rhs_integer) => Some(self.0.cmp(&rhs_integer)),
+                    Err(_) => Some(::core::cmp::Ordering::Less),
                 }
             }
+        }
+
+        impl<T: $crate::TimeInt, RhsInt: $crate::TimeInt, const NUM: u32, const DENOM: u32>
+            ::core::cmp::PartialEq<$crate::rate::Scaled<RhsInt, NUM, DENOM>> for $name<T>
+        {
+            /// See [Comparisons](::embedded_time::rate::Rate#comparisons)
+            fn eq(&self, rhs: &$crate::rate::Scaled<RhsInt, NUM, DENOM>) -> bool {
+                $crate::fixed_point::cmp_values(self, rhs) == Some(::core::cmp::Ordering::Equal)
+            }
+        }
+
+        impl<T: $crate::TimeInt, RhsInt: $crate::TimeInt, const NUM: u32, const DENOM: u32>
+            ::core::cmp::PartialEq<$name<RhsInt>> for $crate::rate::Scaled<T, NUM, DENOM>
+        {
+            /// See [Comparisons](::embedded_time::rate::Rate#comparisons)
+            fn eq(&self, rhs: &$name<RhsInt>) -> bool {
+                $crate::fixed_point::cmp_values(self, rhs) == Some(::core::cmp::Ordering::Equal)
+            }
+        }
+
+        impl<T: $crate::TimeInt, RhsInt: $crate::TimeInt, const NUM: u32, const DENOM: u32>
+            ::core::cmp::PartialOrd<$crate::rate::Scaled<RhsInt, NUM, DENOM>> for $name<T>
+        {
+            /// See [Comparisons](::embedded_time::rate::Rate#comparisons)
+            fn partial_cmp(
+                &self,
+                rhs: &$crate::rate::Scaled<RhsInt, NUM, DENOM>,
+            ) -> Option<::core::cmp::Ordering> {
+                $crate::fixed_point::cmp_values(self, rhs)
+            }
+        }
+
+        impl<T: $crate::TimeInt, RhsInt: $crate::TimeInt, const NUM: u32, const DENOM: u32>
+            ::core::cmp::PartialOrd<$name<RhsInt>> for $crate::rate::Scaled<T, NUM, DENOM>
+        {
+            /// See [Comparisons](::embedded_time::rate::Rate#comparisons)
+            fn partial_cmp(&self, rhs: &$name<RhsInt>) -> Option<::core::cmp::Ordering> {
+                $crate::fixed_point::cmp_values(self, rhs)
+            }
+        }
+
+        impl ::core::convert::From<$name<u32>> for $name<u64> {
+            /// See [Converting between `Rate`s](::embedded_time::rate::Rate#converting-between-rates)
+            fn from(source: $name<u32>) -> Self {
+                Self(u64::from(source.0))
+            }
+        }
+
+        impl ::core::convert::TryFrom<$name<u64>> for $name<u32> {
+            type Error = $crate::ConversionError;
+
+            /// See [Converting between `Rate`s](::embedded_time::rate::Rate#converting-between-rates)
+            fn try_from(source: $name<u64>) -> Result<Self, Self::Error> {
+                $crate::fixed_point::FixedPoint::from_fixed_point(source)
+            }
+        }
+
+        impl<T: $crate::TimeInt, Rhs: $crate::rate::Rate> ::core::ops::Add<Rhs> for $name<T>
+        where
+            Rhs: $crate::fixed_point::FixedPoint,
+            Self: ::core::convert::TryFrom<Rhs>,
+        {
+            type Output = Self;
+
+            /// See [Add/Sub](::embedded_time::rate::Rate#addsub)
+            fn add(self, rhs: Rhs) -> Self::Output {
+                <Self as $crate::fixed_point::FixedPoint>::add(self, rhs)
+            }
+        }
+
+        impl<T: $crate::TimeInt, Rhs: $crate::rate::Rate> ::core::ops::Sub<Rhs> for $name<T>
+        where
+            Rhs: $crate::fixed_point::FixedPoint,
+            Self: ::core::convert::TryFrom<Rhs>,
+        {
+            type Output = Self;
+
+            /// See [Add/Sub](::embedded_time::rate::Rate#addsub)
+            fn sub(self, rhs: Rhs) -> Self::Output {
+                <Self as $crate::fixed_point::FixedPoint>::sub(self, rhs)
+            }
+        }
+
+        impl<T: $crate::TimeInt> ::core::ops::Mul<T> for $name<T> {
+            type Output = Self;
+
+            /// See [Mul/Div](::embedded_time::rate::Rate#muldiv)
+            fn mul(self, rhs: T) -> Self::Output {
+                <Self as $crate::fixed_point::FixedPoint>::mul(self, rhs)
+            }
+        }
+
+        impl<T: $crate::TimeInt> ::core::ops::Div<T> for $name<T> {
+            type Output = Self;
+
+            /// See [Mul/Div](::embedded_time::rate::Rate#muldiv)
+            fn div(self, rhs: T) -> Self::Output {
+                <Self as $crate::fixed_point::FixedPoint>::div(self, rhs)
+            }
+        }
+
+        impl<T: $crate::TimeInt, Rhs: $crate::rate::Rate> ::core::ops::Rem<Rhs> for $name<T>
+        where
+            Rhs: $crate::fixed_point::FixedPoint,
+            Self: ::core::convert::TryFrom<Rhs>,
+        {
+            type Output = Self;
+
+            /// See [Remainder](::embedded_time::rate::Rate#remainder)
+            fn rem(self, rhs: Rhs) -> Self::Output {
+                <Self as $crate::fixed_point::FixedPoint>::rem(self, rhs)
+            }
+        }
+
+        impl<SourceInt: $crate::TimeInt, DestInt: $crate::TimeInt>
+            ::core::convert::TryFrom<$crate::rate::Generic<SourceInt>> for $name<DestInt>
+        where
+            DestInt: ::core::convert::TryFrom<SourceInt>,
+        {
+            type Error = $crate::ConversionError;
+
+            /// See [Converting from a `Generic`
+            /// `Rate`](::embedded_time::rate::Rate#converting-from-a-generic-rate)
+            fn try_from(generic_rate: $crate::rate::Generic<SourceInt>) -> Result<Self, Self::Error> {
+                $crate::fixed_point::FixedPoint::from_ticks(
+                    generic_rate.integer(),
+                    *generic_rate.scaling_factor(),
+                )
+            }
+        }
+
+        impl<T: $crate::TimeInt> ::core::convert::From<$name<T>> for $crate::rate::Generic<T> {
+            /// See [Converting to a `Generic`
+            /// `Rate`](::embedded_time::rate::Rate#converting-to-a-generic-rate)
+            fn from(rate: $name<T>) -> Self {
+                Self::new(
+                    rate.0,
+                    <$name<T> as $crate::fixed_point::FixedPoint>::SCALING_FACTOR,
+                )
+            }
+        }
+
+        impl<SourceInt: $crate::TimeInt, DestInt: $crate::TimeInt, const NUM: u32, const DENOM: u32>
+            ::core::convert::TryFrom<$name<SourceInt>> for $crate::rate::Scaled<DestInt, NUM, DENOM>
+        where
+            DestInt: ::core::convert::TryFrom<SourceInt>,
+        {
+            type Error = $crate::ConversionError;
+
+            /// See [Converting between `Rate`s](::embedded_time::rate::Rate#converting-between-rates)
+            fn try_from(rate: $name<SourceInt>) -> Result<Self, Self::Error> {
+                $crate::fixed_point::FixedPoint::from_fixed_point(rate)
+            }
+        }
 
-            impl<T: TimeInt, Rhs: Rate> ops::Rem<Rhs> for $name<T>
+        impl<SourceInt: $crate::TimeInt, DestInt: $crate::TimeInt, const NUM: u32, const DENOM: u32>
+            ::core::convert::TryFrom<$crate::rate::Scaled<SourceInt, NUM, DENOM>> for $name<DestInt>
+        where
+            DestInt: ::core::convert::TryFrom<SourceInt>,
+        {
+            type Error = $crate::ConversionError;
+
+            /// See [Converting between `Rate`s](::embedded_time::rate::Rate#converting-between-rates)
+            fn try_from(
+                rate: $crate::rate::Scaled<SourceInt, NUM, DENOM>,
+            ) -> Result<Self, Self::Error> {
+                rate.try_convert()
+            }
+        }
+    };
+
+    ( @conversions $name:ident, $($unit:ident),+ ) => {
+        $(
+            impl<SourceInt: $crate::TimeInt, DestInt: $crate::TimeInt>
+                ::core::convert::TryFrom<$crate::rate::$unit<SourceInt>> for $name<DestInt>
             where
-                Self: TryFrom<Rhs>,
-                Rhs: FixedPoint,
+                DestInt: ::core::convert::TryFrom<SourceInt>,
             {
-                type Output = Self;
+                type Error = $crate::ConversionError;
 
-                /// See [Remainder](trait.Rate.html#remainder)
-                fn rem(self, rhs: Rhs) -> Self::Output {
-                    <Self as FixedPoint>::rem(self, rhs)
+                /// See [Converting between
+                /// `Rate`s](::embedded_time::rate::Rate#converting-between-rates)
+                fn try_from(rate: $crate::rate::$unit<SourceInt>) -> Result<Self, Self::Error> {
+                    $crate::fixed_point::FixedPoint::from_fixed_point(rate)
                 }
             }
 
-            impl<SourceInt: TimeInt, DestInt: TimeInt> TryFrom<Generic<SourceInt>>
-                for $name<DestInt>
+            impl<SourceInt: $crate::TimeInt, DestInt: $crate::TimeInt>
+                ::core::convert::TryFrom<$name<SourceInt>> for $crate::rate::$unit<DestInt>
             where
-                DestInt: TryFrom<SourceInt>,
+                DestInt: ::core::convert::TryFrom<SourceInt>,
             {
-                type Error = ConversionError;
+                type Error = $crate::ConversionError;
 
-                /// See [Converting from a `Generic`
-                /// `Rate`](trait.Rate.html#converting-from-a-generic-rate)
-                fn try_from(generic_rate: Generic<SourceInt>) -> Result<Self, Self::Error> {
-                    fixed_point::FixedPoint::from_ticks(
-                        generic_rate.integer,
-                        generic_rate.scaling_factor,
-                    )
+                /// See [Converting between
+                /// `Rate`s](::embedded_time::rate::Rate#converting-between-rates)
+                fn try_from(rate: $name<SourceInt>) -> Result<Self, Self::Error> {
+                    $crate::fixed_point::FixedPoint::from_fixed_point(rate)
                 }
             }
+        )+
+    };
 
-            impl<T: TimeInt> From<$name<T>> for Generic<T> {
-                /// See [Converting to a `Generic`
-                /// `Rate`](trait.Rate.html#converting-to-a-generic-rate)
-                fn from(rate: $name<T>) -> Self {
-                    Self::new(rate.integer(), $name::<T>::SCALING_FACTOR)
-                }
-            }
+    ( $(#[$attr:meta])* $vis:vis $name:ident, ($numer:expr, $denom:expr) ) => {
+        $crate::define_rate_unit![
+            @unit $(#[$attr])* $vis $name, ($numer, $denom), Frequency, Decimal
+        ];
+        $crate::define_rate_unit![
+            @conversions $name,
+            Mebihertz,
+            Megahertz,
+            Kibihertz,
+            Kilohertz,
+            Hertz,
+            Decihertz,
+            Centihertz,
+            Millihertz,
+            Microhertz
+        ];
+    };
+}
+
+/// Rate-type units
+#[doc(hidden)]
+pub mod units {
+    use super::*;
+    use crate::{
+        fixed_point::{self, FixedPoint},
+        fraction::Fraction,
+        time_int::TimeInt,
+        ConversionError,
+    };
+    use core::{
+        cmp,
+        convert::TryFrom,
+        fmt::{self, Formatter},
+        ops,
+    };
+    #[doc(hidden)]
+    pub use Extensions as _;
+
+    macro_rules! impl_rate {
+        ( $name:ident, ($numer:expr, $denom:expr), $class:ident, $prefixes:ident, $desc:literal ) => {
+            crate::define_rate_unit![
+                @unit
+                #[doc = $desc]
+                pub $name, ($numer, $denom), $class, $prefixes
+            ];
         };
     }
-    impl_rate![Mebihertz, (1_048_576, 1), "Hertz × 1,048,576"];
-    impl_rate![Megahertz, (1_000_000, 1), "Hertz × 1,000,000"];
-    impl_rate![Kibihertz, (1_024, 1), "Hertz × 1,024"];
-    impl_rate![Kilohertz, (1_000, 1), "Hertz × 1,000"];
-    impl_rate![Hertz, (1, 1), "Hertz"];
-    impl_rate![Decihertz, (1, 10), "Hertz / 10"];
-    impl_rate![Centihertz, (1, 100), "Hertz / 100"];
-    impl_rate![Millihertz, (1, 1_000), "Hertz / 1000"];
-    impl_rate![Microhertz, (1, 1_000_000), "Hertz / 1,000,000"];
+    impl_rate![
+        Mebihertz,
+        (1_048_576, 1),
+        Frequency,
+        Binary,
+        "Hertz × 1,048,576"
+    ];
+    impl_rate![
+        Megahertz,
+        (1_000_000, 1),
+        Frequency,
+        Decimal,
+        "Hertz × 1,000,000"
+    ];
+    impl_rate![Kibihertz, (1_024, 1), Frequency, Binary, "Hertz × 1,024"];
+    impl_rate![Kilohertz, (1_000, 1), Frequency, Decimal, "Hertz × 1,000"];
+    impl_rate![Hertz, (1, 1), Frequency, Decimal, "Hertz"];
+    impl_rate![Decihertz, (1, 10), Frequency, Decimal, "Hertz / 10"];
+    impl_rate![Centihertz, (1, 100), Frequency, Decimal, "Hertz / 100"];
+    impl_rate![Millihertz, (1, 1_000), Frequency, Decimal, "Hertz / 1000"];
+    impl_rate![
+        Microhertz,
+        (1, 1_000_000),
+        Frequency,
+        Decimal,
+        "Hertz / 1,000,000"
+    ];
     impl_rate![
         MebibytesPerSecond,
         (1_048_576 * 8, 1),
+        Bytes,
+        Binary,
         "Bytes/s × 1,048,576"
     ];
     impl_rate![
         MegabytesPerSecond,
         (1_000_000 * 8, 1),
+        Bytes,
+        Decimal,
         "Bytes/s × 1,000,000"
     ];
-    impl_rate![KibibytesPerSecond, (1_024 * 8, 1), "Bytes/s × 1,024"];
-    impl_rate![KilobytesPerSecond, (1_000 * 8, 1), "Bytes/s × 1,000"];
-    impl_rate![BytesPerSecond, (8, 1), "Bytes/s"];
-    impl_rate![MebibitsPerSecond, (1_048_576, 1), "Bits/s × 1,048,576"];
-    impl_rate![MegabitsPerSecond, (1_000_000, 1), "Bits/s × 1,000,000"];
-    impl_rate![KibibitsPerSecond, (1_024, 1), "Bits/s × 1,024"];
-    impl_rate![KilobitsPerSecond, (1_000, 1), "Bits/s × 1,000"];
-    impl_rate![BitsPerSecond, (1, 1), "Bits/s"];
-    impl_rate![Mebibaud, (1_048_576, 1), "Baud × 1,048,576"];
-    impl_rate![Megabaud, (1_000_000, 1), "Baud × 1,000,000"];
-    impl_rate![Kibibaud, (1_024, 1), "Baud × 1,024"];
-    impl_rate![Kilobaud, (1_000, 1), "Baud × 1,000"];
-    impl_rate![Baud, (1, 1), "Baud"];
-
-    macro_rules! impl_conversion {
-        ($name:ident) => {
-            impl From<$name<u32>> for $name<u64> {
-                /// See [Converting between `Rate`s](trait.Rate.html#converting-between-rates)
-                fn from(source: $name<u32>) -> Self {
-                    Self::new(u64::from(source.integer()))
-                }
-            }
-
-            impl TryFrom<$name<u64>> for $name<u32> {
-                type Error = ConversionError;
+    impl_rate![
+        KibibytesPerSecond,
+        (1_024 * 8, 1),
+        Bytes,
+        Binary,
+        "Bytes/s × 1,024"
+    ];
+    impl_rate![
+        KilobytesPerSecond,
+        (1_000 * 8, 1),
+        Bytes,
+        Decimal,
+        "Bytes/s × 1,000"
+    ];
+    impl_rate![BytesPerSecond, (8, 1), Bytes, Decimal, "Bytes/s"];
+    impl_rate![
+        MebibitsPerSecond,
+        (1_048_576, 1),
+        Bits,
+        Binary,
+        "Bits/s × 1,048,576"
+    ];
+    impl_rate![
+        MegabitsPerSecond,
+        (1_000_000, 1),
+        Bits,
+        Decimal,
+        "Bits/s × 1,000,000"
+    ];
+    impl_rate![
+        KibibitsPerSecond,
+        (1_024, 1),
+        Bits,
+        Binary,
+        "Bits/s × 1,024"
+    ];
+    impl_rate![
+        KilobitsPerSecond,
+        (1_000, 1),
+        Bits,
+        Decimal,
+        "Bits/s × 1,000"
+    ];
+    impl_rate![BitsPerSecond, (1, 1), Bits, Decimal, "Bits/s"];
+    impl_rate![
+        Mebibaud,
+        (1_048_576, 1),
+        Symbols,
+        Binary,
+        "Baud × 1,048,576"
+    ];
+    impl_rate![
+        Megabaud,
+        (1_000_000, 1),
+        Symbols,
+        Decimal,
+        "Baud × 1,000,000"
+    ];
+    impl_rate![Kibibaud, (1_024, 1), Symbols, Binary, "Baud × 1,024"];
+    impl_rate![Kilobaud, (1_000, 1), Symbols, Decimal, "Baud × 1,000"];
+    impl_rate![Baud, (1, 1), Symbols, Decimal, "Baud"];
 
-                /// See [Converting between `Rate`s](trait.Rate.html#converting-between-rates)
-                fn try_from(source: $name<u64>) -> Result<Self, Self::Error> {
-                    fixed_point::FixedPoint::from_ticks(
-                        source.integer(),
-                        $name::<u64>::SCALING_FACTOR,
-                    )
+    #[cfg(feature = "defmt")]
+    macro_rules! impl_format {
+        ( $($name:ident, $format:literal);+ ) => {
+            $(
+                impl<T: TimeInt + defmt::Format> defmt::Format for $name<T> {
+                    fn format(&self, f: defmt::Formatter<'_>) {
+                        defmt::write!(f, $format, self.0)
+                    }
                 }
-            }
+            )+
+        };
+    }
+    #[cfg(feature = "defmt")]
+    impl_format![
+        Mebihertz, "{} MiHz";
+        Megahertz, "{} MHz";
+        Kibihertz, "{} KiHz";
+        Kilohertz, "{} kHz";
+        Hertz, "{} Hz";
+        Decihertz, "{} dHz";
+        Centihertz, "{} cHz";
+        Millihertz, "{} mHz";
+        Microhertz, "{} uHz";
+        MebibytesPerSecond, "{} MiBps";
+        MegabytesPerSecond, "{} MBps";
+        KibibytesPerSecond, "{} KiBps";
+        KilobytesPerSecond, "{} kBps";
+        BytesPerSecond, "{} Bps";
+        MebibitsPerSecond, "{} Mibps";
+        MegabitsPerSecond, "{} Mbps";
+        KibibitsPerSecond, "{} Kibps";
+        KilobitsPerSecond, "{} kbps";
+        BitsPerSecond, "{} bps";
+        Mebibaud, "{} MiBd";
+        Megabaud, "{} MBd";
+        Kibibaud, "{} KiBd";
+        Kilobaud, "{} kBd";
+        Baud, "{} Bd"
+    ];
 
-            impl<T: TimeInt, RhsInt: TimeInt> cmp::PartialEq<$name<RhsInt>> for $name<T>
-            where
-                T: TryFrom<RhsInt>,
-            {
-                /// See [Comparisons](trait.Rate.html#comparisons)
-                fn eq(&self, rhs: &$name<RhsInt>) -> bool {
-                    match T::try_from(rhs.integer()) {
-                        Ok(rhs_value) => self.integer() == rhs_value,
-                        Err(_) => false
+    #[cfg(feature = "ufmt")]
+    macro_rules! impl_ufmt {
+        ( $($name:ident),+ ) => {
+            $(
+                impl<T: TimeInt + ufmt::uDisplay> ufmt::uDisplay for $name<T> {
+                    /// See [Formatting](trait.Rate.html#formatting)
+                    fn fmt<W>(&self, f: &mut ufmt::Formatter<'_, W>) -> Result<(), W::Error>
+                    where
+                        W: ufmt::uWrite + ?Sized,
+                    {
+                        ufmt::uDisplay::fmt(&self.0, f)
                     }
                 }
-            }
 
-            impl<T: TimeInt, RhsInt: TimeInt> PartialOrd<$name<RhsInt>> for $name<T>
-            where
-                T: TryFrom<RhsInt>,
-            {
-                /// See [Comparisons](trait.Rate.html#comparisons)
-                fn partial_cmp(&self, rhs: &$name<RhsInt>) -> Option<core::cmp::Ordering> {
-                    match T::try_from(rhs.integer()) {
-                        Ok(rhs_integer) => Some(self.integer().cmp(&rhs_integer)),
-                        Err(_) => Some(core::cmp::Ordering::Less),
+                impl<T: TimeInt + ufmt::uDebug> ufmt::uDebug for $name<T> {
+                    fn fmt<W>(&self, f: &mut ufmt::Formatter<'_, W>) -> Result<(), W::Error>
+                    where
+                        W: ufmt::uWrite + ?Sized,
+                    {
+                        f.debug_tuple(stringify!($name))?.field(&self.0)?.finish()
                     }
                 }
-            }
+            )+
         };
+    }
+    #[cfg(feature = "ufmt")]
+    impl_ufmt![
+        Mebihertz,
+        Megahertz,
+        Kibihertz,
+        Kilohertz,
+        Hertz,
+        Decihertz,
+        Centihertz,
+        Millihertz,
+        Microhertz,
+        MebibytesPerSecond,
+        MegabytesPerSecond,
+        KibibytesPerSecond,
+        KilobytesPerSecond,
+        BytesPerSecond,
+        MebibitsPerSecond,
+        MegabitsPerSecond,
+        KibibitsPerSecond,
+        KilobitsPerSecond,
+        BitsPerSecond,
+        Mebibaud,
+        Megabaud,
+        Kibibaud,
+        Kilobaud,
+        Baud
+    ];
+
+    /// A frequency unit with a compile-time _scaling factor_ of `NUM / DENOM` Hz
+    ///
+    /// Complements the _named_ units with units that aren't predefined, such as a clock's
+    /// prescaled tick rate. `Scaled<T, 1_000, 1>` has the same _scaling factor_ as
+    /// [`Kilohertz<T>`].
+    ///
+    /// The _named_ units aren't type aliases of `Scaled`: an alias can't be used as a tuple-struct
+    /// constructor or pattern, so `Kilohertz(32)` would no longer compile. Instead, a `Scaled` value
+    /// compares exactly with the _named_ units and with `Scaled` units of any other _scaling
+    /// factor_.
+    ///
+    /// ```rust
+    /// use core::convert::TryFrom;
+    /// use embedded_time::rate::*;
+    ///
+    /// // multiples of 32.768 kHz
+    /// type Crystals = Scaled<u32, 32_768, 1>;
+    ///
+    /// assert_eq!(Hertz::<u32>::try_from(Crystals::new(2)), Ok(Hertz(65_536_u32)));
+    /// assert_eq!(Crystals::try_from(Kibihertz(64_u32)), Ok(Scaled(2_u32)));
+    /// assert_eq!(Hertz(1_u32) + Crystals::new(1), Hertz(32_769_u32));
+    /// assert_eq!(Crystals::new(1), Kibihertz(32_u32));
+    /// assert!(Crystals::new(1) < Scaled::<u32, 1_000, 1>(33));
+    /// assert_eq!(
+    ///     Crystals::new(3).try_convert::<Scaled<u32, 1_024, 1>>(),
+    ///     Ok(Scaled(96_u32))
+    /// );
+    /// ```
+    #[derive(Copy, Clone, Hash, Debug, Default)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub struct Scaled<T: TimeInt, const NUM: u32, const DENOM: u32>(pub T);
+
+    impl<T: TimeInt, const NUM: u32, const DENOM: u32> Eq for Scaled<T, NUM, DENOM> {}
+
+    impl<T: TimeInt, const NUM: u32, const DENOM: u32> Ord for Scaled<T, NUM, DENOM> {
+        fn cmp(&self, rhs: &Self) -> core::cmp::Ordering {
+            self.0.cmp(&rhs.0)
+        }
+    }
+
+    impl<
+            T: TimeInt,
+            RhsInt: TimeInt,
+            const NUM: u32,
+            const DENOM: u32,
+            const RHS_NUM: u32,
+            const RHS_DENOM: u32,
+        > PartialEq<Scaled<RhsInt, RHS_NUM, RHS_DENOM>> for Scaled<T, NUM, DENOM>
+    {
+        /// See [Comparisons](trait.Rate.html#comparisons)
+        fn eq(&self, rhs: &Scaled<RhsInt, RHS_NUM, RHS_DENOM>) -> bool {
+            self.partial_cmp(rhs) == Some(core::cmp::Ordering::Equal)
+        }
+    }
+
+    impl<
+            T: TimeInt,
+            RhsInt: TimeInt,
+            const NUM: u32,
+            const DENOM: u32,
+            const RHS_NUM: u32,
+            const RHS_DENOM: u32,
+        > PartialOrd<Scaled<RhsInt, RHS_NUM, RHS_DENOM>> for Scaled<T, NUM, DENOM>
+    {
+        /// See [Comparisons](trait.Rate.html#comparisons)
+        fn partial_cmp(
+            &self,
+            rhs: &Scaled<RhsInt, RHS_NUM, RHS_DENOM>,
+        ) -> Option<core::cmp::Ordering> {
+            if NUM == RHS_NUM && DENOM == RHS_DENOM {
+                Some(self.0.into_u128().cmp(&rhs.0.into_u128()))
+            } else {
+                fixed_point::cmp_values(self, rhs)
+            }
+        }
+    }
+
+    impl<T: TimeInt, const NUM: u32, const DENOM: u32> Scaled<T, NUM, DENOM> {
+        /// See [Constructing a rate](trait.Rate.html#constructing-a-rate)
+        pub const fn new(value: T) -> Self {
+            Self(value)
+        }
+
+        /// See [Get the integer part](trait.Rate.html#get-the-integer-part)
+        pub const fn integer(&self) -> T {
+            self.0
+        }
+
+        /// See [Formatting](trait.Rate.html#formatting)
+        pub fn display_human(&self) -> DisplayHuman
+        where
+            u64: From<T>,
+        {
+            DisplayHuman::new(
+                self.0.into(),
+                Self::SCALING_FACTOR,
+                Class::Frequency,
+                Prefixes::of(&Self::SCALING_FACTOR),
+            )
+        }
+
+        /// Converts into another frequency unit (_named_ or _scaled_)
+        ///
+        /// # Errors
+        ///
+        /// See [Converting between `Rate`s](trait.Rate.html#converting-between-rates)
+        pub fn try_convert<Dest>(self) -> Result<Dest, ConversionError>
+        where
+            Dest: Rate + FixedPoint,
+            Dest::T: TryFrom<T>,
+        {
+            FixedPoint::from_fixed_point(self)
+        }
+    }
+
+    impl<const NUM: u32, const DENOM: u32> Scaled<u32, NUM, DENOM> {
+        crate::impl_const_conversions![u32];
+
+        /// See [Constant evaluation](trait.Rate.html#constant-evaluation)
+        pub const fn const_widen(self) -> Scaled<u64, NUM, DENOM> {
+            Scaled(self.0 as u64)
+        }
+    }
+
+    impl<const NUM: u32, const DENOM: u32> Scaled<u64, NUM, DENOM> {
+        crate::impl_const_conversions![u64];
+    }
+
+    impl<T: TimeInt, const NUM: u32, const DENOM: u32> Rate for Scaled<T, NUM, DENOM> {}
+
+    impl<T: TimeInt, const NUM: u32, const DENOM: u32> FixedPoint for Scaled<T, NUM, DENOM> {
+        type T = T;
+        const SCALING_FACTOR: Fraction = Fraction::new(NUM as u64, DENOM as u64);
+
+        /// See [Constructing a rate](trait.Rate.html#constructing-a-rate)
+        fn new(value: Self::T) -> Self {
+            Self(value)
+        }
+
+        /// See [Get the integer part](trait.Rate.html#get-the-integer-part)
+        fn integer(&self) -> Self::T {
+            self.0
+        }
+    }
+
+    impl<T: TimeInt, const NUM: u32, const DENOM: u32> fmt::Display for Scaled<T, NUM, DENOM> {
+        /// See [Formatting](trait.Rate.html#formatting)
+        fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+            fmt::Display::fmt(&self.0, f)
+        }
+    }
+
+    #[cfg(feature = "defmt")]
+    impl<T: TimeInt + defmt::Format, const NUM: u32, const DENOM: u32> defmt::Format
+        for Scaled<T, NUM, DENOM>
+    {
+        fn format(&self, f: defmt::Formatter<'_>) {
+            defmt::write!(f, "{} x {}/{} Hz", self.0, NUM, DENOM)
+        }
+    }
+
+    #[cfg(feature = "ufmt")]
+    impl<T: TimeInt + ufmt::uDisplay, const NUM: u32, const DENOM: u32> ufmt::uDisplay
+        for Scaled<T, NUM, DENOM>
+    {
+        /// See [Formatting](trait.Rate.html#formatting)
+        fn fmt<W>(&self, f: &mut ufmt::Formatter<'_, W>) -> Result<(), W::Error>
+        where
+            W: ufmt::uWrite + ?Sized,
+        {
+            ufmt::uDisplay::fmt(&self.0, f)
+        }
+    }
+
+    #[cfg(feature = "ufmt")]
+    impl<T: TimeInt + ufmt::uDebug, const NUM: u32, const DENOM: u32> ufmt::uDebug
+        for Scaled<T, NUM, DENOM>
+    {
+        fn fmt<W>(&self, f: &mut ufmt::Formatter<'_, W>) -> Result<(), W::Error>
+        where
+            W: ufmt::uWrite + ?Sized,
+        {
+            f.debug_tuple("Scaled")?.field(&self.0)?.finish()
+        }
+    }
+
+    impl<T: TimeInt, const NUM: u32, const DENOM: u32> FromStr for Scaled<T, NUM, DENOM>
+    where
+        T: TryFrom<u128>,
+    {
+        type Err = ConversionError;
+
+        /// See [Parsing](trait.Rate.html#parsing)
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            let ticks = parse_class_ticks(s, &Self::SCALING_FACTOR, Class::Frequency)?;
+            Ok(Self(
+                T::try_from(ticks).map_err(|_| ConversionError::Overflow)?,
+            ))
+        }
+    }
+
+    impl<T: TimeInt, Rhs: Rate, const NUM: u32, const DENOM: u32> ops::Add<Rhs>
+        for Scaled<T, NUM, DENOM>
+    where
+        Rhs: FixedPoint,
+        Self: TryFrom<Rhs>,
+    {
+        type Output = Self;
+
+        /// See [Add/Sub](trait.Rate.html#addsub)
+        fn add(self, rhs: Rhs) -> Self::Output {
+            <Self as FixedPoint>::add(self, rhs)
+        }
+    }
+
+    impl<T: TimeInt, Rhs: Rate, const NUM: u32, const DENOM: u32> ops::Sub<Rhs>
+        for Scaled<T, NUM, DENOM>
+    where
+        Rhs: FixedPoint,
+        Self: TryFrom<Rhs>,
+    {
+        type Output = Self;
+
+        /// See [Add/Sub](trait.Rate.html#addsub)
+        fn sub(self, rhs: Rhs) -> Self::Output {
+            <Self as FixedPoint>::sub(self, rhs)
+        }
+    }
+
+    impl<T: TimeInt, const NUM: u32, const DENOM: u32> ops::Mul<T> for Scaled<T, NUM, DENOM> {
+        type Output = Self;
+
+        /// See [Mul/Div](trait.Rate.html#muldiv)
+        fn mul(self, rhs: T) -> Self::Output {
+            <Self as FixedPoint>::mul(self, rhs)
+        }
+    }
+
+    impl<T: TimeInt, const NUM: u32, const DENOM: u32> ops::Div<T> for Scaled<T, NUM, DENOM> {
+        type Output = Self;
+
+        /// See [Mul/Div](trait.Rate.html#muldiv)
+        fn div(self, rhs: T) -> Self::Output {
+            <Self as FixedPoint>::div(self, rhs)
+        }
+    }
+
+    impl<T: TimeInt, Rhs: Rate, const NUM: u32, const DENOM: u32> ops::Rem<Rhs>
+        for Scaled<T, NUM, DENOM>
+    where
+        Rhs: FixedPoint,
+        Self: TryFrom<Rhs>,
+    {
+        type Output = Self;
+
+        /// See [Remainder](trait.Rate.html#remainder)
+        fn rem(self, rhs: Rhs) -> Self::Output {
+            <Self as FixedPoint>::rem(self, rhs)
+        }
+    }
+
+    impl<SourceInt: TimeInt, DestInt: TimeInt, const NUM: u32, const DENOM: u32>
+        TryFrom<Generic<SourceInt>> for Scaled<DestInt, NUM, DENOM>
+    where
+        DestInt: TryFrom<SourceInt>,
+    {
+        type Error = ConversionError;
+
+        /// See [Converting from a `Generic`
+        /// `Rate`](trait.Rate.html#converting-from-a-generic-rate)
+        fn try_from(generic_rate: Generic<SourceInt>) -> Result<Self, Self::Error> {
+            fixed_point::FixedPoint::from_ticks(generic_rate.integer, generic_rate.scaling_factor)
+        }
+    }
+
+    impl<T: TimeInt, const NUM: u32, const DENOM: u32> From<Scaled<T, NUM, DENOM>> for Generic<T> {
+        /// See [Converting to a `Generic`
+        /// `Rate`](trait.Rate.html#converting-to-a-generic-rate)
+        fn from(rate: Scaled<T, NUM, DENOM>) -> Self {
+            Self::new(rate.integer(), Scaled::<T, NUM, DENOM>::SCALING_FACTOR)
+        }
+    }
+
+    macro_rules! impl_conversion {
+        ($name:ident) => {};
 
         (once, $big:ident, $small:ident) => {
             impl<T: TimeInt> From<$small<T>> for $big<T>
             {
                 /// See [Converting between `Rate`s](trait.Rate.html#converting-between-rates)
                 fn from(small: $small<T>) -> Self {
-                    if let Ok(v) = fixed_point::FixedPoint::from_ticks(small.integer(), $small::<T>::SCALING_FACTOR) {
+                    if let Ok(v) = fixed_point::FixedPoint::from_fixed_point(small) {
                         v
                     } else {
                         panic!("From failed")
@@ -611,7 +1959,7 @@ pub mod units {
             {
                 /// See [Converting between `Rate`s](trait.Rate.html#converting-between-rates)
                 fn from(small: $small<u32>) -> Self {
-                    if let Ok(v) = fixed_point::FixedPoint::from_ticks(small.integer(), $small::<u32>::SCALING_FACTOR) {
+                    if let Ok(v) = fixed_point::FixedPoint::from_fixed_point(small) {
                         v
                     } else {
                         panic!("From failed")
@@ -625,10 +1973,7 @@ pub mod units {
 
                 /// See [Converting between `Rate`s](trait.Rate.html#converting-between-rates)
                 fn try_from(small: $small<u64>) -> Result<Self, Self::Error> {
-                    fixed_point::FixedPoint::from_ticks(
-                        small.integer(),
-                        $small::<u64>::SCALING_FACTOR,
-                    )
+                    fixed_point::FixedPoint::from_fixed_point(small)
                 }
             }
 
@@ -637,7 +1982,7 @@ pub mod units {
             {
                /// See [Converting between `Rate`s](trait.Rate.html#converting-between-rates)
                 fn from(big: $big<u32>) -> Self {
-                    if let Ok(v) = fixed_point::FixedPoint::from_ticks(big.integer(), $big::<u32>::SCALING_FACTOR) {
+                    if let Ok(v) = fixed_point::FixedPoint::from_fixed_point(big) {
                         v
                     } else {
                         panic!("From failed")
@@ -651,10 +1996,7 @@ pub mod units {
 
                 /// See [Converting between `Rate`s](trait.Rate.html#converting-between-rates)
                 fn try_from(big: $big<T>) -> Result<Self, Self::Error> {
-                    fixed_point::FixedPoint::from_ticks(
-                        big.integer(),
-                        $big::<T>::SCALING_FACTOR,
-                    )
+                    fixed_point::FixedPoint::from_fixed_point(big)
                 }
             }
 
@@ -664,10 +2006,7 @@ pub mod units {
 
                 /// See [Converting between `Rate`s](trait.Rate.html#converting-between-rates)
                 fn try_from(big: $big<u64>) -> Result<Self, Self::Error> {
-                    fixed_point::FixedPoint::from_ticks(
-                        big.integer(),
-                        $big::<u64>::SCALING_FACTOR,
-                    )
+                    fixed_point::FixedPoint::from_fixed_point(big)
                 }
             }
 
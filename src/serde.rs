@@ -0,0 +1,274 @@
+//! [`serde`](https://docs.rs/serde) support for the duration and rate types
+//!
+//! In human-readable formats (eg. JSON), the named units serialize as a string of their value
+//! followed by their unit, such as `"250ms"` or `"16MHz"`, and deserialize from any string
+//! accepted by their [`FromStr`] implementation (eg. `"1.5s"` into [`Milliseconds`]). In compact
+//! formats (eg. postcard), they serialize as a newtype struct named after the unit that holds
+//! the integer.
+//!
+//! [`Scaled`](duration::Scaled) units and [`Ticks`] serialize as their integer in both forms as
+//! their _scaling factor_ is part of the type. The [`Generic`](duration::Generic) types serialize
+//! as a struct of their integer and _scaling factor_. A [`Fraction`] serializes as `"1/1000"` in
+//! human-readable formats and as a `(numerator, denominator)` tuple otherwise.
+
+use crate::{
+    duration::{self, *},
+    fraction::Fraction,
+    rate::{self, *},
+    time_int::TimeInt,
+    ConversionError,
+};
+use core::{convert::TryFrom, fmt, marker::PhantomData, str::FromStr};
+use serde::{
+    de::{self, Deserialize, Deserializer, Unexpected, Visitor},
+    ser::{Serialize, Serializer},
+};
+
+/// A value written as `numerator / denominator` in decimal followed by a unit
+struct Human<'a> {
+    numerator: u128,
+    denominator: u128,
+    unit: &'a str,
+}
+
+impl fmt::Display for Human<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        crate::decimal::write(f, self.numerator, self.denominator, 9)?;
+        f.write_str(self.unit)
+    }
+}
+
+/// Serializes a named unit as a string or as a newtype struct depending on the format
+fn serialize_named<S: Serializer, T: Serialize>(
+    serializer: S,
+    name: &'static str,
+    integer: &T,
+    human: Human<'_>,
+) -> Result<S::Ok, S::Error> {
+    if serializer.is_human_readable() {
+        serializer.collect_str(&human)
+    } else {
+        serializer.serialize_newtype_struct(name, integer)
+    }
+}
+
+/// Deserializes a named unit from a string or from a newtype struct depending on the format
+fn deserialize_named<'de, D, U, T>(
+    deserializer: D,
+    name: &'static str,
+    new: fn(T) -> U,
+) -> Result<U, D::Error>
+where
+    D: Deserializer<'de>,
+    U: FromStr<Err = ConversionError>,
+    T: Deserialize<'de>,
+{
+    if deserializer.is_human_readable() {
+        deserializer.deserialize_str(ParseVisitor(PhantomData))
+    } else {
+        deserializer
+            .deserialize_newtype_struct(name, NewtypeVisitor(PhantomData))
+            .map(new)
+    }
+}
+
+/// Deserializes the integer of a newtype struct
+struct NewtypeVisitor<T>(PhantomData<T>);
+
+impl<'de, T: Deserialize<'de>> Visitor<'de> for NewtypeVisitor<T> {
+    type Value = T;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a unit holding an integer")
+    }
+
+    fn visit_newtype_struct<D: Deserializer<'de>>(self, d: D) -> Result<Self::Value, D::Error> {
+        T::deserialize(d)
+    }
+
+    fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        seq.next_element()?
+            .ok_or_else(|| de::Error::invalid_length(0, &self))
+    }
+}
+
+/// Deserializes a value from a string using its [`FromStr`] implementation
+struct ParseVisitor<U>(PhantomData<U>);
+
+impl<'de, U: FromStr<Err = ConversionError>> Visitor<'de> for ParseVisitor<U> {
+    type Value = U;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a value followed by a unit")
+    }
+
+    fn visit_str<E: de::Error>(self, s: &str) -> Result<Self::Value, E> {
+        s.parse()
+            .map_err(|_| de::Error::invalid_value(Unexpected::Str(s), &self))
+    }
+}
+
+macro_rules! impl_serde {
+    ( $($name:ident, $unit:literal, ($numer:expr, $denom:expr));+ ) => {
+        $(
+            impl<T: TimeInt + Serialize> Serialize for $name<T>
+            where
+                u128: From<T>,
+            {
+                fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    serialize_named(
+                        serializer,
+                        stringify!($name),
+                        &self.0,
+                        Human {
+                            numerator: u128::from(self.0) * $numer,
+                            denominator: $denom,
+                            unit: $unit,
+                        },
+                    )
+                }
+            }
+
+            impl<'de, T: TimeInt + Deserialize<'de>> Deserialize<'de> for $name<T>
+            where
+                T: TryFrom<u128>,
+            {
+                fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    deserialize_named(deserializer, stringify!($name), $name)
+                }
+            }
+        )+
+    };
+}
+impl_serde![
+    Weeks, "w", (1, 1);
+    Days, "d", (1, 1);
+    Hours, "h", (1, 1);
+    Minutes, "min", (1, 1);
+    Seconds, "s", (1, 1);
+    Milliseconds, "ms", (1, 1);
+    Microseconds, "us", (1, 1);
+    Nanoseconds, "ns", (1, 1);
+    Picoseconds, "ps", (1, 1);
+
+    Mebihertz, "MiHz", (1, 1);
+    Megahertz, "MHz", (1, 1);
+    Kibihertz, "KiHz", (1, 1);
+    Kilohertz, "kHz", (1, 1);
+    Hertz, "Hz", (1, 1);
+    // sub-hertz units are written as decimal hertz
+    Decihertz, "Hz", (1, 10);
+    Centihertz, "Hz", (1, 100);
+    Millihertz, "Hz", (1, 1_000);
+    Microhertz, "Hz", (1, 1_000_000);
+    MebibytesPerSecond, "MiBps", (1, 1);
+    MegabytesPerSecond, "MBps", (1, 1);
+    KibibytesPerSecond, "KiBps", (1, 1);
+    KilobytesPerSecond, "kBps", (1, 1);
+    BytesPerSecond, "Bps", (1, 1);
+    MebibitsPerSecond, "Mibps", (1, 1);
+    MegabitsPerSecond, "Mbps", (1, 1);
+    KibibitsPerSecond, "Kibps", (1, 1);
+    KilobitsPerSecond, "kbps", (1, 1);
+    BitsPerSecond, "bps", (1, 1);
+    Mebibaud, "MiBd", (1, 1);
+    Megabaud, "MBd", (1, 1);
+    Kibibaud, "KiBd", (1, 1);
+    Kilobaud, "kBd", (1, 1);
+    Baud, "Bd", (1, 1)
+];
+
+impl<Clock: crate::Clock> Serialize for Ticks<Clock>
+where
+    Clock::T: Serialize,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_newtype_struct("Ticks", &self.integer())
+    }
+}
+
+impl<'de, Clock: crate::Clock> Deserialize<'de> for Ticks<Clock>
+where
+    Clock::T: Deserialize<'de>,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer
+            .deserialize_newtype_struct("Ticks", NewtypeVisitor(PhantomData))
+            .map(Self::new)
+    }
+}
+
+/// The serialized form of both `Generic` types
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(rename = "Generic")]
+struct GenericRepr<T> {
+    integer: T,
+    scaling_factor: Fraction,
+}
+
+macro_rules! impl_generic {
+    ($module:ident) => {
+        impl<T: TimeInt + Serialize> Serialize for $module::Generic<T> {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                GenericRepr {
+                    integer: self.integer(),
+                    scaling_factor: *self.scaling_factor(),
+                }
+                .serialize(serializer)
+            }
+        }
+
+        impl<'de, T: TimeInt + Deserialize<'de>> Deserialize<'de> for $module::Generic<T> {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let repr = GenericRepr::deserialize(deserializer)?;
+                Ok(Self::new(repr.integer, repr.scaling_factor))
+            }
+        }
+    };
+}
+impl_generic!(duration);
+impl_generic!(rate);
+
+impl Serialize for Fraction {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.collect_str(self)
+        } else {
+            (self.numerator(), self.denominator()).serialize(serializer)
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Fraction {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            return deserializer.deserialize_str(FractionVisitor);
+        }
+
+        let (numerator, denominator) = <(u64, u64)>::deserialize(deserializer)?;
+        if denominator == 0 {
+            return Err(de::Error::invalid_value(
+                Unexpected::Unsigned(0),
+                &"a non-zero denominator",
+            ));
+        }
+        Ok(Self::new(numerator, denominator))
+    }
+}
+
+/// Deserializes a `"numerator/denominator"` string using the [`FromStr`] implementation of
+/// [`Fraction`]
+struct FractionVisitor;
+
+impl<'de> Visitor<'de> for FractionVisitor {
+    type Value = Fraction;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a fraction such as \"1/1000\"")
+    }
+
+    fn visit_str<E: de::Error>(self, s: &str) -> Result<Self::Value, E> {
+        s.parse()
+            .map_err(|_| de::Error::invalid_value(Unexpected::Str(s), &self))
+    }
+}
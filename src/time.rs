@@ -0,0 +1,138 @@
+//! Interoperability with the [`time`](https://docs.rs/time) crate
+//!
+//! With the `time` feature, the duration units convert to [`time::Duration`] and an [`Instant`]
+//! converts to a [`time::OffsetDateTime`] given the date and time of its clock's epoch. The
+//! conversions use the same fixed-point math as the rest of the crate and are truncated to
+//! nanoseconds.
+
+use crate::{
+    duration::{self, *},
+    fixed_point::FixedPoint,
+    time_int::TimeInt,
+    ConversionError, Instant,
+};
+use core::convert::TryFrom;
+use time::OffsetDateTime;
+
+/// Converts a number of ticks of the _scaling factor_ into a `time` duration
+fn to_time_duration(
+    ticks: u64,
+    scaling_factor: &Fraction,
+) -> Result<time::Duration, ConversionError> {
+    time::Duration::try_from(duration::ticks_to_core_duration(ticks, scaling_factor)?)
+        .map_err(|_| ConversionError::Overflow)
+}
+
+impl<Clock: crate::Clock> Instant<Clock>
+where
+    u64: From<Clock::T>,
+{
+    /// Returns the date and time of the instant given that of the clock's epoch (`0` ticks)
+    ///
+    /// ```rust
+    /// use embedded_time::{fraction::Fraction, Instant};
+    /// use time::{Date, Month, PrimitiveDateTime, Time};
+    /// # #[derive(Debug)]
+    /// struct Clock;
+    /// impl embedded_time::Clock for Clock {
+    ///     type T = u32;
+    ///     const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+    ///     // ...
+    /// # fn try_now(&self) -> Result<Instant<Self>, embedded_time::clock::Error> {unimplemented!()}
+    /// }
+    ///
+    /// let date = Date::from_calendar_date(2021, Month::October, 2).unwrap();
+    /// let boot = PrimitiveDateTime::new(date, Time::from_hms(12, 0, 0).unwrap()).assume_utc();
+    ///
+    /// assert_eq!(
+    ///     Instant::<Clock>::new(90_500).to_offset_date_time(boot),
+    ///     Ok(PrimitiveDateTime::new(date, Time::from_hms_milli(12, 1, 30, 500).unwrap())
+    ///         .assume_utc())
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// [`ConversionError::Overflow`] : The date and time is out of the range of
+    /// [`time::OffsetDateTime`].
+    pub fn to_offset_date_time(
+        &self,
+        epoch: OffsetDateTime,
+    ) -> Result<OffsetDateTime, ConversionError> {
+        let since_epoch = self.duration_since_epoch();
+        epoch
+            .checked_add(to_time_duration(
+                since_epoch.integer().into(),
+                since_epoch.scaling_factor(),
+            )?)
+            .ok_or(ConversionError::Overflow)
+    }
+}
+
+macro_rules! impl_time {
+    ($($name:ident),+) => {
+        $(
+            impl<T: TimeInt> TryFrom<$name<T>> for time::Duration
+            where
+                u64: From<T>,
+            {
+                type Error = ConversionError;
+
+                /// The duration is truncated to nanoseconds
+                fn try_from(duration: $name<T>) -> Result<Self, Self::Error> {
+                    to_time_duration(duration.integer().into(), &$name::<T>::SCALING_FACTOR)
+                }
+            }
+        )+
+    };
+}
+impl_time![
+    Weeks,
+    Days,
+    Hours,
+    Minutes,
+    Seconds,
+    Milliseconds,
+    Microseconds,
+    Nanoseconds,
+    Picoseconds
+];
+
+impl<T: TimeInt, const NUM: u32, const DENOM: u32> TryFrom<Scaled<T, NUM, DENOM>> for time::Duration
+where
+    u64: From<T>,
+{
+    type Error = ConversionError;
+
+    /// The duration is truncated to nanoseconds
+    fn try_from(duration: Scaled<T, NUM, DENOM>) -> Result<Self, Self::Error> {
+        to_time_duration(
+            duration.integer().into(),
+            &Scaled::<T, NUM, DENOM>::SCALING_FACTOR,
+        )
+    }
+}
+
+impl<Clock: crate::Clock> TryFrom<Ticks<Clock>> for time::Duration
+where
+    u64: From<Clock::T>,
+{
+    type Error = ConversionError;
+
+    /// The duration is truncated to nanoseconds
+    fn try_from(duration: Ticks<Clock>) -> Result<Self, Self::Error> {
+        to_time_duration(duration.integer().into(), &Clock::SCALING_FACTOR)
+    }
+}
+
+impl<T: TimeInt> TryFrom<Generic<T>> for time::Duration
+where
+    u64: From<T>,
+{
+    type Error = ConversionError;
+
+    /// The duration is truncated to nanoseconds
+    fn try_from(duration: Generic<T>) -> Result<Self, Self::Error> {
+        to_time_duration(duration.integer().into(), duration.scaling_factor())
+    }
+}
@@ -1,5 +1,5 @@
-use crate::fraction::Fraction;
-use core::{fmt, ops};
+use crate::{fraction::Fraction, Rounding};
+use core::{convert::TryFrom, fmt, hash::Hash, ops};
 
 /// The core inner-type trait for time-related types
 pub trait TimeInt:
@@ -18,28 +18,196 @@ pub trait TimeInt:
     + fmt::Display
     + fmt::Debug
 {
+    /// Returns the integer widened to a [`u128`]
+    ///
+    /// The default implementation only uses the operations of the supertraits (16 bits at a time)
+    /// and requires the value to fit in a [`u128`]. `u32` and `u64` convert directly.
+    #[doc(hidden)]
+    fn into_u128(self) -> u128 {
+        let radix = Self::from(1 << 16);
+        let (mut value, mut wide, mut shift) = (self, 0_u128, 0);
+        while !value.is_zero() {
+            let (quotient, mut digit) = value.div_rem(&radix);
+            for bit in (0..16).rev() {
+                let bit_value = Self::from(1 << bit);
+                if digit >= bit_value {
+                    digit = digit - bit_value;
+                    wide |= 1 << (shift + bit);
+                }
+            }
+            value = quotient;
+            shift += 16;
+        }
+        wide
+    }
+
+    /// Returns the integer equal to the [`u128`] or [`None`] if it doesn't fit
+    ///
+    /// The default implementation only uses the operations of the supertraits (16 bits at a
+    /// time). `u32` and `u64` convert directly.
+    #[doc(hidden)]
+    fn try_from_u128(value: u128) -> Option<Self> {
+        let radix = Self::from(1 << 16);
+        (0..8).rev().try_fold(Self::zero(), |integer, digit| {
+            let digit = Self::from((value >> (16 * digit)) as u32 & 0xffff);
+            integer.checked_mul(&radix)?.checked_add(&digit)
+        })
+    }
+
     /// Checked integer × [`Fraction`] = integer
     ///
+    /// The product is computed in a [`u128`] so only a result that doesn't fit fails.
+    ///
     /// Returns truncated (rounded toward `0`) integer or [`None`] upon failure
     fn checked_mul_fraction(&self, fraction: &Fraction) -> Option<Self> {
-        self.checked_mul(&(*fraction.numerator()).into())?
-            .checked_div(&(*fraction.denominator()).into())
+        ratio_of(
+            *self,
+            *fraction.numerator(),
+            *fraction.denominator(),
+            Rounding::Floor,
+        )
     }
 
     /// Checked integer / [`Fraction`] = integer
     ///
+    /// The product is computed in a [`u128`] so only a result that doesn't fit fails.
+    ///
     /// Returns truncated (rounded toward `0`) integer or [`None`] upon failure
     fn checked_div_fraction(&self, fraction: &Fraction) -> Option<Self> {
-        self.checked_mul_fraction(&fraction.recip())
+        ratio_of(
+            *self,
+            *fraction.denominator(),
+            *fraction.numerator(),
+            Rounding::Floor,
+        )
     }
+
+    /// Checked integer × [`Fraction`] = integer, rounded according to the [`Rounding`] mode
+    ///
+    /// Returns [`None`] upon failure
+    fn checked_mul_fraction_rounded(
+        &self,
+        fraction: &Fraction,
+        rounding: Rounding,
+    ) -> Option<Self> {
+        ratio_of(
+            *self,
+            *fraction.numerator(),
+            *fraction.denominator(),
+            rounding,
+        )
+    }
+
+    /// Checked integer / [`Fraction`] = integer, rounded according to the [`Rounding`] mode
+    ///
+    /// Returns [`None`] upon failure
+    fn checked_div_fraction_rounded(
+        &self,
+        fraction: &Fraction,
+        rounding: Rounding,
+    ) -> Option<Self> {
+        ratio_of(
+            *self,
+            *fraction.denominator(),
+            *fraction.numerator(),
+            rounding,
+        )
+    }
+}
+
+/// Returns `integer × numerator / denominator` rounded according to the [`Rounding`] mode
+///
+/// The product of a [`TimeInt`] and a [`u64`] always fits in a [`u128`]. The division is performed
+/// in a [`u64`] when the product allows (eg. [`u32`] _integers_ and small fractions) and in a
+/// [`u128`] otherwise. Returns [`None`] if the denominator is `0` or the result doesn't fit.
+fn ratio_of<T: TimeInt>(
+    integer: T,
+    numerator: u64,
+    denominator: u64,
+    rounding: Rounding,
+) -> Option<T> {
+    let product = integer.into_u128() * u128::from(numerator);
+    let quotient = match u64::try_from(product) {
+        Ok(product) => rounding.div(product, denominator).map(u128::from),
+        Err(_) => rounding.div(product, u128::from(denominator)),
+    }?;
+    T::try_from_u128(quotient)
 }
 
-impl TimeInt for u32 {}
-impl TimeInt for u64 {}
+macro_rules! impl_time_int {
+    ($int:ty) => {
+        impl TimeInt for $int {
+            fn into_u128(self) -> u128 {
+                self.into()
+            }
+
+            fn try_from_u128(value: u128) -> Option<Self> {
+                Self::try_from(value).ok()
+            }
+        }
+    };
+}
+impl_time_int![u32];
+impl_time_int![u64];
+
+/// A [`TimeInt`] with a signed counterpart of the same width (eg. `i32` for `u32`)
+///
+/// Used as the _integer_ of a [`SignedDuration`](crate::duration::SignedDuration)
+pub trait SignedTimeInt: TimeInt {
+    /// The signed integer type of the same width
+    type Signed: Copy
+        + Ord
+        + Hash
+        + Default
+        + num::Signed
+        + num::Bounded
+        + num::CheckedAdd
+        + num::CheckedSub
+        + num::CheckedMul
+        + num::CheckedDiv
+        + num::traits::CheckedNeg
+        + fmt::Display
+        + fmt::Debug;
+
+    /// Returns the magnitude of the signed value (never fails, even for the minimum value)
+    fn unsigned_abs(value: Self::Signed) -> Self;
+
+    /// Returns the signed value with the given magnitude and sign or [`None`] if it doesn't fit
+    fn checked_from_magnitude(magnitude: Self, negative: bool) -> Option<Self::Signed>;
+}
+
+macro_rules! impl_signed_time_int {
+    ($unsigned:ty, $signed:ty) => {
+        impl SignedTimeInt for $unsigned {
+            type Signed = $signed;
+
+            fn unsigned_abs(value: Self::Signed) -> Self {
+                value.wrapping_abs() as Self
+            }
+
+            fn checked_from_magnitude(magnitude: Self, negative: bool) -> Option<Self::Signed> {
+                if !negative {
+                    <$signed>::try_from(magnitude).ok()
+                } else if magnitude <= <$signed>::max_value() as Self + 1 {
+                    // the magnitude of the minimum value wraps to the minimum value itself
+                    Some((magnitude as $signed).wrapping_neg())
+                } else {
+                    None
+                }
+            }
+        }
+    };
+}
+impl_signed_time_int![u32, i32];
+impl_signed_time_int![u64, i64];
 
 #[cfg(test)]
 mod tests {
-    use crate::{fraction::Fraction, time_int::TimeInt};
+    use crate::{
+        fraction::Fraction,
+        time_int::{SignedTimeInt, TimeInt},
+        Rounding,
+    };
 
     #[test]
     fn checked_integer_mul_fraction() {
@@ -68,4 +236,83 @@ mod tests {
             Some(2_u32)
         );
     }
+
+    #[test]
+    fn checked_integer_mul_fraction_wide_product() {
+        // the product fits in a u64
+        assert_eq!(
+            u32::MAX.checked_mul_fraction(&Fraction::new(1_000, 1_000_000)),
+            Some(4_294_967)
+        );
+
+        // the product only fits in a u128
+        assert_eq!(
+            u32::MAX.checked_mul_fraction(&Fraction::new(u64::MAX, u64::MAX)),
+            Some(u32::MAX)
+        );
+        assert_eq!(
+            u64::MAX.checked_div_fraction(&Fraction::new(3, 2)),
+            Some(u64::MAX / 3 * 2)
+        );
+    }
+
+    #[test]
+    fn checked_from_magnitude() {
+        assert_eq!(u32::checked_from_magnitude(5, false), Some(5_i32));
+        assert_eq!(u32::checked_from_magnitude(5, true), Some(-5_i32));
+        assert_eq!(u32::checked_from_magnitude(1 << 31, true), Some(i32::MIN));
+        assert_eq!(u32::checked_from_magnitude(1 << 31, false), None);
+        assert_eq!(u64::checked_from_magnitude(u64::MAX, true), None);
+        assert_eq!(u64::unsigned_abs(i64::MIN), 1 << 63);
+    }
+
+    #[test]
+    fn checked_integer_mul_fraction_rounded() {
+        let third = Fraction::new(1, 3);
+        let half = Fraction::new(1, 2);
+
+        assert_eq!(
+            8_u32.checked_mul_fraction_rounded(&third, Rounding::Floor),
+            Some(2)
+        );
+        assert_eq!(
+            8_u32.checked_mul_fraction_rounded(&third, Rounding::Ceil),
+            Some(3)
+        );
+        assert_eq!(
+            8_u32.checked_mul_fraction_rounded(&third, Rounding::NearestEven),
+            Some(3)
+        );
+        assert_eq!(
+            7_u32.checked_mul_fraction_rounded(&third, Rounding::NearestEven),
+            Some(2)
+        );
+        assert_eq!(
+            9_u32.checked_mul_fraction_rounded(&third, Rounding::Ceil),
+            Some(3)
+        );
+
+        // ties
+        assert_eq!(
+            5_u32.checked_mul_fraction_rounded(&half, Rounding::NearestEven),
+            Some(2)
+        );
+        assert_eq!(
+            7_u32.checked_mul_fraction_rounded(&half, Rounding::NearestEven),
+            Some(4)
+        );
+
+        assert_eq!(
+            u32::MAX.checked_mul_fraction_rounded(&Fraction::new(1, 1), Rounding::Ceil),
+            Some(u32::MAX)
+        );
+        assert_eq!(
+            u32::MAX.checked_mul_fraction_rounded(&Fraction::new(2, 1), Rounding::Floor),
+            None
+        );
+        assert_eq!(
+            u32::MAX.checked_div_fraction_rounded(&Fraction::new(2, 1), Rounding::Ceil),
+            Some(1 << 31)
+        );
+    }
 }
@@ -1,6 +1,5 @@
 //! Software timers coupled to a [crate::Clock] implementation
 
-use crate::fraction::Fraction;
 use crate::{
     duration::{self, *},
     fixed_point::FixedPoint,
@@ -118,7 +117,7 @@ impl<Type, Clock: crate::Clock, Dur: Duration> Timer<'_, Type, Running, Clock, D
         Dur::T: TryFrom<Clock::T>,
         Clock::T: TryFrom<Dur::T>,
     {
-        let generic_duration = self
+        let ticks = self
             .clock
             .try_now()?
             .checked_duration_since(
@@ -129,7 +128,7 @@ impl<Type, Clock: crate::Clock, Dur: Duration> Timer<'_, Type, Running, Clock, D
             )
             .ok_or(TimeError::Overflow)?;
 
-        Ok(Dur::try_from(generic_duration)?)
+        Ok(Dur::try_from(duration::Generic::from(ticks))?)
     }
 
     /// Returns the [`Duration`] until the expiration of the timer
@@ -146,15 +145,10 @@ impl<Type, Clock: crate::Clock, Dur: Duration> Timer<'_, Type, Running, Clock, D
         let result = self
             .expiration
             .checked_duration_since(&self.clock.try_now()?)
-            .or_else(|| {
-                Some(duration::Generic::<Clock::T>::new(
-                    0.into(),
-                    Fraction::default(),
-                ))
-            })
+            .or_else(|| Some(duration::Ticks::new(0.into())))
             .ok_or(TimeError::NegDuration)?;
 
-        Ok(Dur::try_from(result)?)
+        Ok(Dur::try_from(duration::Generic::from(result))?)
     }
 }
 
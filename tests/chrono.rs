@@ -0,0 +1,88 @@
+#![cfg(feature = "chrono")]
+
+use chrono::{TimeZone, Utc};
+use core::convert::TryFrom;
+use embedded_time::{duration::*, ConversionError, Instant};
+
+#[derive(Debug)]
+struct Clock;
+
+impl embedded_time::Clock for Clock {
+    type T = u64;
+    const SCALING_FACTOR: Fraction = Fraction::new(1, 32_768);
+
+    fn try_now(&self) -> Result<Instant<Self>, embedded_time::clock::Error> {
+        Ok(Instant::new(0))
+    }
+}
+
+#[test]
+fn duration() {
+    assert_eq!(
+        chrono::Duration::try_from(Hours(2_u32)),
+        Ok(chrono::Duration::hours(2))
+    );
+    assert_eq!(
+        chrono::Duration::try_from(Seconds(90_u64)),
+        Ok(chrono::Duration::seconds(90))
+    );
+    assert_eq!(
+        chrono::Duration::try_from(Milliseconds(1_500_u32)),
+        Ok(chrono::Duration::milliseconds(1_500))
+    );
+    assert_eq!(
+        chrono::Duration::try_from(Nanoseconds(123_u32)),
+        Ok(chrono::Duration::nanoseconds(123))
+    );
+    assert_eq!(
+        chrono::Duration::try_from(Days(2_u32)),
+        Ok(chrono::Duration::days(2))
+    );
+    assert_eq!(
+        chrono::Duration::try_from(Weeks(1_u32)),
+        Ok(chrono::Duration::weeks(1))
+    );
+    assert_eq!(
+        chrono::Duration::try_from(Picoseconds(1_999_u32)),
+        Ok(chrono::Duration::nanoseconds(1))
+    );
+
+    // truncated to nanoseconds
+    assert_eq!(
+        chrono::Duration::try_from(Generic::new(1_u32, Fraction::new(1, 32_768))),
+        Ok(chrono::Duration::nanoseconds(30_517))
+    );
+    assert_eq!(
+        chrono::Duration::try_from(Scaled::<u32, 1, 60>(3)),
+        Ok(chrono::Duration::milliseconds(50))
+    );
+    assert_eq!(
+        chrono::Duration::try_from(Ticks::<Clock>::new(49_152)),
+        Ok(chrono::Duration::milliseconds(1_500))
+    );
+
+    assert_eq!(
+        chrono::Duration::try_from(Hours(u64::MAX)),
+        Err(ConversionError::Overflow)
+    );
+}
+
+#[test]
+fn instant() {
+    let epoch = Utc.with_ymd_and_hms(2021, 10, 2, 12, 0, 0).unwrap();
+
+    assert_eq!(
+        Instant::<Clock>::new(0).to_chrono_date_time(epoch),
+        Ok(epoch)
+    );
+    assert_eq!(
+        Instant::<Clock>::new(32_768 * 3_600 + 16_384).to_chrono_date_time(epoch),
+        Ok(Utc.with_ymd_and_hms(2021, 10, 2, 13, 0, 0).unwrap()
+            + chrono::Duration::milliseconds(500))
+    );
+
+    assert_eq!(
+        Instant::<Clock>::new(u64::MAX).to_chrono_date_time(chrono::DateTime::<Utc>::MAX_UTC),
+        Err(ConversionError::Overflow)
+    );
+}
@@ -0,0 +1,214 @@
+#![cfg(feature = "defmt")]
+
+use embedded_time::{
+    clock,
+    duration::{self, *},
+    fraction::Fraction,
+    rate::{self, *},
+    ConversionError, Instant, TimeError,
+};
+
+#[derive(Debug)]
+struct Clock;
+
+impl embedded_time::Clock for Clock {
+    type T = u32;
+    const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+
+    fn try_now(&self) -> Result<Instant<Self>, embedded_time::clock::Error> {
+        Ok(Instant::new(0))
+    }
+}
+
+fn assert_format<T: defmt::Format>() {}
+
+#[test]
+fn format() {
+    assert_format::<Instant<Clock>>();
+    assert_format::<Fraction>();
+    assert_format::<TimeError>();
+    assert_format::<ConversionError>();
+    assert_format::<clock::Error>();
+
+    assert_format::<Hours<u32>>();
+    assert_format::<Nanoseconds<u64>>();
+    assert_format::<Days<u32>>();
+    assert_format::<Weeks<u32>>();
+    assert_format::<Picoseconds<u64>>();
+    assert_format::<duration::Scaled<u32, 1, 32_768>>();
+    assert_format::<duration::Ticks<Clock>>();
+    assert_format::<duration::Generic<u64>>();
+
+    assert_format::<Megahertz<u32>>();
+    assert_format::<KibibytesPerSecond<u64>>();
+    assert_format::<Baud<u32>>();
+    assert_format::<rate::Scaled<u32, 1_000, 1>>();
+    assert_format::<rate::Generic<u32>>();
+}
+
+/// Encodes the value with `defmt`'s mocked logger
+///
+/// The mock interns each format string as the next index, so the index of the first string is
+/// returned with the bytes.
+fn encode<T: defmt::Format>(value: &T) -> (u16, Vec<u8>) {
+    let index = defmt::export::fetch_string_index();
+    defmt::export::istr(&T::_format_tag());
+    value._format_data();
+    (index, defmt::export::fetch_bytes())
+}
+
+macro_rules! bytes {
+    ($($x:expr),* $(,)?) => {{
+        let mut bytes = Vec::<u8>::new();
+        $(bytes.extend_from_slice(&($x).to_le_bytes());)*
+        bytes
+    }};
+}
+
+#[test]
+fn encode_duration() {
+    let (i, bytes) = encode(&Milliseconds(5_u32));
+    assert_eq!(
+        bytes,
+        bytes![
+            i,     // "{=__internal_FormatSequence}"
+            i + 1, // "{} ms"
+            i + 2, // "{=u32}"
+            5_u32,
+            0_u16, // terminator
+        ]
+    );
+
+    let (i, bytes) = encode(&Microseconds(5_u64));
+    assert_eq!(
+        bytes,
+        bytes![
+            i,     // "{=__internal_FormatSequence}"
+            i + 1, // "{} us"
+            i + 2, // "{=u64}"
+            5_u64,
+            0_u16, // terminator
+        ]
+    );
+
+    let (i, bytes) = encode(&duration::Scaled::<u32, 1, 32_768>(3));
+    assert_eq!(
+        bytes,
+        bytes![
+            i,     // "{=__internal_FormatSequence}"
+            i + 1, // "{} x {}/{} s"
+            i + 2, // "{=u32}"
+            3_u32,
+            i + 3, // "{=u32}"
+            1_u32,
+            i + 4, // "{=u32}"
+            32_768_u32,
+            0_u16, // terminator
+        ]
+    );
+
+    let (i, bytes) = encode(&duration::Ticks::<Clock>::new(7));
+    assert_eq!(
+        bytes,
+        bytes![
+            i,     // "{=__internal_FormatSequence}"
+            i + 1, // "{} ticks"
+            i + 2, // "{=u32}"
+            7_u32,
+            0_u16, // terminator
+        ]
+    );
+
+    let (i, bytes) = encode(&duration::Generic::new(2_u32, Fraction::new(1, 3)));
+    assert_eq!(
+        bytes,
+        bytes![
+            i,     // "{=__internal_FormatSequence}"
+            i + 1, // "{} x {} s"
+            i + 2, // "{=u32}"
+            2_u32,
+            i + 3, // "{=__internal_FormatSequence}"
+            i + 4, // "{}/{}"
+            i + 5, // "{=u64}"
+            1_u64,
+            i + 6, // "{=u64}"
+            3_u64,
+            0_u16, // terminator of the fraction
+            0_u16, // terminator
+        ]
+    );
+}
+
+#[test]
+fn encode_rate() {
+    let (i, bytes) = encode(&Microhertz(5_u32));
+    assert_eq!(
+        bytes,
+        bytes![
+            i,     // "{=__internal_FormatSequence}"
+            i + 1, // "{} uHz"
+            i + 2, // "{=u32}"
+            5_u32,
+            0_u16, // terminator
+        ]
+    );
+
+    let (i, bytes) = encode(&rate::Scaled::<u64, 32_768, 1>(2));
+    assert_eq!(
+        bytes,
+        bytes![
+            i,     // "{=__internal_FormatSequence}"
+            i + 1, // "{} x {}/{} Hz"
+            i + 2, // "{=u64}"
+            2_u64,
+            i + 3, // "{=u32}"
+            32_768_u32,
+            i + 4, // "{=u32}"
+            1_u32,
+            0_u16, // terminator
+        ]
+    );
+
+    let (i, bytes) = encode(&rate::Generic::new(2_u32, Fraction::new(1_000, 1)));
+    assert_eq!(
+        bytes,
+        bytes![
+            i,     // "{=__internal_FormatSequence}"
+            i + 1, // "{} x {} Hz"
+            i + 2, // "{=u32}"
+            2_u32,
+            i + 3, // "{=__internal_FormatSequence}"
+            i + 4, // "{}/{}"
+            i + 5, // "{=u64}"
+            1_000_u64,
+            i + 6, // "{=u64}"
+            1_u64,
+            0_u16, // terminator of the fraction
+            0_u16, // terminator
+        ]
+    );
+}
+
+#[test]
+fn encode_instant_and_errors() {
+    let (i, bytes) = encode(&Instant::<Clock>::new(7));
+    assert_eq!(
+        bytes,
+        bytes![
+            i,     // "{=__internal_FormatSequence}"
+            i + 1, // "Instant({} ticks)"
+            i + 2, // "{=u32}"
+            7_u32,
+            0_u16, // terminator
+        ]
+    );
+
+    let (i, bytes) = encode(&ConversionError::Overflow);
+    assert_eq!(
+        bytes,
+        bytes![
+            i,    // "Unspecified|ConversionFailure|Overflow|..."
+            2_u8, // Overflow
+        ]
+    );
+}
@@ -139,7 +139,7 @@ fn from_generic() {
     // Overflow
     assert_eq!(
         Seconds::<u32>::try_from(duration::Generic::new(u32::MAX, Fraction::new(10, 1))),
-        Err(ConversionError::Unspecified)
+        Err(ConversionError::Overflow)
     );
 
     // ConversionFailure (type)
@@ -150,6 +150,21 @@ fn from_generic() {
         )),
         Err(ConversionError::ConversionFailure)
     );
+
+    // the conversion is exact, even with a non-integer _scaling factor_
+    assert_eq!(
+        duration::Scaled::<u32, 1, 2>::try_from(duration::Generic::new(1_u32, Fraction::new(3, 2))),
+        Ok(duration::Scaled(3_u32))
+    );
+    assert_eq!(
+        Seconds::<u64>::try_from(duration::Generic::new(
+            3_u64,
+            Fraction::new(u64::MAX - 1, 6_000_000_000)
+        )),
+        Ok(Seconds(
+            (u128::from(u64::MAX - 1) * 3 / 6_000_000_000) as u64
+        ))
+    );
 }
 
 #[test]
@@ -162,7 +177,7 @@ fn to_generic() {
     // Overflow error
     assert_eq!(
         Seconds(u32::MAX).to_generic::<u32>(Fraction::new(1, 2)),
-        Err(ConversionError::Unspecified)
+        Err(ConversionError::Overflow)
     );
 
     // From named
@@ -179,6 +194,11 @@ fn get_generic_integer() {
     assert_eq!(generic.integer(), 246_u32);
 }
 
+embedded_time::define_rate_unit!(
+    /// 1/3^26 Hz
+    TinyHertz, (1, 2_541_865_828_329)
+);
+
 #[test]
 fn to_rate() {
     assert_eq!(Microseconds(500_u32).to_rate(), Ok(Kilohertz(2_u32)));
@@ -188,9 +208,21 @@ fn to_rate() {
 
     assert_eq!(Milliseconds(500_u32).to_rate(), Ok(Hertz(2_u32)));
 
+    // intermediate values don't overflow
+    assert_eq!(Hours(u32::MAX).to_rate(), Ok(Megahertz(0_u32)));
+    assert_eq!(
+        Nanoseconds(1_u64).to_rate(),
+        Ok(Millihertz(1_000_000_000_000_u64))
+    );
+    // the product of the scaling factors (1/(2^32 - 1) × 1/3^26) doesn't fit in a `Fraction`
+    assert_eq!(
+        duration::Scaled::<u64, 1, 4_294_967_295>::new(6_847_565_142_666_285).to_rate(),
+        Ok(TinyHertz(1_594_323_u64))
+    );
+
     // Errors
     assert_eq!(
-        Hours(u32::MAX).to_rate::<Megahertz<u32>>(),
+        Nanoseconds(1_u32).to_rate::<Millihertz<u32>>(),
         Err(ConversionError::Overflow)
     );
     assert_eq!(
@@ -218,6 +250,20 @@ fn from_core_duration() {
     assert_eq!(core_duration.try_into(), Ok(Seconds::<u32>(5_025)));
     assert_eq!(core_duration.try_into(), Ok(Minutes::<u32>(83)));
     assert_eq!(core_duration.try_into(), Ok(Hours::<u32>(1)));
+    assert_eq!(core_duration.try_into(), Ok(Days::<u32>(0)));
+    assert_eq!(core_duration.try_into(), Ok(Weeks::<u32>(0)));
+    assert_eq!(
+        core_duration.try_into(),
+        Ok(Picoseconds::<u64>(5_025_678_901_234_000))
+    );
+    assert_eq!(
+        Picoseconds::<u32>::try_from(core_duration),
+        Err(ConversionError::ConversionFailure)
+    );
+    assert_eq!(
+        core::time::Duration::from_secs(3 * 604_800).try_into(),
+        Ok(Weeks::<u32>(3))
+    );
 
     // From
     let duration: Seconds<u64> = core_duration.into();
@@ -250,6 +296,19 @@ fn into_core_duration() {
         Hours(123_u32).try_into(),
         Ok(core::time::Duration::from_secs(123 * 3600))
     );
+    assert_eq!(
+        Days(123_u32).try_into(),
+        Ok(core::time::Duration::from_secs(123 * 86_400))
+    );
+    assert_eq!(
+        Weeks(123_u32).try_into(),
+        Ok(core::time::Duration::from_secs(123 * 604_800))
+    );
+    // truncated to nanoseconds
+    assert_eq!(
+        Picoseconds(123_456_u32).try_into(),
+        Ok(core::time::Duration::from_nanos(123))
+    );
 }
 
 #[test]
@@ -262,6 +321,88 @@ fn duration_scaling() {
     assert_eq!(1_000_u32.nanoseconds(), 1_u32.microseconds());
     assert_eq!(1_000_000_u32.nanoseconds(), 1_u32.milliseconds());
     assert_eq!(1_000_000_000_u32.nanoseconds(), 1_u32.seconds());
+    assert_eq!(1_u32.days(), 24_u32.hours());
+    assert_eq!(1_u32.weeks(), 7_u32.days());
+    assert_eq!(1_000_u32.picoseconds(), 1_u32.nanoseconds());
+}
+
+#[test]
+fn days_weeks_picoseconds() {
+    assert!(Weeks(1_u32) > Days(6_u32));
+    assert!(Days(1_u32) < Hours(25_u32));
+    assert!(Picoseconds(999_u32) < Nanoseconds(1_u32));
+    assert!(Nanoseconds(1_u32) > Picoseconds(999_u64));
+    assert_eq!(Weeks(2_u32), Hours(336_u32));
+
+    assert_eq!(Days(1_u32) + Hours(2_u32), Days(1_u32));
+    assert_eq!(Hours(2_u32) + Days(1_u32), Hours(26_u32));
+    assert_eq!(
+        Nanoseconds(2_u32) + Picoseconds(1_500_u32),
+        Nanoseconds(3_u32)
+    );
+    assert_eq!(
+        Picoseconds(500_u32).add_finer(Nanoseconds(2_u32)),
+        Picoseconds(2_500_u32)
+    );
+
+    assert_eq!(
+        Picoseconds::<u64>::from(Milliseconds(5_u32)),
+        Picoseconds(5_000_000_000_u64)
+    );
+    assert_eq!(
+        Picoseconds::<u32>::try_from(Milliseconds(5_u32)),
+        Err(ConversionError::Overflow)
+    );
+    assert_eq!(Seconds::<u64>::from(Weeks(1_u32)), Seconds(604_800_u64));
+    assert_eq!(
+        Picoseconds::<u64>::from(Nanoseconds(u32::MAX)),
+        Picoseconds(u32::MAX as u64 * 1_000)
+    );
+    assert_eq!(
+        Picoseconds::<u64>::from(Milliseconds(u32::MAX)),
+        Picoseconds(u32::MAX as u64 * 1_000_000_000)
+    );
+    assert_eq!(
+        Milliseconds::<u64>::from(Weeks(u32::MAX)),
+        Milliseconds(u32::MAX as u64 * 604_800_000)
+    );
+
+    // u32::MAX of these doesn't fit in a u64 of the smaller unit, so they are only `TryFrom`
+    assert_eq!(
+        Picoseconds::<u64>::try_from(Weeks(31_u32)),
+        Err(ConversionError::Overflow)
+    );
+    assert_eq!(
+        Picoseconds::<u64>::try_from(Weeks(30_u32)),
+        Ok(Picoseconds(18_144_000_000_000_000_000_u64))
+    );
+    assert_eq!(
+        Picoseconds::<u64>::try_from(Seconds(20_000_000_u32)),
+        Err(ConversionError::Overflow)
+    );
+    assert_eq!(
+        Nanoseconds::<u64>::try_from(Days(u32::MAX)),
+        Err(ConversionError::Overflow)
+    );
+    assert_eq!(
+        Nanoseconds::<u64>::try_from(Minutes(1_u32)),
+        Ok(Nanoseconds(60_000_000_000_u64))
+    );
+    assert_eq!(
+        Microseconds::<u64>::try_from(Weeks(u32::MAX)),
+        Err(ConversionError::Overflow)
+    );
+    assert_ne!(Weeks(31_u32), Picoseconds(0_u64));
+    assert!(Weeks(31_u32) > Picoseconds(u64::MAX));
+    assert!(Picoseconds(u64::MAX) < Weeks(31_u32));
+
+    assert_eq!("1.5d".parse(), Ok(Hours(36_u32)));
+    assert_eq!("2w 1d".parse(), Ok(Days(15_u32)));
+    assert_eq!("1.5ns".parse(), Ok(Picoseconds(1_500_u32)));
+    assert_eq!(
+        "1.5ps".parse::<Picoseconds>(),
+        Err(ConversionError::PrecisionLoss)
+    );
 }
 
 #[test]
@@ -349,12 +490,15 @@ fn into_bigger() {
         };
     }
     test_into_bigger![
+        Weeks,
+        Days,
         Hours,
         Minutes,
         Seconds,
         Milliseconds,
         Microseconds,
-        Nanoseconds
+        Nanoseconds,
+        Picoseconds
     ];
 }
 
@@ -367,12 +511,15 @@ fn widen_integer() {
             assert_eq!(rate, $name(500_u64));
         };
     }
+    test_widen_integer![Weeks];
+    test_widen_integer![Days];
     test_widen_integer![Hours];
     test_widen_integer![Minutes];
     test_widen_integer![Seconds];
     test_widen_integer![Milliseconds];
     test_widen_integer![Microseconds];
     test_widen_integer![Nanoseconds];
+    test_widen_integer![Picoseconds];
 }
 
 #[test]
@@ -491,6 +638,7 @@ fn into_smaller() {
     }
     test_into_smaller![Milliseconds, Seconds, Minutes, Hours];
     test_into_smaller![Nanoseconds, Microseconds, Milliseconds, Seconds];
+    test_into_smaller![Hours, Days, Weeks];
 }
 
 #[test]
@@ -501,6 +649,945 @@ fn error_try_from() {
     );
     assert_eq!(
         Milliseconds::<u32>::try_from(Seconds(u64::MAX)),
-        Err(ConversionError::Unspecified)
+        Err(ConversionError::Overflow)
+    );
+}
+
+#[test]
+fn display_human() {
+    assert_eq!(format!("{}", Nanoseconds(0_u32).display_human()), "0 s");
+    assert_eq!(format!("{}", Picoseconds(1_u32).display_human()), "1 ps");
+    assert_eq!(
+        format!("{}", Picoseconds(1_500_u32).display_human()),
+        "1.5 ns"
+    );
+    assert_eq!(
+        format!("{}", Picoseconds(1_234_567_u32).display_human()),
+        "1.234567 µs"
+    );
+    assert_eq!(
+        format!("{}", Nanoseconds(999_u32).display_human()),
+        "999 ns"
+    );
+    assert_eq!(
+        format!("{}", Nanoseconds(1_500_u32).display_human()),
+        "1.5 µs"
+    );
+    assert_eq!(
+        format!("{}", Microseconds(250_u32).display_human()),
+        "250 µs"
+    );
+    assert_eq!(
+        format!("{}", Nanoseconds(1_234_567_u32).display_human()),
+        "1.234567 ms"
+    );
+    assert_eq!(
+        format!("{}", Milliseconds(3_004_u32).display_human()),
+        "3.004 s"
+    );
+    assert_eq!(
+        format!("{}", Milliseconds(63_004_u32).display_human()),
+        "1m 03.004s"
+    );
+    assert_eq!(
+        format!("{}", Milliseconds(3_723_004_u64).display_human()),
+        "1h 02m 03.004s"
+    );
+    assert_eq!(
+        format!("{}", Hours(100_u32).display_human()),
+        "4d 04h 00m 00s"
+    );
+    assert_eq!(
+        format!("{}", Weeks(3_u32).display_human()),
+        "21d 00h 00m 00s"
+    );
+    assert_eq!(
+        format!("{}", Seconds(90_061_u32).display_human()),
+        "1d 01h 01m 01s"
+    );
+    assert_eq!(
+        format!("{}", Hours(u32::MAX).display_human()),
+        "178956970d 15h 00m 00s"
+    );
+    // `u64` × `u64` × 1e9 doesn't fit in a `u128`
+    assert_eq!(
+        format!(
+            "{}",
+            Generic::new(u64::MAX, Fraction::new(u64::MAX, 1)).display_human()
+        ),
+        "3938453320844195178547235176902188d 18h 03m 45s"
+    );
+    assert_eq!(
+        format!(
+            "{}",
+            Generic::new(u64::MAX, Fraction::new(u64::MAX, 1_000)).display_human()
+        ),
+        "3938453320844195178547235176902d 04h 31m 48.225s"
+    );
+
+    // precision
+    assert_eq!(
+        format!("{:.2}", Microseconds(1_234_u32).display_human()),
+        "1.23 ms"
+    );
+    assert_eq!(
+        format!("{:.4}", Milliseconds(1_500_u32).display_human()),
+        "1.5000 s"
+    );
+    assert_eq!(
+        format!("{:.12}", Seconds(1_u32).display_human()),
+        "1.000000000000 s"
+    );
+    assert_eq!(
+        format!("{:.1}", Milliseconds(3_723_004_u32).display_human()),
+        "1h 02m 03.0s"
+    );
+
+    // generic
+    assert_eq!(
+        format!(
+            "{}",
+            Generic::new(1_u32, Fraction::new(1, 32_768)).display_human()
+        ),
+        "30.517578 µs"
+    );
+    assert_eq!(
+        format!(
+            "{}",
+            Generic::new(3_u64, Fraction::new(1, 2)).display_human()
+        ),
+        "1.5 s"
+    );
+    assert_eq!(
+        format!(
+            "{}",
+            Generic::new(5_u32, Fraction::new(1, 0)).display_human()
+        ),
+        "5 x 1/0 s"
+    );
+
+    // width, fill and alignment
+    let duration = Milliseconds(3_723_004_u32).display_human();
+    assert_eq!(format!("[{:>16}]", duration), "[  1h 02m 03.004s]");
+    assert_eq!(format!("[{:16}]", duration), "[1h 02m 03.004s  ]");
+    assert_eq!(format!("[{:*^17}]", duration), "[*1h 02m 03.004s**]");
+    assert_eq!(format!("[{:>18.1}]", duration), "[      1h 02m 03.0s]");
+    assert_eq!(format!("[{:>4}]", duration), "[1h 02m 03.004s]");
+    assert_eq!(
+        format!("[{:>7}]", Microseconds(5_u32).display_human()),
+        "[   5 µs]"
+    );
+}
+
+#[test]
+fn parse() {
+    assert_eq!("250ms".parse(), Ok(Milliseconds(250_u32)));
+    assert_eq!("250 ms".parse(), Ok(Microseconds(250_000_u64)));
+    assert_eq!("  42ns ".parse(), Ok(Nanoseconds(42_u32)));
+    assert_eq!("42us".parse(), Ok(Microseconds(42_u32)));
+    assert_eq!("42µs".parse(), Ok(Microseconds(42_u32)));
+    assert_eq!("1.5s".parse(), Ok(Milliseconds(1_500_u32)));
+    assert_eq!(".5s".parse(), Ok(Milliseconds(500_u32)));
+    assert_eq!("2.s".parse(), Ok(Seconds(2_u32)));
+    assert_eq!("1.500000000000s".parse(), Ok(Milliseconds(1_500_u32)));
+    assert_eq!("2min".parse(), Ok(Seconds(120_u32)));
+    assert_eq!("2m".parse(), Ok(Seconds(120_u32)));
+    assert_eq!("1.25h".parse(), Ok(Minutes(75_u32)));
+    assert_eq!("1h30m".parse(), Ok(Minutes(90_u32)));
+    assert_eq!("1h 30m 1s 2ms".parse(), Ok(Milliseconds(5_401_002_u32)));
+    assert_eq!("18446744073709551615ns".parse(), Ok(Nanoseconds(u64::MAX)));
+
+    assert_eq!("".parse::<Seconds>(), Err(ConversionError::ParseFailure));
+    assert_eq!("s".parse::<Seconds>(), Err(ConversionError::ParseFailure));
+    assert_eq!("5".parse::<Seconds>(), Err(ConversionError::ParseFailure));
+    assert_eq!(
+        "5 seconds".parse::<Seconds>(),
+        Err(ConversionError::ParseFailure)
+    );
+    assert_eq!(
+        "1.2.3s".parse::<Seconds>(),
+        Err(ConversionError::ParseFailure)
+    );
+    assert_eq!("-5s".parse::<Seconds>(), Err(ConversionError::ParseFailure));
+    assert_eq!(
+        "1s1s".parse::<Seconds>(),
+        Err(ConversionError::ParseFailure)
+    );
+    assert_eq!(
+        "10ms5s".parse::<Milliseconds>(),
+        Err(ConversionError::ParseFailure)
+    );
+    assert_eq!(
+        "1min1m".parse::<Seconds>(),
+        Err(ConversionError::ParseFailure)
+    );
+    assert_eq!(
+        "1us1µs".parse::<Nanoseconds>(),
+        Err(ConversionError::ParseFailure)
+    );
+    assert_eq!("1m1ms".parse(), Ok(Milliseconds(60_001_u32)));
+
+    assert_eq!(
+        "1.5s".parse::<Seconds>(),
+        Err(ConversionError::PrecisionLoss)
+    );
+    assert_eq!(
+        "1ms".parse::<Seconds>(),
+        Err(ConversionError::PrecisionLoss)
+    );
+    assert_eq!(
+        "0.5ns".parse::<Nanoseconds>(),
+        Err(ConversionError::PrecisionLoss)
+    );
+
+    assert_eq!(
+        "4294967296ms".parse::<Milliseconds<u32>>(),
+        Err(ConversionError::Overflow)
+    );
+    assert_eq!(
+        "18446744073709551616ns".parse::<Nanoseconds<u64>>(),
+        Err(ConversionError::Overflow)
+    );
+    assert_eq!(
+        "999999999999999999999999999999999999999h".parse::<Hours<u64>>(),
+        Err(ConversionError::Overflow)
+    );
+}
+
+#[test]
+fn parse_generic() {
+    assert_eq!(
+        "1h".parse::<Generic<u32>>(),
+        Ok(Generic::new(1_u32, Fraction::new(3_600, 1)))
+    );
+    assert_eq!(
+        "1h".parse::<Generic<u32>>()
+            .map(|generic| *generic.scaling_factor()),
+        Ok(Fraction::new(3_600, 1))
+    );
+    assert_eq!(
+        "1.5s"
+            .parse::<Generic<u32>>()
+            .map(|generic| generic.integer()),
+        Ok(1_500_u32)
+    );
+    assert_eq!(
+        "90s"
+            .parse::<Generic<u64>>()
+            .map(|generic| generic.integer()),
+        Ok(90_u64)
+    );
+    assert_eq!(
+        "1h30m"
+            .parse::<Generic<u64>>()
+            .map(|generic| generic.integer()),
+        Ok(90_u64)
+    );
+    assert_eq!(
+        "5s".parse::<Generic<u32>>()
+            .and_then(Milliseconds::<u32>::try_from),
+        Ok(Milliseconds(5_000_u32))
+    );
+
+    assert_eq!(
+        "1ps".parse::<Generic<u64>>(),
+        Ok(Generic::new(1_u64, Fraction::new(1, 1_000_000_000_000)))
+    );
+    assert_eq!(
+        "1500ps".parse::<Generic<u64>>(),
+        Ok(Generic::new(1_500_u64, Fraction::new(1, 1_000_000_000_000)))
+    );
+    assert_eq!(
+        "1.5ns"
+            .parse::<Generic<u32>>()
+            .map(|generic| generic.integer()),
+        Ok(1_500_u32)
+    );
+    assert_eq!(
+        "2w".parse::<Generic<u32>>()
+            .map(|generic| generic.integer()),
+        Ok(2_u32)
+    );
+    assert_eq!(
+        "1w 1d"
+            .parse::<Generic<u32>>()
+            .and_then(Hours::<u32>::try_from),
+        Ok(Hours(192_u32))
+    );
+
+    assert_eq!(
+        "0.5ps".parse::<Generic<u32>>(),
+        Err(ConversionError::PrecisionLoss)
+    );
+    assert_eq!(
+        "4294967296ns".parse::<Generic<u32>>(),
+        Err(ConversionError::Overflow)
+    );
+}
+
+#[test]
+fn write_iso8601() {
+    fn iso8601<D: Duration + embedded_time::fixed_point::FixedPoint>(duration: D) -> String
+    where
+        u64: From<D::T>,
+    {
+        let mut iso = String::new();
+        duration.write_iso8601(&mut iso).unwrap();
+        iso
+    }
+
+    assert_eq!(iso8601(Seconds(0_u32)), "PT0S");
+    assert_eq!(iso8601(Milliseconds(0_u32)), "PT0S");
+    assert_eq!(iso8601(Milliseconds(250_u32)), "PT0.250S");
+    assert_eq!(iso8601(Milliseconds(5_400_250_u32)), "PT1H30M0.250S");
+    assert_eq!(iso8601(Milliseconds(5_400_000_u32)), "PT1H30M");
+    assert_eq!(iso8601(Minutes(61_u32)), "PT1H1M");
+    assert_eq!(iso8601(Hours(48_u32)), "PT48H");
+    assert_eq!(iso8601(Nanoseconds(1_u32)), "PT0.000000001S");
+    assert_eq!(iso8601(Seconds(u64::MAX)), "PT5124095576030431H15S");
+
+    let mut iso = String::new();
+    Generic::new(1_u32, Fraction::new(1, 3))
+        .write_iso8601(&mut iso)
+        .unwrap_err();
+    let mut iso = String::new();
+    Generic::new(3_u32, Fraction::new(1, 3))
+        .write_iso8601(&mut iso)
+        .unwrap();
+    assert_eq!(iso, "PT1S");
+}
+
+#[test]
+fn from_iso8601() {
+    assert_eq!(Seconds::<u32>::from_iso8601("PT0S"), Ok(Seconds(0_u32)));
+    assert_eq!(Seconds::<u32>::from_iso8601("P0D"), Ok(Seconds(0_u32)));
+    assert_eq!(
+        Seconds::<u32>::from_iso8601("P1DT1S"),
+        Ok(Seconds(86_401_u32))
+    );
+    assert_eq!(Minutes::<u32>::from_iso8601("P1W"), Ok(Minutes(10_080_u32)));
+    assert_eq!(
+        Milliseconds::<u32>::from_iso8601("PT1H30M0.250S"),
+        Ok(Milliseconds(5_400_250_u32))
+    );
+    assert_eq!(
+        Milliseconds::<u32>::from_iso8601("PT0.25000000000000000000000000S"),
+        Ok(Milliseconds(250_u32))
+    );
+    assert_eq!(Seconds::<u32>::from_iso8601("PT1.5M"), Ok(Seconds(90_u32)));
+    assert_eq!(
+        Milliseconds::<u32>::from_iso8601("PT0,5S"),
+        Ok(Milliseconds(500_u32))
+    );
+    assert_eq!(Seconds::<u32>::from_iso8601("PT1,5M"), Ok(Seconds(90_u32)));
+    assert_eq!(
+        Nanoseconds::<u64>::from_iso8601("PT0.000000001S"),
+        Ok(Nanoseconds(1_u64))
+    );
+
+    for invalid in &[
+        "", "P", "PT", "P1DT", "T1S", "PT1", "PT1X", "P1Y", "P1M", "PT1S1M", "PT1H1H", "pt1s",
+        "PT-1S", "PT1.2.3S", " PT1S", "PT1.S", "PT1,S", "PT1,2.3S", "PT.S",
+    ] {
+        assert_eq!(
+            Seconds::<u32>::from_iso8601(invalid),
+            Err(ConversionError::ParseFailure),
+            "{}",
+            invalid
+        );
+    }
+
+    assert_eq!(
+        Minutes::<u32>::from_iso8601("PT1S"),
+        Err(ConversionError::PrecisionLoss)
+    );
+    assert_eq!(
+        Seconds::<u32>::from_iso8601("PT4294967296S"),
+        Err(ConversionError::Overflow)
+    );
+
+    // lossless round trip through a clock's scaling factor
+    let ticks = Generic::new(u32::MAX, Fraction::new(1, 32_768));
+    let mut iso = String::new();
+    ticks.write_iso8601(&mut iso).unwrap();
+    assert_eq!(iso, "PT36H24M31.999969482421875S");
+    assert_eq!(
+        Generic::<u32>::from_iso8601(&iso, Fraction::new(1, 32_768)),
+        Ok(ticks)
+    );
+    assert_eq!(
+        Generic::<u32>::from_iso8601("PT1S", Fraction::new(0, 1)),
+        Err(ConversionError::DivByZero)
+    );
+}
+
+#[test]
+fn signed_duration() {
+    let early = SignedDuration::<Milliseconds>::new(-3);
+    let late = SignedDuration::<Milliseconds>::new(5);
+
+    assert!(early.is_negative() && !early.is_positive());
+    assert!(late.is_positive() && !late.is_negative());
+    assert!(early < late);
+    assert_eq!(-early, SignedDuration::new(3));
+    assert_eq!(early + late, SignedDuration::new(2));
+    assert_eq!(early - late, SignedDuration::new(-8));
+    assert_eq!(early * -2, SignedDuration::new(6));
+    assert_eq!(late / -5, SignedDuration::new(-1));
+    assert_eq!(early.unsigned_abs(), Milliseconds(3_u32));
+    assert_eq!(early.checked_abs(), Some(SignedDuration::new(3)));
+    assert_eq!(format!("{}", early), "-3");
+
+    let min = SignedDuration::<Seconds<u64>>::new(i64::MIN);
+    assert_eq!(min.checked_neg(), None);
+    assert_eq!(min.checked_abs(), None);
+    assert_eq!(min.checked_sub(SignedDuration::new(1)), None);
+    assert_eq!(
+        min.checked_add(SignedDuration::new(1)),
+        Some(SignedDuration::new(i64::MIN + 1))
+    );
+    assert_eq!(min.checked_div(-1), None);
+    assert_eq!(min.checked_mul(2), None);
+    assert_eq!(min.unsigned_abs(), Seconds(1_u64 << 63));
+}
+
+#[test]
+fn signed_duration_conversion() {
+    assert_eq!(
+        SignedDuration::try_from(Seconds(5_u32)),
+        Ok(SignedDuration::<Seconds>::new(5))
+    );
+    assert_eq!(
+        SignedDuration::try_from(Seconds(u64::MAX)),
+        Err(ConversionError::Overflow)
+    );
+    assert_eq!(
+        Seconds::try_from(SignedDuration::<Seconds>::new(0)),
+        Ok(Seconds(0_u32))
+    );
+    assert_eq!(
+        Seconds::try_from(SignedDuration::<Seconds>::new(-1)),
+        Err(ConversionError::NegDuration)
+    );
+
+    assert_eq!(
+        SignedDuration::<Milliseconds>::new(-1_500).try_convert::<Seconds>(),
+        Ok(SignedDuration::new(-1))
+    );
+    assert_eq!(
+        SignedDuration::<Seconds>::new(i32::MIN).try_convert::<Seconds<u64>>(),
+        Ok(SignedDuration::new(i64::from(i32::MIN)))
+    );
+    assert_eq!(
+        SignedDuration::<Milliseconds<u64>>::new(-2_147_483_648_000).try_convert::<Seconds>(),
+        Ok(SignedDuration::new(i32::MIN))
+    );
+    assert_eq!(
+        SignedDuration::<Milliseconds<u64>>::new(2_147_483_648_000).try_convert::<Seconds>(),
+        Err(ConversionError::Overflow)
+    );
+}
+
+#[test]
+#[cfg(feature = "f32")]
+fn f32_conversion() {
+    assert_eq!(Seconds(0_u32).as_secs_f32(), 0.0);
+    assert_eq!(Milliseconds(1_500_u32).as_secs_f32(), 1.5);
+    assert_eq!(Nanoseconds(1_u32).as_secs_f32(), 1e-9);
+    assert_eq!(Hours(u64::MAX).as_secs_f32(), 6.640_828e22);
+    // correctly rounded: 16_777_217 is halfway between two `f32`s and ties to even
+    assert_eq!(Seconds(16_777_217_u32).as_secs_f32(), 16_777_216.0);
+    assert_eq!(Microseconds(16_777_217_u32).as_secs_f32(), 16.777_217);
+    // a `u64` × `u64` product of at least 2^127
+    assert_eq!(
+        Generic::new(u64::MAX, Fraction::new(u64::MAX, 1)).as_secs_f32(),
+        f32::INFINITY
+    );
+
+    assert_eq!(
+        Milliseconds::<u32>::from_secs_f32(0.001),
+        Ok(Milliseconds(1_u32))
+    );
+    assert_eq!(Seconds::<u32>::from_secs_f32(0.5), Ok(Seconds(0_u32)));
+    assert_eq!(Seconds::<u32>::from_secs_f32(1.5), Ok(Seconds(2_u32)));
+    assert_eq!(Seconds::<u32>::from_secs_f32(-0.0), Ok(Seconds(0_u32)));
+    assert_eq!(Seconds::<u32>::from_secs_f32(1e-40), Ok(Seconds(0_u32)));
+    assert_eq!(
+        Seconds::<u32>::from_secs_f32(-0.1),
+        Err(ConversionError::ConversionFailure)
+    );
+    assert_eq!(
+        Seconds::<u32>::from_secs_f32(f32::NAN),
+        Err(ConversionError::ConversionFailure)
+    );
+    assert_eq!(
+        Seconds::<u32>::from_secs_f32(f32::INFINITY),
+        Err(ConversionError::Overflow)
+    );
+    assert_eq!(
+        Seconds::<u64>::from_secs_f32(f32::MAX),
+        Err(ConversionError::Overflow)
+    );
+
+    let generic = Generic::new(1_u32, Fraction::new(1, 3));
+    assert_eq!(generic.as_secs_f32(), 1.0 / 3.0);
+    assert_eq!(
+        Generic::<u32>::from_secs_f32(1.0, Fraction::new(1, 3)),
+        Ok(Generic::new(3_u32, Fraction::new(1, 3)))
+    );
+}
+
+#[test]
+#[cfg(feature = "f64")]
+fn f64_conversion() {
+    assert_eq!(Seconds(0_u32).as_secs_f64(), 0.0);
+    assert_eq!(Microseconds(1_u32).as_secs_f64(), 1e-6);
+    assert_eq!(Nanoseconds(u64::MAX).as_secs_f64(), 18_446_744_073.709_552);
+    assert_eq!(
+        Generic::new(2_u32, Fraction::new(1, 3)).as_secs_f64(),
+        2.0 / 3.0
+    );
+    // `u64` × `u64` products of at least 2^127
+    assert_eq!(
+        Generic::new(u64::MAX, Fraction::new(u64::MAX, 1)).as_secs_f64(),
+        3.402_823_669_209_385e38
+    );
+    assert_eq!(
+        Generic::new(u64::MAX, Fraction::new(u64::MAX, 3)).as_secs_f64(),
+        1.134_274_556_403_128_1e38
+    );
+
+    assert_eq!(
+        Nanoseconds::<u64>::from_secs_f64(1.000_000_001),
+        Ok(Nanoseconds(1_000_000_001_u64))
+    );
+    assert_eq!(Seconds::<u32>::from_secs_f64(2.5), Ok(Seconds(2_u32)));
+    assert_eq!(Seconds::<u32>::from_secs_f64(3.5), Ok(Seconds(4_u32)));
+    // the `f64` closest to 0.0025 is slightly larger, so it isn't a tie
+    assert_eq!(
+        Milliseconds::<u32>::from_secs_f64(0.0025),
+        Ok(Milliseconds(3_u32))
+    );
+    assert_eq!(
+        Seconds::<u32>::from_secs_f64(4_294_967_295.4),
+        Ok(Seconds(u32::MAX))
+    );
+    assert_eq!(
+        Seconds::<u32>::from_secs_f64(4_294_967_295.5),
+        Err(ConversionError::Overflow)
+    );
+    assert_eq!(
+        Seconds::<u32>::from_secs_f64(f64::MIN_POSITIVE / 2.0),
+        Ok(Seconds(0_u32))
+    );
+    assert_eq!(
+        Generic::<u32>::from_secs_f64(1.0, Fraction::new(0, 1)),
+        Err(ConversionError::DivByZero)
+    );
+}
+
+#[test]
+fn try_from_rounded() {
+    use embedded_time::{fixed_point::FixedPoint, Rounding};
+
+    assert_eq!(
+        Milliseconds::<u32>::try_from_rounded(Microseconds(1_999_u32), Rounding::Floor),
+        Ok(Milliseconds(1_u32))
+    );
+    assert_eq!(
+        Milliseconds::<u32>::try_from_rounded(Microseconds(1_001_u32), Rounding::Ceil),
+        Ok(Milliseconds(2_u32))
+    );
+    assert_eq!(
+        Milliseconds::<u32>::try_from_rounded(Microseconds(1_500_u32), Rounding::NearestEven),
+        Ok(Milliseconds(2_u32))
+    );
+    assert_eq!(
+        Milliseconds::<u32>::try_from_rounded(Microseconds(2_500_u32), Rounding::NearestEven),
+        Ok(Milliseconds(2_u32))
+    );
+    assert_eq!(
+        Milliseconds::<u32>::try_from_rounded(Microseconds(2_501_u32), Rounding::NearestEven),
+        Ok(Milliseconds(3_u32))
+    );
+    assert_eq!(
+        Microseconds::<u64>::try_from_rounded(Seconds(5_u32), Rounding::Ceil),
+        Ok(Microseconds(5_000_000_u64))
+    );
+    assert_eq!(
+        Seconds::<u32>::try_from_rounded(Milliseconds(u64::MAX), Rounding::Ceil),
+        Err(ConversionError::Overflow)
+    );
+    assert_eq!(
+        Seconds::<u32>::try_from_rounded(Milliseconds(4_294_967_295_001_u64), Rounding::Ceil),
+        Err(ConversionError::Overflow)
+    );
+
+    // 100 µs in ticks of a 32.768 kHz clock is 3.2768
+    let tick = Fraction::new(1, 32_768);
+    assert_eq!(
+        Microseconds(100_u32).into_ticks_rounded::<u32>(tick, Rounding::Floor),
+        Ok(3)
+    );
+    assert_eq!(
+        Microseconds(100_u32).into_ticks_rounded::<u32>(tick, Rounding::Ceil),
+        Ok(4)
+    );
+    assert_eq!(
+        Microseconds(100_u32).into_ticks_rounded::<u32>(Fraction::new(0, 1), Rounding::Ceil),
+        Err(ConversionError::DivByZero)
+    );
+}
+
+#[test]
+fn saturating() {
+    use embedded_time::fixed_point::FixedPoint;
+
+    assert_eq!(
+        Seconds(1_u32).saturating_add(Seconds(2_u32)),
+        Seconds(3_u32)
+    );
+    assert_eq!(
+        Seconds(u32::MAX).saturating_add(Seconds(1_u32)),
+        Seconds(u32::MAX)
+    );
+    assert_eq!(
+        Milliseconds(1_u32).saturating_add(Seconds(u64::MAX)),
+        Milliseconds(u32::MAX)
+    );
+    assert_eq!(
+        Seconds(1_u64).saturating_add(Milliseconds(1_999_u32)),
+        Seconds(2_u64)
+    );
+
+    assert_eq!(
+        Seconds(3_u32).saturating_sub(Seconds(2_u32)),
+        Seconds(1_u32)
+    );
+    assert_eq!(
+        Seconds(1_u32).saturating_sub(Seconds(2_u32)),
+        Seconds(0_u32)
+    );
+    assert_eq!(
+        Milliseconds(1_u32).saturating_sub(Hours(u64::MAX)),
+        Milliseconds(0_u32)
+    );
+
+    assert_eq!(Minutes(5_u64).saturating_mul(2), Minutes(10_u64));
+    assert_eq!(Minutes(5_u64).saturating_mul(u64::MAX), Minutes(u64::MAX));
+
+    assert_eq!(
+        Milliseconds::<u32>::saturating_from(Seconds(4_294_967_u64)),
+        Milliseconds(4_294_967_000_u32)
+    );
+    assert_eq!(
+        Milliseconds::<u32>::saturating_from(Seconds(4_294_968_u64)),
+        Milliseconds(u32::MAX)
+    );
+    assert_eq!(
+        Seconds::<u32>::saturating_from(Milliseconds(1_999_u64)),
+        Seconds(1_u32)
+    );
+}
+
+#[test]
+fn checked_mixed_unit_arithmetic() {
+    assert_eq!(
+        Milliseconds(1_u32).checked_add(Seconds(1_u32)),
+        Ok(Milliseconds(1_001_u32))
+    );
+    assert_eq!(
+        Seconds(1_u64).checked_add(Seconds(2_u32)),
+        Ok(Seconds(3_u64))
+    );
+    assert_eq!(
+        Seconds(u32::MAX).checked_add(Seconds(1_u32)),
+        Err(ConversionError::Overflow)
+    );
+    assert!(Milliseconds(1_u32).checked_add(Seconds(u32::MAX)).is_err());
+
+    assert_eq!(
+        Milliseconds(2_001_u32).checked_sub(Seconds(1_u32)),
+        Ok(Milliseconds(1_001_u32))
+    );
+    assert_eq!(
+        Seconds(1_u32).checked_sub(Seconds(2_u32)),
+        Err(ConversionError::NegDuration)
+    );
+    assert_eq!(
+        Seconds(2_u32).checked_sub(Milliseconds(1_999_u32)),
+        Err(ConversionError::PrecisionLoss)
+    );
+    assert_eq!(
+        Seconds(2_u32).checked_sub(Milliseconds(1_000_u32)),
+        Ok(Seconds(1_u32))
+    );
+    assert_eq!(
+        Seconds(1_u32).checked_add(Milliseconds(500_u32)),
+        Err(ConversionError::PrecisionLoss)
+    );
+    assert!(Milliseconds(1_u32).checked_sub(Hours(u64::MAX)).is_err());
+
+    assert_eq!(
+        Milliseconds(2_500_u32).checked_rem(Seconds(1_u32)),
+        Ok(Milliseconds(500_u32))
+    );
+    assert_eq!(
+        Seconds(5_u64).checked_rem(Seconds(0_u32)),
+        Err(ConversionError::DivByZero)
+    );
+    assert_eq!(
+        Seconds(5_u32).checked_rem(Milliseconds(500_u32)),
+        Err(ConversionError::PrecisionLoss)
+    );
+    assert_eq!(
+        Seconds(5_u32).checked_rem(Milliseconds(1_500_u32)),
+        Err(ConversionError::PrecisionLoss)
+    );
+    assert_eq!(
+        Seconds(5_u32).checked_rem(Milliseconds(2_000_u32)),
+        Ok(Seconds(1_u32))
+    );
+}
+
+#[test]
+fn add_finer() {
+    assert_eq!(
+        Seconds(1_u32).add_finer(Milliseconds(500_u32)),
+        Milliseconds(1_500_u32)
+    );
+    assert_eq!(
+        Milliseconds(500_u64).add_finer(Seconds(1_u64)),
+        Milliseconds(1_500_u64)
+    );
+    assert_eq!(
+        Hours(1_u64).add_finer(Nanoseconds(1_u64)),
+        Nanoseconds(3_600_000_000_001_u64)
+    );
+    assert_eq!(Minutes(1_u32).add_finer(Minutes(2_u32)), Minutes(3_u32));
+
+    assert_eq!(
+        Seconds(1_u32).checked_add_finer(Milliseconds(u32::MAX)),
+        Err(ConversionError::Overflow)
+    );
+    assert!(Hours(u32::MAX).checked_add_finer(Seconds(1_u32)).is_err());
+}
+
+#[test]
+#[should_panic]
+fn add_finer_overflow() {
+    let _ = Seconds(u32::MAX).add_finer(Milliseconds(1_u32));
+}
+
+#[test]
+fn scaled() {
+    type Ticks32k = duration::Scaled<u32, 1, 32_768>;
+
+    assert_eq!(Ticks32k::new(32_768).integer(), 32_768_u32);
+    assert_eq!(Ticks32k::SCALING_FACTOR, Fraction::new(1, 32_768));
+
+    assert_eq!(
+        Ticks32k::try_from(Seconds(2_u32)),
+        Ok(duration::Scaled(65_536_u32))
+    );
+    assert_eq!(
+        duration::Scaled::<u64, 1, 32_768>::try_from(Seconds(u32::MAX)),
+        Ok(duration::Scaled(140_737_488_322_560_u64))
+    );
+    assert!(Ticks32k::try_from(Seconds(u32::MAX)).is_err());
+    assert_eq!(
+        Seconds::<u64>::try_from(Ticks32k::new(65_537)),
+        Ok(Seconds(2_u64))
+    );
+    assert_eq!(
+        Ticks32k::new(16_384).try_convert::<duration::Scaled<u32, 1, 1_000>>(),
+        Ok(duration::Scaled(500_u32))
+    );
+    assert_eq!(
+        Generic::from(Ticks32k::new(1)),
+        Generic::new(1_u32, Fraction::new(1, 32_768))
+    );
+    assert_eq!(
+        Ticks32k::try_from(Generic::new(1_u32, Fraction::new(1, 1_024))),
+        Ok(duration::Scaled(32_u32))
+    );
+
+    assert_eq!(
+        Ticks32k::new(1) + Milliseconds(1_000_u32),
+        Ticks32k::new(32_769_u32)
+    );
+    assert_eq!(Ticks32k::new(32_769) - Seconds(1_u32), Ticks32k::new(1_u32));
+    assert_eq!(Ticks32k::new(32_769) % Seconds(1_u32), Ticks32k::new(1_u32));
+    assert_eq!(Ticks32k::new(2) * 3, Ticks32k::new(6_u32));
+    assert_eq!(Ticks32k::new(6) / 3, Ticks32k::new(2_u32));
+    assert!(Ticks32k::new(1) < Ticks32k::new(2));
+
+    assert_eq!(Milliseconds(250_u32), Ticks32k::new(8_192));
+    assert_eq!(Ticks32k::new(32_768), Seconds(1_u64));
+    assert_ne!(Ticks32k::new(1), Milliseconds(0_u32));
+    assert!(Ticks32k::new(32) > Milliseconds(0_u32));
+    assert!(Ticks32k::new(33) < Milliseconds(2_u32));
+    assert!(Microseconds(u64::MAX) > Ticks32k::new(u32::MAX));
+
+    assert_eq!(Ticks32k::new(32), duration::Scaled::<u32, 1, 1_024>(1));
+    assert_eq!(Ticks32k::new(32), duration::Scaled::<u64, 1, 1_024>(1));
+    assert_ne!(Ticks32k::new(33), duration::Scaled::<u32, 1, 1_024>(1));
+    assert!(Ticks32k::new(31) < duration::Scaled::<u32, 1, 1_024>(1));
+    assert!(duration::Scaled::<u64, 3, 1>(u64::MAX) > Ticks32k::new(u32::MAX));
+    assert_eq!(
+        duration::Scaled::<u32, 1, 0>::new(1).partial_cmp(&Ticks32k::new(1)),
+        None
+    );
+    assert_eq!(Ticks32k::new(3).max(Ticks32k::new(2)), Ticks32k::new(3));
+    assert_eq!(
+        duration::Scaled::<u32, 1, 0>::new(1).partial_cmp(&Seconds(1_u32)),
+        None
+    );
+
+    assert_eq!("0.5s".parse(), Ok(Ticks32k::new(16_384)));
+    assert_eq!(Ticks32k::new(16_384).to_string(), "16384");
+    assert_eq!(Ticks32k::new(16_384).display_human().to_string(), "500 ms");
+}
+
+#[test]
+fn const_conversion() {
+    const TICKS: u32 = Milliseconds(250_u32).const_into_ticks(Fraction::new(1, 32_768));
+    const SECONDS: u64 = Hours(1_u64).const_into_ticks(Fraction::new(1, 1));
+    const WIDE: Nanoseconds<u64> = Nanoseconds(
+        Seconds(5_u32)
+            .const_widen()
+            .const_into_ticks(Nanoseconds::<u64>::SCALING_FACTOR),
+    );
+    const SCALED: duration::Scaled<u32, 1, 32_768> = duration::Scaled::new(8_192);
+
+    assert_eq!(TICKS, 8_192);
+    assert_eq!(SECONDS, 3_600);
+    assert_eq!(WIDE, Nanoseconds(5_000_000_000_u64));
+    assert_eq!(SCALED.const_into_ticks(Fraction::new(1, 1_000)), 250);
+    assert_eq!(
+        Milliseconds(1_999_u32).const_into_ticks(Fraction::new(1, 1)),
+        1
+    );
+
+    assert_eq!(
+        Seconds(u32::MAX).const_checked_into_ticks(Fraction::new(1, 1_000)),
+        None
+    );
+    assert_eq!(
+        Seconds(1_u32).const_checked_into_ticks(Fraction::new(0, 1)),
+        None
+    );
+    assert_eq!(
+        Seconds(u32::MAX)
+            .const_widen()
+            .const_checked_into_ticks(Fraction::new(1, 1_000)),
+        Some(4_294_967_295_000)
+    );
+}
+
+#[test]
+#[should_panic]
+fn const_conversion_overflow() {
+    let _ = Seconds(u32::MAX).const_into_ticks(Fraction::new(1, 1_000));
+}
+
+embedded_time::define_duration_unit!(
+    /// Video frames at 60 fps
+    Frames, (1, 60)
+);
+embedded_time::define_duration_unit!(SiderealSeconds, (86_164_091, 86_400_000));
+embedded_time::define_duration_unit!(pub Ticks32k, (1, 32_768));
+
+#[test]
+fn user_defined_units() {
+    assert_eq!(Frames::<u32>::try_from(Seconds(2_u32)), Ok(Frames(120_u32)));
+    assert_eq!(
+        Frames::<u64>::try_from(Minutes(1_u32)),
+        Ok(Frames(3_600_u64))
+    );
+    assert_eq!(
+        Milliseconds::<u32>::try_from(Frames(3_u32)),
+        Ok(Milliseconds(50_u32))
+    );
+    assert_eq!(
+        Hours::<u32>::try_from(Frames(216_000_u32)),
+        Ok(Hours(1_u32))
+    );
+    assert_eq!(
+        Frames::<u32>::try_from(Nanoseconds(50_000_000_u32)),
+        Ok(Frames(3_u32))
+    );
+    assert_eq!(
+        Frames::<u32>::try_from(Hours(u32::MAX)),
+        Err(ConversionError::Overflow)
+    );
+
+    assert_eq!(
+        Seconds::<u64>::try_from(SiderealSeconds(86_400_u64)),
+        Ok(Seconds(86_164_u64))
+    );
+    assert_eq!(
+        Ticks32k::<u32>::try_from(Generic::from(Frames(1_u32))),
+        Ok(Ticks32k(546_u32))
+    );
+    assert_eq!(
+        Ticks32k::<u32>::try_from(duration::Scaled::<u32, 1, 1_024>(3)),
+        Ok(Ticks32k(96_u32))
+    );
+    assert_eq!(
+        duration::Scaled::<u32, 1, 1_024>::try_from(Ticks32k(96_u32)),
+        Ok(duration::Scaled(3_u32))
+    );
+    assert_eq!(
+        Frames::<u32>::try_from(Generic::new(1_u32, Fraction::new(1, 20))),
+        Ok(Frames(3_u32))
+    );
+    assert_eq!(
+        Generic::from(Frames(3_u32)),
+        Generic::new(1_u32, Fraction::new(1, 20))
+    );
+
+    assert_eq!(Frames(60_u32) + Seconds(1_u32), Frames(120_u32));
+    assert_eq!(Frames(60_u32) - Milliseconds(500_u32), Frames(30_u32));
+    assert_eq!(Frames(61_u32) % Seconds(1_u32), Frames(1_u32));
+    assert_eq!(Frames(3_u32) * 2, Frames(6_u32));
+    assert_eq!(Frames(6_u32) / 2, Frames(3_u32));
+    assert_eq!(
+        Frames(60_u32).checked_add(Hours(u32::MAX)),
+        Err(ConversionError::Overflow)
+    );
+
+    assert_eq!(Frames(5_u32), Frames(5_u64));
+    assert!(Frames(4_u32) < Frames(5_u64));
+    assert_eq!(Frames(30_u32).to_string(), "30");
+    assert_eq!(Frames(90_u32).display_human().to_string(), "1.5 s");
+    assert_eq!("0.5s".parse(), Ok(Frames(30_u32)));
+    assert_eq!(
+        "10ms".parse::<Frames>(),
+        Err(ConversionError::PrecisionLoss)
+    );
+
+    assert_eq!(Frames(3_u32).add_finer(Frames(2_u32)), Frames(5_u32));
+    assert_eq!(Frames::<u64>::from(Frames(3_u32)), Frames(3_u64));
+    assert_eq!(
+        Frames::<u32>::try_from(Frames(u64::MAX)),
+        Err(ConversionError::ConversionFailure)
+    );
+    const FRAME: u32 = Frames(1_u32).const_into_ticks(Fraction::new(1, 1_000));
+    assert_eq!(FRAME, 16);
+    assert_eq!(Frames(5_u32).const_widen(), Frames(5_u64));
+
+    assert_eq!(
+        core::time::Duration::try_from(Frames(90_u32)),
+        Ok(core::time::Duration::from_millis(1_500))
+    );
+    assert_eq!(
+        Frames::<u32>::try_from(core::time::Duration::from_millis(1_510)),
+        Ok(Frames(90_u32))
+    );
+    assert_eq!(
+        Frames::<u32>::try_from(core::time::Duration::from_secs(u64::MAX)),
+        Err(ConversionError::ConversionFailure)
     );
+    assert_eq!(Frames(1_u32).to_rate(), Ok(Hertz(60_u32)));
 }
@@ -4,59 +4,203 @@ use test_case::test_case;
 
 #[test_case((6, 3) => Ok((2,1)) ; "Reduce the fraction if possible")]
 #[test_case((6, 0) => Err(ConversionError::DivByZero) ; "A denominator of 0 will fail")]
-fn new_reduce(fraction: (u32, u32)) -> Result<(u32, u32), ConversionError> {
+fn new_reduce(fraction: (u64, u64)) -> Result<(u64, u64), ConversionError> {
     Fraction::new_reduce(fraction.0, fraction.1)
         .map(|fraction| (*fraction.numerator(), *fraction.denominator()))
 }
 
 #[test_case((3, 1) => 3 ; "Returns integer, no truncation required")]
 #[test_case((5, 2) => 2 ; "Returns integer, truncation required")]
-fn to_integer(fraction: (u32, u32)) -> u32 {
+fn to_integer(fraction: (u64, u64)) -> u64 {
     Fraction::new(fraction.0, fraction.1).to_integer()
 }
 
 #[test_case(3 => (3,1) ; "Returns integer as fraction")]
-fn from_integer(integer: u32) -> (u32, u32) {
+fn from_integer(integer: u64) -> (u64, u64) {
     let fraction = Fraction::from_integer(integer);
     (*fraction.numerator(), *fraction.denominator())
 }
 
 #[test_case(u32::MAX, (3,5) => u32::MAX / 5 * 3 ; "Properly handles potential overflows when possible")]
 #[test_case(u32::MAX, (2,1) => panics "")]
-fn u32_mul_fraction(integer: u32, fraction: (u32, u32)) -> u32 {
+fn u32_mul_fraction(integer: u32, fraction: (u64, u64)) -> u32 {
     integer * Fraction::new(fraction.0, fraction.1)
 }
 
 #[test_case(u64::MAX, (3,5) => u64::MAX / 5 * 3 ; "Properly handles potential overflows when possible")]
 #[test_case(u64::MAX, (2,1) => panics "")]
-fn u64_mul_fraction(integer: u64, fraction: (u32, u32)) -> u64 {
+fn u64_mul_fraction(integer: u64, fraction: (u64, u64)) -> u64 {
     integer * Fraction::new(fraction.0, fraction.1)
 }
 
 #[test]
 fn fraction_mul_fraction() {
     let product = Fraction::new(1_000, 1) * Fraction::new(5, 5);
-    assert_eq!(*product.numerator(), 1_000_u32);
-    assert_eq!(*product.denominator(), 1_u32);
+    assert_eq!(*product.numerator(), 1_000_u64);
+    assert_eq!(*product.denominator(), 1_u64);
 }
 
 #[test_case(12, (4,3) => 9 ; "Returns integer result")]
 #[test_case(u32::MAX, (5,3) => u32::MAX / 5 * 3 ; "Properly handles potential overflows when possible")]
 #[test_case(u32::MAX, (1,2) => panics "")]
-fn u32_div_fraction(integer: u32, fraction: (u32, u32)) -> u32 {
+fn u32_div_fraction(integer: u32, fraction: (u64, u64)) -> u32 {
     integer / Fraction::new(fraction.0, fraction.1)
 }
 
 #[test_case(12_u64, (4,3) => 9_u64 ; "Returns integer result")]
 #[test_case(u64::MAX, (5,3) => u64::MAX / 5 * 3 ; "Properly handles potential overflows when possible")]
 #[test_case(u64::MAX, (1,2) => panics "")]
-fn u64_div_fraction(integer: u64, fraction: (u32, u32)) -> u64 {
+fn u64_div_fraction(integer: u64, fraction: (u64, u64)) -> u64 {
     integer / Fraction::new(fraction.0, fraction.1)
 }
 
 #[test]
 fn fraction_div_fraction() {
     let product = Fraction::new(1_000, 1) / Fraction::new(10, 1_000);
-    assert_eq!(*product.numerator(), 100_000_u32);
-    assert_eq!(*product.denominator(), 1_u32);
+    assert_eq!(*product.numerator(), 100_000_u64);
+    assert_eq!(*product.denominator(), 1_u64);
+}
+
+#[test]
+fn sixty_four_bit() {
+    let picosecond = Fraction::new(1, 1_000_000_000_000);
+
+    // the intermediate product doesn't fit in a `u64`
+    assert_eq!(u64::MAX * Fraction::new(1_000, 1_000), u64::MAX);
+    assert_eq!(
+        u32::MAX * Fraction::new(1_000_000_000_000, 1_000_000_000_000),
+        u32::MAX
+    );
+    assert_eq!(3_000_000_000_000_u64 * picosecond, 3);
+    assert_eq!(3_u64 / picosecond, 3_000_000_000_000);
+
+    // the operands are reduced before multiplying
+    assert_eq!(
+        picosecond.checked_mul(&Fraction::new(1_000_000_000_000, 3)),
+        Some(Fraction::new(1, 3))
+    );
+    assert_eq!(
+        Fraction::new(5_000_000, 1).checked_div(&picosecond),
+        Some(Fraction::new(5_000_000_000_000_000_000, 1))
+    );
+    assert_eq!(Fraction::new(u64::MAX, 1).checked_div(&picosecond), None);
+}
+
+#[test_case((1, 12_288_000) => Ok(((1, 12_288_000), true)) ; "Fits exactly")]
+#[test_case((4_000_000_000, 8_000_000_000) => Ok(((1, 2), true)) ; "Reduces to fit exactly")]
+#[test_case((1_000_000_000, 14_745_600_000) => Ok(((625, 9_216), true)) ; "Reduces an odd crystal period")]
+#[test_case((7, 6_000_000_000) => Ok(((5, 4_285_714_286), false)) ; "Denominator doesn't fit")]
+#[test_case((6_000_000_000, 7) => Ok(((4_285_714_286, 5), false)) ; "Numerator doesn't fit")]
+#[test_case((8_589_934_592, 1) => Err(ConversionError::Overflow) ; "Value doesn't fit")]
+#[test_case((1, 0) => Err(ConversionError::DivByZero) ; "A denominator of 0 will fail")]
+fn approximate(fraction: (u64, u64)) -> Result<((u64, u64), bool), ConversionError> {
+    Fraction::approximate(fraction.0, fraction.1).map(|approximation| {
+        let fraction = approximation.fraction();
+        (
+            (*fraction.numerator(), *fraction.denominator()),
+            approximation.is_exact(),
+        )
+    })
+}
+
+#[test]
+fn approximation_error() {
+    let approximation = Fraction::approximate(7, 6_000_000_000).unwrap();
+    // |5 / 4_285_714_286 - 7 / 6_000_000_000| = 2 / 25_714_285_716_000_000_000
+    assert!(approximation.error() >= Fraction::new(1, 12_857_142_858_000_000_000));
+    assert!(approximation.error() < Fraction::new(1, 12_000_000_000_000_000_000));
+
+    let approximation = Fraction::approximate(u64::MAX - 1, u64::MAX).unwrap();
+    assert_eq!(approximation.fraction(), Fraction::new(1, 1));
+    assert!(approximation.error() >= Fraction::new(1, u64::MAX));
+}
+
+#[cfg(feature = "f64")]
+#[test_case(0. => Ok((0, 1)) ; "Zero")]
+#[test_case(0.000_030_517_578_125 => Ok((1, 32_768)) ; "Exact power of two")]
+#[test_case(1e-9 => Ok((1, 1_000_000_000)) ; "Nanoseconds")]
+#[test_case(4_294_967_295. => Ok((4_294_967_295, 1)) ; "Largest integer")]
+#[test_case(4_294_967_296. => Err(ConversionError::Overflow) ; "Value doesn't fit")]
+#[test_case(-1. => Err(ConversionError::ConversionFailure) ; "Negative")]
+#[test_case(f64::NAN => Err(ConversionError::ConversionFailure) ; "NaN")]
+fn from_f64(value: f64) -> Result<(u64, u64), ConversionError> {
+    Fraction::from_f64(value).map(|approximation| {
+        let fraction = approximation.fraction();
+        (*fraction.numerator(), *fraction.denominator())
+    })
+}
+
+#[test_case((1, 3), (1, 6) => Some((1, 2)) ; "Adds and reduces")]
+#[test_case((u64::MAX, 1), (1, 1) => None ; "Overflow fails")]
+fn fraction_add_fraction(lhs: (u64, u64), rhs: (u64, u64)) -> Option<(u64, u64)> {
+    let sum = Fraction::new(lhs.0, lhs.1).checked_add(&Fraction::new(rhs.0, rhs.1));
+    assert_eq!(
+        sum.is_some(),
+        std::panic::catch_unwind(|| Fraction::new(lhs.0, lhs.1) + Fraction::new(rhs.0, rhs.1))
+            .is_ok()
+    );
+    sum.map(|sum| (*sum.numerator(), *sum.denominator()))
+}
+
+#[test_case((1, 2), (1, 6) => Some((1, 3)) ; "Subtracts and reduces")]
+#[test_case((1, 6), (1, 2) => None ; "A negative result fails")]
+fn fraction_sub_fraction(lhs: (u64, u64), rhs: (u64, u64)) -> Option<(u64, u64)> {
+    let difference = Fraction::new(lhs.0, lhs.1).checked_sub(&Fraction::new(rhs.0, rhs.1));
+    assert_eq!(
+        difference.is_some(),
+        std::panic::catch_unwind(|| Fraction::new(lhs.0, lhs.1) - Fraction::new(rhs.0, rhs.1))
+            .is_ok()
+    );
+    difference.map(|difference| (*difference.numerator(), *difference.denominator()))
+}
+
+#[test]
+fn fraction_cmp_integer() {
+    assert_eq!(Fraction::new(6, 3), 2);
+    assert_ne!(Fraction::new(5, 3), 2);
+    assert!(Fraction::new(5, 3) < 2);
+    assert!(Fraction::new(7, 3) > 2);
+    assert!(Fraction::new(1, 32_768) > 0);
+}
+
+#[test_case((5, 2) => (2, 3, 3) ; "Half-way rounds up")]
+#[test_case((7, 3) => (2, 3, 2) ; "Rounds down")]
+#[test_case((8, 3) => (2, 3, 3) ; "Rounds up")]
+#[test_case((6, 3) => (2, 2, 2) ; "Integer")]
+#[test_case((u64::MAX, u64::MAX - 1) => (1, 2, 1) ; "Large operands")]
+fn floor_ceil_round(fraction: (u64, u64)) -> (u64, u64, u64) {
+    let fraction = Fraction::new(fraction.0, fraction.1);
+    (fraction.floor(), fraction.ceil(), fraction.round())
+}
+
+#[test_case((1, 32_768), 9 => "0.000030517" ; "Truncates")]
+#[test_case((3, 2), 3 => "1.500" ; "Pads with zeros")]
+#[test_case((u64::MAX, 1), 1 => "18446744073709551615.0" ; "Large integer")]
+#[test_case((7, 3), 0 => "2" ; "No fractional digits")]
+#[test_case((1, 0), 3 => "1/0" ; "A denominator of 0 is written as a fraction")]
+fn to_decimal(fraction: (u64, u64), precision: usize) -> String {
+    Fraction::new(fraction.0, fraction.1)
+        .to_decimal(precision)
+        .to_string()
+}
+
+#[test_case("1/32768" => Ok((1, 32_768)) ; "Fraction")]
+#[test_case(" 5000 / 5 " => Ok((5_000, 5)) ; "Not reduced, whitespace")]
+#[test_case("3" => Ok((3, 1)) ; "Integer")]
+#[test_case("1/0" => Err(ConversionError::DivByZero) ; "A denominator of 0 will fail")]
+#[test_case("1/x" => Err(ConversionError::ParseFailure) ; "Invalid denominator")]
+#[test_case("" => Err(ConversionError::ParseFailure) ; "Empty")]
+fn from_str(s: &str) -> Result<(u64, u64), ConversionError> {
+    s.parse::<Fraction>()
+        .map(|fraction| (*fraction.numerator(), *fraction.denominator()))
+}
+
+#[test]
+fn display() {
+    assert_eq!(Fraction::new(1, 32_768).to_string(), "1/32768");
+    assert_eq!(Fraction::new(3, 1).to_string(), "3/1");
+    assert_eq!(
+        Fraction::new(1, 32_768).to_string().parse(),
+        Ok(Fraction::new(1, 32_768))
+    );
 }
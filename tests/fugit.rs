@@ -0,0 +1,136 @@
+#![cfg(feature = "fugit")]
+
+use core::cell::Cell;
+use core::convert::TryFrom;
+use embedded_time::{
+    duration::{self, *},
+    fugit::FugitClock,
+    rate::{self, *},
+    Clock as _, ConversionError, Instant,
+};
+
+#[derive(Debug)]
+struct Clock;
+
+impl embedded_time::Clock for Clock {
+    type T = u32;
+    const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+
+    fn try_now(&self) -> Result<Instant<Self>, embedded_time::clock::Error> {
+        Ok(Instant::new(0))
+    }
+}
+
+#[test]
+fn duration() {
+    assert_eq!(
+        Milliseconds::<u32>::try_from(fugit::SecsDurationU32::from_ticks(2)),
+        Ok(Milliseconds(2_000_u32))
+    );
+    assert_eq!(
+        Seconds::<u64>::try_from(fugit::MillisDurationU64::from_ticks(2_999)),
+        Ok(Seconds(2_u64))
+    );
+    assert_eq!(
+        fugit::MicrosDurationU32::try_from(Milliseconds(5_u32)),
+        Ok(fugit::MicrosDurationU32::from_ticks(5_000))
+    );
+    assert_eq!(
+        fugit::TimerDurationU32::<32_768>::try_from(Milliseconds(250_u32)),
+        Ok(fugit::TimerDurationU32::<32_768>::from_ticks(8_192))
+    );
+    assert_eq!(
+        fugit::NanosDurationU32::try_from(Seconds(u32::MAX)),
+        Err(ConversionError::Overflow)
+    );
+    assert_eq!(
+        Days::<u32>::try_from(fugit::HoursDurationU32::from_ticks(49)),
+        Ok(Days(2_u32))
+    );
+    assert_eq!(
+        fugit::HoursDurationU32::try_from(Weeks(1_u32)),
+        Ok(fugit::HoursDurationU32::from_ticks(168))
+    );
+    assert_eq!(
+        Weeks::<u64>::try_from(fugit::SecsDurationU64::from_ticks(1_209_600)),
+        Ok(Weeks(2_u64))
+    );
+    assert_eq!(
+        Picoseconds::<u64>::try_from(fugit::NanosDurationU32::from_ticks(3)),
+        Ok(Picoseconds(3_000_u64))
+    );
+    assert_eq!(
+        fugit::NanosDurationU64::try_from(Picoseconds(2_999_u32)),
+        Ok(fugit::NanosDurationU64::from_ticks(2))
+    );
+    assert_eq!(
+        Picoseconds::<u32>::try_from(fugit::MillisDurationU32::from_ticks(5)),
+        Err(ConversionError::Overflow)
+    );
+
+    assert_eq!(
+        duration::Scaled::from(fugit::TimerDurationU32::<32_768>::from_ticks(3)),
+        duration::Scaled::<u32, 1, 32_768>(3)
+    );
+    assert_eq!(
+        fugit::TimerDurationU64::<32_768>::from(duration::Scaled::<u64, 1, 32_768>(3)),
+        fugit::TimerDurationU64::<32_768>::from_ticks(3)
+    );
+}
+
+#[test]
+fn rate() {
+    assert_eq!(
+        Hertz::<u32>::try_from(fugit::KilohertzU32::from_raw(32)),
+        Ok(Hertz(32_000_u32))
+    );
+    assert_eq!(
+        fugit::MegahertzU64::try_from(Kilohertz(16_000_u32)),
+        Ok(fugit::MegahertzU64::from_raw(16))
+    );
+    assert_eq!(
+        fugit::HertzU32::try_from(Megahertz(u32::MAX)),
+        Err(ConversionError::Overflow)
+    );
+
+    assert_eq!(
+        rate::Scaled::from(fugit::KilohertzU32::from_raw(3)),
+        rate::Scaled::<u32, 1_000, 1>(3)
+    );
+    assert_eq!(
+        fugit::KilohertzU32::from(rate::Scaled::<u32, 1_000, 1>(3)),
+        fugit::KilohertzU32::from_raw(3)
+    );
+}
+
+#[test]
+fn instant() {
+    assert_eq!(
+        Instant::<Clock>::try_from(fugit::TimerInstantU32::<10>::from_ticks(5)),
+        Ok(Instant::<Clock>::new(500))
+    );
+    assert_eq!(
+        fugit::TimerInstantU64::<1_000_000>::try_from(Instant::<Clock>::new(5)),
+        Ok(fugit::TimerInstantU64::<1_000_000>::from_ticks(5_000))
+    );
+}
+
+#[test]
+fn clock() {
+    let ticks = Cell::new(0_u64);
+    let clock = FugitClock::new(|| fugit::TimerInstantU64::<1_000>::from_ticks(ticks.get()));
+
+    let start = clock.try_now().unwrap();
+    ticks.set(1_500);
+    let elapsed = clock.try_now().unwrap() - start;
+
+    assert_eq!(elapsed.integer(), 1_500_u64);
+    assert_eq!(
+        Milliseconds::<u64>::try_from(elapsed),
+        Ok(Milliseconds(1_500_u64))
+    );
+    assert_eq!(
+        clock.new_timer(Seconds(1_u32)).start().unwrap().elapsed(),
+        Ok(Seconds(0_u32))
+    );
+}
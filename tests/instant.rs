@@ -1,8 +1,8 @@
-use core::convert::TryInto;
+use core::convert::{TryFrom, TryInto};
 use embedded_time::{
     self as time,
     duration::{self, *},
-    Clock as _, Instant,
+    Clock as _, ConversionError, Instant, Rounding,
 };
 use test_case::test_case;
 
@@ -28,10 +28,7 @@ fn duration_since() {
     let instant1 = clock.try_now().unwrap();
     let instant2 = clock.try_now().unwrap();
     let diff = instant2.checked_duration_since(&instant1);
-    assert_eq!(
-        diff,
-        Some(duration::Generic::new(1_u32, Fraction::new(1, 1_000)))
-    );
+    assert_eq!(diff, Some(Ticks::new(1)));
 
     let micros: Result<Microseconds<<Clock as time::Clock>::T>, _> = diff.unwrap().try_into();
     assert_eq!(micros, Ok(Microseconds(1_000_u32)));
@@ -46,10 +43,7 @@ fn duration_until() {
     let instant1 = clock.try_now().unwrap();
     let instant2 = clock.try_now().unwrap();
     let diff = instant1.checked_duration_until(&instant2);
-    assert_eq!(
-        diff,
-        Some(duration::Generic::new(1_u32, Fraction::new(1, 1_000)))
-    );
+    assert_eq!(diff, Some(Ticks::new(1)));
 
     let micros: Result<Microseconds<<Clock as time::Clock>::T>, _> = diff.unwrap().try_into();
     assert_eq!(micros, Ok(Microseconds(1_000_u32)));
@@ -93,3 +87,134 @@ fn checked_add(base: u32, addition: u32) -> Option<Instant<Clock>> {
 fn checked_sub(base: u32, subtrahend: u32) -> Option<Instant<Clock>> {
     Instant::<Clock>::new(base).checked_sub(Milliseconds(subtrahend))
 }
+
+#[test_case(0, 1_500, Rounding::Floor => Some(Instant::<Clock>::new(1)) ; "Round down")]
+#[test_case(0, 1_001, Rounding::Ceil => Some(Instant::<Clock>::new(2)) ; "Round up to the next tick")]
+#[test_case(0, 1_000, Rounding::Ceil => Some(Instant::<Clock>::new(1)) ; "Exact")]
+#[test_case(0, 1_500, Rounding::NearestEven => Some(Instant::<Clock>::new(2)) ; "Round half to even")]
+#[test_case(u32::MAX, 1, Rounding::Ceil => Some(Instant::<Clock>::new(0)) ; "Wrap around")]
+#[test_case(0, 2_147_483_647_001, Rounding::Ceil => None ; "Overflow due to rounding up")]
+fn checked_add_rounded(base: u32, addition: u64, rounding: Rounding) -> Option<Instant<Clock>> {
+    Instant::<Clock>::new(base).checked_add_rounded(Microseconds(addition), rounding)
+}
+
+#[test_case(10, 1_500, Rounding::Floor => Some(Instant::<Clock>::new(9)) ; "Round down")]
+#[test_case(10, 1_001, Rounding::Ceil => Some(Instant::<Clock>::new(8)) ; "Round up to the next tick")]
+#[test_case(10, 2_500, Rounding::NearestEven => Some(Instant::<Clock>::new(8)) ; "Round half to even")]
+fn checked_sub_rounded(base: u32, subtrahend: u64, rounding: Rounding) -> Option<Instant<Clock>> {
+    Instant::<Clock>::new(base).checked_sub_rounded(Microseconds(subtrahend), rounding)
+}
+
+#[test_case(0, 1 => Instant::<Clock>::new(1) ; "Add a small duration")]
+#[test_case(0, u32::MAX/2 + 1 => Instant::<Clock>::new(u32::MAX / 2) ; "Clamp to the maximum allowed duration")]
+#[test_case(u32::MAX, u32::MAX => Instant::<Clock>::new(u32::MAX / 2 - 1) ; "Clamp and wrap around")]
+fn saturating_add(base: u32, addition: u32) -> Instant<Clock> {
+    Instant::<Clock>::new(base).saturating_add(Milliseconds(addition))
+}
+
+#[test_case(10, 1 => Instant::<Clock>::new(9) ; "Subtract a small duration")]
+#[test_case(u32::MAX, u32::MAX => Instant::<Clock>::new(u32::MAX - u32::MAX / 2) ; "Clamp to the maximum allowed duration")]
+fn saturating_sub(base: u32, subtrahend: u32) -> Instant<Clock> {
+    Instant::<Clock>::new(base).saturating_sub(Milliseconds(subtrahend))
+}
+
+#[test]
+fn saturating_add_overflowing_conversion() {
+    assert_eq!(
+        Instant::<Clock>::new(0).saturating_add(Hours(u64::MAX)),
+        Instant::<Clock>::new(u32::MAX / 2)
+    );
+}
+
+#[test]
+fn ticks() {
+    let elapsed = Instant::<Clock>::new(5_001) - Instant::<Clock>::new(1);
+    assert_eq!(elapsed, Ticks::<Clock>::new(5_000));
+    assert_eq!(elapsed.integer(), 5_000);
+
+    assert_eq!(Seconds::<u32>::try_from(elapsed), Ok(Seconds(5_u32)));
+    assert_eq!(
+        Microseconds::<u64>::try_from(elapsed),
+        Ok(Microseconds(5_000_000_u64))
+    );
+    assert_eq!(
+        Ticks::<Clock>::try_from(Seconds(2_u32)),
+        Ok(Ticks::new(2_000))
+    );
+    assert_eq!(
+        Ticks::<Clock>::try_from(Seconds(2_u64)),
+        Ok(Ticks::new(2_000))
+    );
+    assert_eq!(
+        Ticks::<Clock>::try_from(Seconds(u32::MAX)),
+        Err(ConversionError::Overflow)
+    );
+    assert_eq!(
+        Ticks::<Clock>::try_from(duration::Scaled::<u32, 1, 100>(3)),
+        Ok(Ticks::new(30))
+    );
+    assert_eq!(
+        Ticks::<Clock>::try_from(duration::Generic::new(3_u32, Fraction::new(1, 100))),
+        Ok(Ticks::new(30))
+    );
+    assert_eq!(
+        duration::Generic::from(elapsed),
+        duration::Generic::from(Seconds(5_u32))
+    );
+
+    assert_eq!(elapsed + Seconds(1_u32), Ticks::new(6_000));
+    assert_eq!(elapsed - Ticks::new(1), Ticks::new(4_999));
+    assert_eq!(elapsed % Seconds(2_u32), Ticks::new(1_000));
+    assert_eq!(elapsed * 2, Ticks::new(10_000));
+    assert_eq!(elapsed.to_string(), "5000");
+
+    assert_eq!(
+        Instant::<Clock>::new(1).checked_add(elapsed),
+        Some(Instant::<Clock>::new(5_001))
+    );
+    assert_eq!(
+        Instant::<Clock>::new(5_001) - elapsed,
+        Instant::<Clock>::new(1)
+    );
+    assert_eq!(
+        elapsed + Instant::<Clock>::new(1),
+        Instant::<Clock>::new(5_001)
+    );
+}
+
+#[test]
+fn full_range_u64_conversions() {
+    /// A 12.288 MHz clock
+    #[derive(Debug)]
+    struct WideClock;
+
+    impl time::Clock for WideClock {
+        type T = u64;
+        const SCALING_FACTOR: Fraction = Fraction::new(1, 12_288_000);
+
+        fn try_now(&self) -> Result<Instant<Self>, time::clock::Error> {
+            unimplemented!()
+        }
+    }
+
+    // u64::MAX × 12_288_000 overflows a u64, but the result fits
+    let ticks = (u128::from(u64::MAX) * 12_288_000 / 1_000_000_000) as u64;
+    assert_eq!(
+        Ticks::<WideClock>::try_from(Nanoseconds(u64::MAX)),
+        Ok(Ticks::new(ticks))
+    );
+    assert_eq!(
+        Instant::<WideClock>::new(0).checked_add(Nanoseconds(u64::MAX)),
+        Some(Instant::new(ticks))
+    );
+
+    let nanoseconds = (u128::from(u64::MAX / 128) * 1_000_000_000 / 12_288_000) as u64;
+    assert_eq!(
+        Nanoseconds::<u64>::try_from(Ticks::<WideClock>::new(u64::MAX / 128)),
+        Ok(Nanoseconds(nanoseconds))
+    );
+    assert_eq!(
+        Nanoseconds::<u64>::try_from(Ticks::<WideClock>::new(u64::MAX)),
+        Err(ConversionError::Overflow)
+    );
+}
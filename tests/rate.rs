@@ -142,7 +142,7 @@ fn to_generic() {
     // Overflow error
     assert_eq!(
         Hertz(u32::MAX).to_generic::<u32>(Fraction::new(1, 2)),
-        Err(ConversionError::Unspecified)
+        Err(ConversionError::Overflow)
     );
 
     // From named
@@ -159,14 +159,31 @@ fn get_generic_integer() {
     assert_eq!(generic.integer(), 246_u32);
 }
 
+embedded_time::define_duration_unit!(
+    /// 1/3^26 s
+    TinySeconds, (1, 2_541_865_828_329)
+);
+
 #[test]
 fn convert_to_duration() {
     assert_eq!(Hertz(500_u32).to_duration(), Ok(Milliseconds(2_u32)));
     assert_eq!(Kilohertz(500_u32).to_duration(), Ok(Microseconds(2_u32)));
 
+    // intermediate values don't overflow
+    assert_eq!(Megahertz(u32::MAX).to_duration(), Ok(Hours(0_u32)));
+    assert_eq!(
+        Millihertz(1_u64).to_duration(),
+        Ok(Nanoseconds(1_000_000_000_000_u64))
+    );
+    // the product of the scaling factors (1/(2^32 - 1) × 1/3^26) doesn't fit in a `Fraction`
+    assert_eq!(
+        rate::Scaled::<u64, 1, 4_294_967_295>::new(6_847_565_142_666_285).to_duration(),
+        Ok(TinySeconds(1_594_323_u64))
+    );
+
     // Errors
     assert_eq!(
-        Megahertz(u32::MAX).to_duration::<Hours<u32>>(),
+        Millihertz(1_u32).to_duration::<Nanoseconds<u32>>(),
         Err(ConversionError::Overflow)
     );
     assert_eq!(
@@ -382,3 +399,402 @@ fn into_smaller() {
     test_into_smaller![Baud, Kilobaud, Megabaud];
     test_into_smaller![Baud, Kibibaud, Mebibaud];
 }
+
+#[test]
+fn display_human() {
+    assert_eq!(format!("{}", Hertz(0_u32).display_human()), "0 Hz");
+    assert_eq!(format!("{}", Hertz(999_u32).display_human()), "999 Hz");
+    assert_eq!(
+        format!("{}", Hertz(16_000_000_u32).display_human()),
+        "16 MHz"
+    );
+    assert_eq!(
+        format!("{}", Kilohertz(32_768_u32).display_human()),
+        "32.768 MHz"
+    );
+    assert_eq!(format!("{}", Kibihertz(32_u32).display_human()), "32 KiHz");
+    assert_eq!(
+        format!("{}", Microhertz(1_u32).display_human()),
+        "0.000001 Hz"
+    );
+    assert_eq!(
+        format!("{}", Baud(115_200_u32).display_human()),
+        "115.2 kBd"
+    );
+    assert_eq!(
+        format!("{}", MegabitsPerSecond(1_u32).display_human()),
+        "1 Mbps"
+    );
+    assert_eq!(
+        format!("{}", BytesPerSecond(1_500_u32).display_human()),
+        "1.5 kBps"
+    );
+    assert_eq!(
+        format!("{}", MebibytesPerSecond(u32::MAX).display_human()),
+        "4294967295 MiBps"
+    );
+    assert_eq!(
+        format!("{:.2}", Kilobaud(9_u32).display_human()),
+        "9.00 kBd"
+    );
+    assert_eq!(
+        format!(
+            "{}",
+            rate::Generic::new(1_u32, Fraction::new(1, 3)).display_human()
+        ),
+        "0.333333333 Hz"
+    );
+    assert_eq!(
+        format!(
+            "{}",
+            rate::Generic::new(3_u32, Fraction::new(1_024, 1)).display_human()
+        ),
+        "3 KiHz"
+    );
+    assert_eq!(
+        format!(
+            "{}",
+            rate::Generic::new(3_u32, Fraction::new(2_048, 2)).display_human()
+        ),
+        "3 KiHz"
+    );
+    assert_eq!(
+        format!("{}", rate::Generic::from(Mebihertz(5_u32)).display_human()),
+        "5 MiHz"
+    );
+    assert_eq!(
+        format!(
+            "{}",
+            rate::Generic::new(5_u32, Fraction::new(1_000, 1)).display_human()
+        ),
+        "5 kHz"
+    );
+
+    assert_eq!(
+        format!(
+            "{}",
+            rate::Generic::new(5_u32, Fraction::new(1, 0)).display_human()
+        ),
+        "5 x 1/0 Hz"
+    );
+
+    // width, fill and alignment
+    assert_eq!(
+        format!("[{:>10}]", Baud(115_200_u32).display_human()),
+        "[ 115.2 kBd]"
+    );
+    assert_eq!(
+        format!("[{:-<10.2}]", Hertz(1_500_u32).display_human()),
+        "[1.50 kHz--]"
+    );
+}
+
+#[test]
+fn parse() {
+    assert_eq!("16MHz".parse(), Ok(Hertz(16_000_000_u32)));
+    assert_eq!(" 16 MHz ".parse(), Ok(Kilohertz(16_000_u32)));
+    assert_eq!("1.5 MiHz".parse(), Ok(Kibihertz(1_536_u32)));
+    assert_eq!("0.5Hz".parse(), Ok(Millihertz(500_u32)));
+    assert_eq!("115200 Bd".parse(), Ok(Baud(115_200_u32)));
+    assert_eq!("1 kBps".parse(), Ok(BitsPerSecond(8_000_u32)));
+    assert_eq!("16 kbps".parse(), Ok(BytesPerSecond(2_000_u32)));
+    assert_eq!("1 Mbps".parse(), Ok(KilobitsPerSecond(1_000_u64)));
+
+    assert_eq!("".parse::<Hertz>(), Err(ConversionError::ParseFailure));
+    assert_eq!("MHz".parse::<Hertz>(), Err(ConversionError::ParseFailure));
+    assert_eq!(
+        "16 mhz".parse::<Hertz>(),
+        Err(ConversionError::ParseFailure)
+    );
+    assert_eq!(
+        "1 Hz 2 Hz".parse::<Hertz>(),
+        Err(ConversionError::ParseFailure)
+    );
+    assert_eq!(
+        "9600 bps".parse::<Baud>(),
+        Err(ConversionError::ConversionFailure)
+    );
+    assert_eq!(
+        "1 bps".parse::<BytesPerSecond>(),
+        Err(ConversionError::PrecisionLoss)
+    );
+    assert_eq!(
+        "4294967.296 Hz".parse::<Millihertz<u32>>(),
+        Err(ConversionError::Overflow)
+    );
+}
+
+#[test]
+fn parse_generic() {
+    assert_eq!(
+        "16MHz".parse::<rate::Generic<u32>>(),
+        Ok(rate::Generic::new(16_u32, Fraction::new(1_000_000, 1)))
+    );
+    assert_eq!(
+        "1.25 Hz".parse::<rate::Generic<u32>>(),
+        Ok(rate::Generic::new(125_u32, Fraction::new(1, 100)))
+    );
+    assert_eq!(
+        "3 KiBps".parse::<rate::Generic<u32>>(),
+        Ok(rate::Generic::new(3_u32, Fraction::new(1_024 * 8, 1)))
+    );
+    assert_eq!(
+        "0.0000001 Hz".parse::<rate::Generic<u32>>(),
+        Err(ConversionError::PrecisionLoss)
+    );
+    assert_eq!(
+        "4294967297 Bd".parse::<rate::Generic<u32>>(),
+        Err(ConversionError::Overflow)
+    );
+}
+
+#[test]
+#[cfg(feature = "f32")]
+fn f32_conversion() {
+    assert_eq!(Hertz(0_u32).as_hz_f32(), 0.0);
+    assert_eq!(Megahertz(16_u32).as_hz_f32(), 16e6);
+    assert_eq!(KilobytesPerSecond(1_u32).as_hz_f32(), 8_000.0);
+    assert_eq!(
+        rate::Generic::new(1_u32, Fraction::new(1, 3)).as_hz_f32(),
+        1.0 / 3.0
+    );
+
+    assert_eq!(Baud::<u32>::from_hz_f32(115_200.0), Ok(Baud(115_200_u32)));
+    assert_eq!(
+        BytesPerSecond::<u32>::from_hz_f32(12.0),
+        Ok(BytesPerSecond(2_u32))
+    );
+    assert_eq!(
+        Hertz::<u32>::from_hz_f32(-1.0),
+        Err(ConversionError::ConversionFailure)
+    );
+    assert_eq!(
+        rate::Generic::<u32>::from_hz_f32(1.5, Fraction::new(1, 2)),
+        Ok(rate::Generic::new(3_u32, Fraction::new(1, 2)))
+    );
+}
+
+#[test]
+#[cfg(feature = "f64")]
+fn f64_conversion() {
+    assert_eq!(Microhertz(1_u32).as_hz_f64(), 1e-6);
+    assert_eq!(Mebihertz(u64::MAX).as_hz_f64(), 1.934_281_311_383_406_7e25);
+
+    assert_eq!(
+        Millihertz::<u32>::from_hz_f64(0.0015),
+        Ok(Millihertz(2_u32))
+    );
+    assert_eq!(
+        Hertz::<u32>::from_hz_f64(f64::NAN),
+        Err(ConversionError::ConversionFailure)
+    );
+    assert_eq!(
+        Hertz::<u32>::from_hz_f64(1e10),
+        Err(ConversionError::Overflow)
+    );
+}
+
+#[test]
+fn try_from_rounded() {
+    use embedded_time::Rounding;
+
+    assert_eq!(
+        Kilohertz::<u32>::try_from_rounded(Hertz(32_768_u32), Rounding::Floor),
+        Ok(Kilohertz(32_u32))
+    );
+    assert_eq!(
+        Kilohertz::<u32>::try_from_rounded(Hertz(32_768_u32), Rounding::Ceil),
+        Ok(Kilohertz(33_u32))
+    );
+    assert_eq!(
+        Kilohertz::<u32>::try_from_rounded(Hertz(32_768_u32), Rounding::NearestEven),
+        Ok(Kilohertz(33_u32))
+    );
+    assert_eq!(
+        KilobytesPerSecond::<u32>::try_from_rounded(BitsPerSecond(12_000_u32), Rounding::Ceil),
+        Ok(KilobytesPerSecond(2_u32))
+    );
+}
+
+#[test]
+fn saturating() {
+    assert_eq!(
+        Hertz(1_u32).saturating_add(Kilohertz(1_u32)),
+        Hertz(1_001_u32)
+    );
+    assert_eq!(
+        Hertz(1_u32).saturating_add(Megahertz(u32::MAX)),
+        Hertz(u32::MAX)
+    );
+    assert_eq!(Baud(1_u32).saturating_sub(Kilobaud(1_u32)), Baud(0_u32));
+    assert_eq!(
+        BitsPerSecond(u32::MAX).saturating_mul(8),
+        BitsPerSecond(u32::MAX)
+    );
+    assert_eq!(
+        BitsPerSecond::<u32>::saturating_from(KilobytesPerSecond(1_u32)),
+        BitsPerSecond(8_000_u32)
+    );
+    assert_eq!(
+        Hertz::<u32>::saturating_from(Megahertz(u64::MAX)),
+        Hertz(u32::MAX)
+    );
+}
+
+#[test]
+fn checked_mixed_unit_arithmetic() {
+    assert_eq!(
+        Hertz(1_u32).checked_add(Kilohertz(1_u32)),
+        Ok(Hertz(1_001_u32))
+    );
+    assert_eq!(
+        Hertz(u32::MAX).checked_add(Hertz(1_u32)),
+        Err(ConversionError::Overflow)
+    );
+    assert!(Hertz(1_u32).checked_add(Megahertz(u64::MAX)).is_err());
+
+    assert_eq!(
+        Hertz(2_001_u32).checked_sub(Kilohertz(1_u32)),
+        Ok(Hertz(1_001_u32))
+    );
+    assert_eq!(
+        Hertz(1_u32).checked_sub(Hertz(2_u32)),
+        Err(ConversionError::Overflow)
+    );
+    assert_eq!(
+        Kilohertz(2_u32).checked_sub(Hertz(1_999_u32)),
+        Err(ConversionError::PrecisionLoss)
+    );
+    assert_eq!(
+        Kilohertz(2_u32).checked_add(Hertz(1_000_u32)),
+        Ok(Kilohertz(3_u32))
+    );
+
+    assert_eq!(
+        Hertz(2_037_u32).checked_rem(Kilohertz(1_u32)),
+        Ok(Hertz(37_u32))
+    );
+    assert_eq!(
+        Hertz(5_u32).checked_rem(Kilohertz(0_u32)),
+        Err(ConversionError::DivByZero)
+    );
+    assert_eq!(
+        Kilohertz(5_u32).checked_rem(Hertz(1_500_u32)),
+        Err(ConversionError::PrecisionLoss)
+    );
+}
+
+#[test]
+fn scaled() {
+    type Crystals = rate::Scaled<u32, 32_768, 1>;
+
+    assert_eq!(Crystals::new(2).integer(), 2_u32);
+    assert_eq!(Crystals::SCALING_FACTOR, Fraction::new(32_768, 1));
+
+    assert_eq!(
+        Hertz::<u32>::try_from(Crystals::new(2)),
+        Ok(Hertz(65_536_u32))
+    );
+    assert_eq!(
+        Crystals::try_from(Kibihertz(64_u32)),
+        Ok(rate::Scaled(2_u32))
+    );
+    assert_eq!(
+        Crystals::new(1).try_convert::<rate::Scaled<u32, 1_024, 1>>(),
+        Ok(rate::Scaled(32_u32))
+    );
+    assert_eq!(
+        rate::Generic::from(Crystals::new(1)),
+        rate::Generic::new(1_u32, Fraction::new(32_768, 1))
+    );
+
+    assert_eq!(Crystals::new(1) + Kibihertz(32_u32), Crystals::new(2_u32));
+    assert_eq!(Crystals::new(2) - Kibihertz(32_u32), Crystals::new(1_u32));
+    assert_eq!(Crystals::new(2) * 3, Crystals::new(6_u32));
+
+    assert_eq!(Crystals::new(2), Kibihertz(64_u32));
+    assert_eq!(Hertz(65_536_u64), Crystals::new(2));
+    assert!(Crystals::new(1) > Kilohertz(32_u32));
+    assert!(Kilohertz(33_u32) > Crystals::new(1));
+    assert_eq!(Crystals::new(1), rate::Scaled::<u64, 1_024, 1>(32));
+    assert!(Crystals::new(1) < rate::Scaled::<u32, 1_000, 1>(33));
+    assert!(Crystals::new(1) > rate::Scaled::<u32, 1_000, 1>(32));
+
+    assert_eq!("65.536 kHz".parse(), Ok(Crystals::new(2)));
+    assert_eq!(
+        "1 Bd".parse::<Crystals>(),
+        Err(ConversionError::ConversionFailure)
+    );
+    assert_eq!(Crystals::new(2).display_human().to_string(), "64 KiHz");
+}
+
+#[test]
+fn const_conversion() {
+    const HZ: u32 = Kilohertz(32_u32).const_into_ticks(Fraction::new(1, 1));
+    const MILLIHERTZ: u64 = Kibihertz(1_u32)
+        .const_widen()
+        .const_into_ticks(Millihertz::<u64>::SCALING_FACTOR);
+    const SCALED: u32 =
+        rate::Scaled::<u32, 32_768, 1>::new(2).const_into_ticks(Fraction::new(1_024, 1));
+
+    assert_eq!(HZ, 32_000);
+    assert_eq!(MILLIHERTZ, 1_024_000);
+    assert_eq!(SCALED, 64);
+    assert_eq!(
+        Megahertz(u32::MAX).const_checked_into_ticks(Fraction::new(1, 1)),
+        None
+    );
+}
+
+embedded_time::define_rate_unit!(
+    /// Revolutions per minute
+    Rpm, (1, 60)
+);
+
+#[test]
+fn user_defined_units() {
+    assert_eq!(Rpm::<u32>::try_from(Hertz(50_u32)), Ok(Rpm(3_000_u32)));
+    assert_eq!(Rpm::<u64>::try_from(Kilohertz(1_u32)), Ok(Rpm(60_000_u64)));
+    assert_eq!(
+        Millihertz::<u32>::try_from(Rpm(3_u32)),
+        Ok(Millihertz(50_u32))
+    );
+    assert_eq!(Hertz::<u32>::try_from(Rpm(59_u32)), Ok(Hertz(0_u32)));
+    assert_eq!(
+        Rpm::<u32>::try_from(Megahertz(u32::MAX)),
+        Err(ConversionError::Overflow)
+    );
+    assert_eq!(
+        Rpm::<u32>::try_from(rate::Scaled::<u32, 1, 2>(1)),
+        Ok(Rpm(30_u32))
+    );
+    assert_eq!(
+        rate::Scaled::<u32, 1, 2>::try_from(Rpm(30_u32)),
+        Ok(rate::Scaled(1_u32))
+    );
+    assert_eq!(
+        Rpm::<u32>::try_from(rate::Generic::new(1_u32, Fraction::new(1, 1))),
+        Ok(Rpm(60_u32))
+    );
+
+    assert_eq!(Rpm(60_u32) + Hertz(1_u32), Rpm(120_u32));
+    assert_eq!(Rpm(120_u32) - Hertz(1_u32), Rpm(60_u32));
+    assert_eq!(Rpm(61_u32) % Hertz(1_u32), Rpm(1_u32));
+    assert_eq!(Rpm(3_u32) * 2, Rpm(6_u32));
+    assert_eq!(Rpm(6_u32) / 2, Rpm(3_u32));
+
+    assert_eq!(Rpm(5_u32), Rpm(5_u64));
+    assert!(Rpm(4_u32) < Rpm(5_u64));
+    assert_eq!(Rpm(90_u32).display_human().to_string(), "1.5 Hz");
+    assert_eq!("2 Hz".parse(), Ok(Rpm(120_u32)));
+    assert_eq!(
+        "2 Bd".parse::<Rpm>(),
+        Err(ConversionError::ConversionFailure)
+    );
+    assert_eq!(Rpm(60_u32).to_duration(), Ok(Seconds(1_u32)));
+
+    assert_eq!(Rpm::<u64>::from(Rpm(3_u32)), Rpm(3_u64));
+    const HERTZ: u32 = Rpm(120_u32).const_into_ticks(Fraction::new(1, 1));
+    assert_eq!(HERTZ, 2);
+    assert_eq!(Rpm(5_u32).const_widen(), Rpm(5_u64));
+}
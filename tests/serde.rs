@@ -0,0 +1,161 @@
+#![cfg(feature = "serde")]
+
+use embedded_time::{
+    duration::{self, *},
+    fraction::Fraction,
+    rate::{self, *},
+    Instant,
+};
+use serde::{de::DeserializeOwned, Serialize};
+
+#[derive(Debug)]
+struct Clock;
+
+impl embedded_time::Clock for Clock {
+    type T = u32;
+    const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+
+    fn try_now(&self) -> Result<Instant<Self>, embedded_time::clock::Error> {
+        Ok(Instant::new(0))
+    }
+}
+
+fn json<T: Serialize>(value: &T) -> String {
+    serde_json::to_string(value).unwrap()
+}
+
+fn from_json<T: DeserializeOwned>(s: &str) -> Result<T, serde_json::Error> {
+    serde_json::from_str(s)
+}
+
+fn postcard_round_trip<T: Serialize + DeserializeOwned>(value: &T) -> (usize, T) {
+    let mut buffer = [0_u8; 32];
+    let bytes = postcard::to_slice(value, &mut buffer).unwrap();
+    (bytes.len(), postcard::from_bytes(bytes).unwrap())
+}
+
+#[test]
+fn duration() {
+    assert_eq!(json(&Milliseconds(250_u32)), r#""250ms""#);
+    assert_eq!(json(&Microseconds(5_u64)), r#""5us""#);
+    assert_eq!(json(&Minutes(3_u32)), r#""3min""#);
+    assert_eq!(json(&Days(3_u32)), r#""3d""#);
+    assert_eq!(json(&Weeks(2_u32)), r#""2w""#);
+    assert_eq!(json(&Picoseconds(500_u64)), r#""500ps""#);
+
+    assert_eq!(
+        from_json::<Milliseconds<u32>>(r#""250ms""#).unwrap(),
+        Milliseconds(250_u32)
+    );
+    assert_eq!(
+        from_json::<Milliseconds<u32>>(r#""1.5s""#).unwrap(),
+        Milliseconds(1_500_u32)
+    );
+    assert_eq!(
+        from_json::<Seconds<u32>>(r#""1h 30min""#).unwrap(),
+        Seconds(5_400_u32)
+    );
+    assert_eq!(
+        from_json::<Hours<u32>>(r#""1w 1d""#).unwrap(),
+        Hours(192_u32)
+    );
+    assert!(from_json::<Seconds<u32>>(r#""1.5s""#).is_err());
+    assert!(from_json::<Seconds<u32>>(r#""1.5""#).is_err());
+    assert!(from_json::<Seconds<u32>>("5").is_err());
+
+    assert_eq!(
+        postcard_round_trip(&Milliseconds(250_u32)),
+        (2, Milliseconds(250_u32))
+    );
+    assert_eq!(postcard_round_trip(&Hours(u64::MAX)), (10, Hours(u64::MAX)));
+
+    assert_eq!(json(&duration::Scaled::<u32, 1, 32_768>(5)), "5");
+    assert_eq!(
+        from_json::<duration::Scaled<u32, 1, 32_768>>("5").unwrap(),
+        duration::Scaled::<u32, 1, 32_768>(5)
+    );
+    assert_eq!(json(&duration::Ticks::<Clock>::new(7)), "7");
+    assert_eq!(
+        postcard_round_trip(&duration::Ticks::<Clock>::new(7)),
+        (1, duration::Ticks::new(7))
+    );
+
+    let generic = duration::Generic::new(5_u32, Fraction::new(1, 32_768));
+    assert_eq!(
+        json(&generic),
+        r#"{"integer":5,"scaling_factor":"1/32768"}"#
+    );
+    assert_eq!(
+        from_json::<duration::Generic<u32>>(r#"{"integer":5,"scaling_factor":"1/32768"}"#).unwrap(),
+        generic
+    );
+    let (_, round_trip) = postcard_round_trip(&generic);
+    assert_eq!(round_trip.integer(), 5);
+    assert_eq!(round_trip.scaling_factor(), &Fraction::new(1, 32_768));
+}
+
+#[test]
+fn rate() {
+    assert_eq!(json(&Megahertz(16_u32)), r#""16MHz""#);
+    assert_eq!(json(&Baud(115_200_u32)), r#""115200Bd""#);
+    assert_eq!(json(&KibibytesPerSecond(3_u32)), r#""3KiBps""#);
+    assert_eq!(json(&Millihertz(500_u32)), r#""0.5Hz""#);
+    assert_eq!(json(&Microhertz(1_u32)), r#""0.000001Hz""#);
+
+    assert_eq!(
+        from_json::<Megahertz<u32>>(r#""16MHz""#).unwrap(),
+        Megahertz(16_u32)
+    );
+    assert_eq!(
+        from_json::<Hertz<u32>>(r#""1.5kHz""#).unwrap(),
+        Hertz(1_500_u32)
+    );
+    assert_eq!(
+        from_json::<Millihertz<u32>>(r#""0.5Hz""#).unwrap(),
+        Millihertz(500_u32)
+    );
+    assert_eq!(
+        from_json::<Baud<u32>>(r#""115200Bd""#).unwrap(),
+        Baud(115_200_u32)
+    );
+    assert!(from_json::<Hertz<u32>>(r#""16Bd""#).is_err());
+
+    assert_eq!(
+        postcard_round_trip(&Megahertz(16_u32)),
+        (1, Megahertz(16_u32))
+    );
+    assert_eq!(
+        postcard_round_trip(&Millihertz(500_u64)),
+        (2, Millihertz(500_u64))
+    );
+
+    assert_eq!(json(&rate::Scaled::<u32, 1_000, 1>(8)), "8");
+
+    let generic = rate::Generic::new(5_u32, Fraction::new(1_000, 1));
+    assert_eq!(json(&generic), r#"{"integer":5,"scaling_factor":"1000/1"}"#);
+    assert_eq!(
+        from_json::<rate::Generic<u32>>(r#"{"integer":5,"scaling_factor":"1000"}"#).unwrap(),
+        generic
+    );
+    assert_eq!(postcard_round_trip(&generic).1, generic);
+}
+
+#[test]
+fn fraction() {
+    assert_eq!(json(&Fraction::new(1, 1_000)), r#""1/1000""#);
+    assert_eq!(
+        from_json::<Fraction>(r#""1/1000""#).unwrap(),
+        Fraction::new(1, 1_000)
+    );
+    assert_eq!(
+        from_json::<Fraction>(r#""3""#).unwrap(),
+        Fraction::new(3, 1)
+    );
+    assert!(from_json::<Fraction>(r#""1/0""#).is_err());
+    assert!(from_json::<Fraction>(r#""1/x""#).is_err());
+
+    assert_eq!(
+        postcard_round_trip(&Fraction::new(1, 1_000)),
+        (3, Fraction::new(1, 1_000))
+    );
+}
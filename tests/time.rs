@@ -0,0 +1,89 @@
+#![cfg(feature = "time")]
+
+use core::convert::TryFrom;
+use embedded_time::{duration::*, ConversionError, Instant};
+use time::{Date, Month, PrimitiveDateTime, Time};
+
+#[derive(Debug)]
+struct Clock;
+
+impl embedded_time::Clock for Clock {
+    type T = u64;
+    const SCALING_FACTOR: Fraction = Fraction::new(1, 32_768);
+
+    fn try_now(&self) -> Result<Instant<Self>, embedded_time::clock::Error> {
+        Ok(Instant::new(0))
+    }
+}
+
+#[test]
+fn duration() {
+    assert_eq!(
+        time::Duration::try_from(Hours(2_u32)),
+        Ok(time::Duration::hours(2))
+    );
+    assert_eq!(
+        time::Duration::try_from(Seconds(90_u64)),
+        Ok(time::Duration::seconds(90))
+    );
+    assert_eq!(
+        time::Duration::try_from(Milliseconds(1_500_u32)),
+        Ok(time::Duration::milliseconds(1_500))
+    );
+    assert_eq!(
+        time::Duration::try_from(Nanoseconds(123_u32)),
+        Ok(time::Duration::nanoseconds(123))
+    );
+    assert_eq!(
+        time::Duration::try_from(Days(2_u32)),
+        Ok(time::Duration::days(2))
+    );
+    assert_eq!(
+        time::Duration::try_from(Weeks(1_u32)),
+        Ok(time::Duration::weeks(1))
+    );
+    assert_eq!(
+        time::Duration::try_from(Picoseconds(1_999_u32)),
+        Ok(time::Duration::nanoseconds(1))
+    );
+
+    // truncated to nanoseconds
+    assert_eq!(
+        time::Duration::try_from(Generic::new(1_u32, Fraction::new(1, 32_768))),
+        Ok(time::Duration::nanoseconds(30_517))
+    );
+    assert_eq!(
+        time::Duration::try_from(Scaled::<u32, 1, 60>(3)),
+        Ok(time::Duration::milliseconds(50))
+    );
+    assert_eq!(
+        time::Duration::try_from(Ticks::<Clock>::new(49_152)),
+        Ok(time::Duration::milliseconds(1_500))
+    );
+
+    assert_eq!(
+        time::Duration::try_from(Hours(u64::MAX)),
+        Err(ConversionError::Overflow)
+    );
+}
+
+#[test]
+fn instant() {
+    let date = Date::from_calendar_date(2021, Month::October, 2).unwrap();
+    let epoch = PrimitiveDateTime::new(date, Time::from_hms(12, 0, 0).unwrap()).assume_utc();
+
+    assert_eq!(
+        Instant::<Clock>::new(0).to_offset_date_time(epoch),
+        Ok(epoch)
+    );
+    assert_eq!(
+        Instant::<Clock>::new(32_768 * 3_600 + 16_384).to_offset_date_time(epoch),
+        Ok(PrimitiveDateTime::new(date, Time::from_hms_milli(13, 0, 0, 500).unwrap()).assume_utc())
+    );
+
+    let max = PrimitiveDateTime::new(Date::MAX, Time::MIDNIGHT).assume_offset(time::UtcOffset::UTC);
+    assert_eq!(
+        Instant::<Clock>::new(u64::MAX).to_offset_date_time(max),
+        Err(ConversionError::Overflow)
+    );
+}
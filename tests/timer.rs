@@ -123,6 +123,19 @@ fn read_timer() {
     assert_eq!(timer.remaining(), Ok(0_u32.seconds()));
 }
 
+#[test]
+fn ticks_timer() {
+    init_ticks();
+    let clock = Clock;
+
+    let timer = clock.new_timer(Ticks::<Clock>::new(2_000)).start().unwrap();
+
+    add_to_ticks(500_u32.milliseconds());
+
+    assert_eq!(timer.elapsed(), Ok(Ticks::new(500)));
+    assert_eq!(timer.remaining(), Ok(Ticks::new(1_500)));
+}
+
 fn init_ticks() {}
 
 fn add_to_ticks<Dur: Duration>(duration: Dur)
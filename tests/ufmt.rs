@@ -0,0 +1,103 @@
+#![cfg(feature = "ufmt")]
+
+use core::convert::Infallible;
+use embedded_time::{
+    clock,
+    duration::{self, *},
+    fraction::Fraction,
+    rate::{self, *},
+    ConversionError, Instant, TimeError,
+};
+use ufmt::{uDebug, uDisplay, uWrite};
+
+#[derive(Debug)]
+struct Clock;
+
+impl embedded_time::Clock for Clock {
+    type T = u32;
+    const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+
+    fn try_now(&self) -> Result<Instant<Self>, embedded_time::clock::Error> {
+        Ok(Instant::new(0))
+    }
+}
+
+#[derive(Default)]
+struct Buffer(String);
+
+impl uWrite for Buffer {
+    type Error = Infallible;
+
+    fn write_str(&mut self, s: &str) -> Result<(), Self::Error> {
+        self.0.push_str(s);
+        Ok(())
+    }
+}
+
+fn display(value: impl uDisplay) -> String {
+    let mut buffer = Buffer::default();
+    ufmt::uwrite!(buffer, "{}", value).unwrap();
+    buffer.0
+}
+
+fn debug(value: impl uDebug) -> String {
+    let mut buffer = Buffer::default();
+    ufmt::uwrite!(buffer, "{:?}", value).unwrap();
+    buffer.0
+}
+
+#[test]
+fn duration() {
+    assert_eq!(display(Milliseconds(250_u32)), "250");
+    assert_eq!(display(Hours(u64::MAX)), format!("{}", Hours(u64::MAX)));
+    assert_eq!(display(Days(7_u32)), "7");
+    assert_eq!(display(duration::Scaled::<u32, 1, 32_768>(3)), "3");
+    assert_eq!(display(duration::Ticks::<Clock>::new(5)), "5");
+    assert_eq!(display(SignedDuration::<Seconds<u32>>::new(-3)), "-3");
+
+    assert_eq!(
+        debug(Milliseconds(250_u32)),
+        format!("{:?}", Milliseconds(250_u32))
+    );
+    assert_eq!(
+        debug(duration::Scaled::<u32, 1, 60>(3)),
+        format!("{:?}", duration::Scaled::<u32, 1, 60>(3))
+    );
+    assert_eq!(
+        debug(duration::Ticks::<Clock>::new(5)),
+        format!("{:?}", duration::Ticks::<Clock>::new(5))
+    );
+    let generic = duration::Generic::new(5_u32, Fraction::new(1, 1_000));
+    assert_eq!(debug(generic), format!("{:?}", generic));
+}
+
+#[test]
+fn rate() {
+    assert_eq!(display(Megahertz(16_u32)), "16");
+    assert_eq!(display(rate::Scaled::<u32, 1_000, 1>(8)), "8");
+
+    assert_eq!(debug(Baud(115_200_u32)), format!("{:?}", Baud(115_200_u32)));
+    let generic = rate::Generic::new(5_u32, Fraction::new(1_000, 1));
+    assert_eq!(debug(generic), format!("{:?}", generic));
+}
+
+#[test]
+fn instant() {
+    assert_eq!(
+        debug(Instant::<Clock>::new(23)),
+        format!("{:?}", Instant::<Clock>::new(23))
+    );
+}
+
+#[test]
+fn errors() {
+    assert_eq!(
+        debug(Fraction::new(1, 1_000)),
+        format!("{:?}", Fraction::new(1, 1_000))
+    );
+    assert_eq!(debug(ConversionError::Overflow), "Overflow");
+    assert_eq!(
+        debug(TimeError::Clock(clock::Error::NotRunning)),
+        "Clock(NotRunning)"
+    );
+}